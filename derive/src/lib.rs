@@ -0,0 +1,246 @@
+//! Derive macros for `tf-demo-parser`, see the `tf_demo_parser::demo::entityview` module for usage
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr, Path, Result, Token,
+};
+
+/// Derive `FromEntity` to apply the props of an entity to the fields of a struct
+///
+/// ```ignore
+/// #[derive(Default, FromEntity)]
+/// #[entity(class = "CObjectSentrygun")]
+/// struct Sentry {
+///     #[prop("DT_ObjectSentrygun", "m_iAmmoShells")]
+///     shells: u16,
+///     #[prop("DT_BaseEntity", "m_angRotation", unit = "radians")]
+///     rotation: Vector,
+///     #[prop("DT_TFLocalPlayerExclusive", "m_vecOrigin", xy)]
+///     #[prop("DT_TFLocalPlayerExclusive", "m_vecOrigin[2]", z)]
+///     position: Vector,
+///     #[prop("DT_BaseObject", "m_hBuilder", with = "builder_user_id")]
+///     builder: UserId,
+/// }
+/// ```
+#[proc_macro_derive(FromEntity, attributes(entity, prop))]
+pub fn derive_from_entity(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum Unit {
+    Radians,
+    Meters,
+}
+
+enum Target {
+    Field,
+    XY,
+    Z,
+}
+
+struct PropAttr {
+    table: LitStr,
+    name: LitStr,
+    with: Option<Path>,
+    unit: Option<Unit>,
+    target: Target,
+}
+
+impl Parse for PropAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let table: LitStr = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let name: LitStr = input.parse()?;
+
+        let mut attr = PropAttr {
+            table,
+            name,
+            with: None,
+            unit: None,
+            target: Target::Field,
+        };
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            match key.to_string().as_str() {
+                "xy" => attr.target = Target::XY,
+                "z" => attr.target = Target::Z,
+                "with" => {
+                    input.parse::<Token![=]>()?;
+                    let path: LitStr = input.parse()?;
+                    attr.with = Some(path.parse()?);
+                }
+                "unit" => {
+                    input.parse::<Token![=]>()?;
+                    let unit: LitStr = input.parse()?;
+                    attr.unit = Some(match unit.value().as_str() {
+                        "radians" => Unit::Radians,
+                        "meters" => Unit::Meters,
+                        _ => {
+                            return Err(Error::new(
+                                unit.span(),
+                                "unknown unit, expected \"radians\" or \"meters\"",
+                            ))
+                        }
+                    });
+                }
+                _ => return Err(Error::new(key.span(), "unknown prop option")),
+            }
+        }
+
+        if attr.with.is_some() && (attr.unit.is_some() || !matches!(attr.target, Target::Field)) {
+            return Err(Error::new(
+                attr.name.span(),
+                "`with` can't be combined with other prop options",
+            ));
+        }
+
+        Ok(attr)
+    }
+}
+
+fn entity_class(input: &DeriveInput) -> Result<LitStr> {
+    let mut class = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("entity"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("class") {
+                class = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown entity option"))
+            }
+        })?;
+    }
+    class.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "missing `#[entity(class = \"...\")]` attribute",
+        )
+    })
+}
+
+fn derive(input: DeriveInput) -> Result<TokenStream2> {
+    let class = entity_class(&input)?;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "FromEntity can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "FromEntity can only be derived for structs",
+            ))
+        }
+    };
+
+    let krate = quote!(::tf_demo_parser::demo);
+    let mut identifiers = Vec::new();
+    let mut handlers = Vec::new();
+
+    for field in fields {
+        let field_name = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("prop"))
+        {
+            let prop: PropAttr = attr.parse_args()?;
+            let id = format_ident!("PROP_{}", identifiers.len());
+            let (table, name) = (&prop.table, &prop.name);
+            identifiers.push(quote! {
+                const #id: #krate::sendprop::SendPropIdentifier =
+                    #krate::sendprop::SendPropIdentifier::new(#table, #name);
+            });
+
+            let scale = match prop.unit {
+                Some(unit) => {
+                    let unit = match unit {
+                        Unit::Radians => quote!(Radians),
+                        Unit::Meters => quote!(Meters),
+                    };
+                    quote! {
+                        .map(|value| #krate::entityview::ScaleProp::scale(
+                            value,
+                            #krate::entityview::PropUnit::#unit.factor(),
+                        ))
+                    }
+                }
+                None => quote!(),
+            };
+
+            let apply = match (&prop.with, &prop.target) {
+                (Some(with), _) => quote! {
+                    self.#field_name = #with(&prop.value, state).unwrap_or_default();
+                },
+                (None, Target::Field) => quote! {
+                    self.#field_name = <#ty as #krate::entityview::FromPropValue>::from_prop_value(&prop.value)
+                        #scale
+                        .unwrap_or_default();
+                },
+                (None, Target::XY) => quote! {
+                    let value = <#krate::vector::VectorXY as #krate::entityview::FromPropValue>::from_prop_value(&prop.value)
+                            #scale
+                            .unwrap_or_default();
+                    self.#field_name.x = value.x;
+                    self.#field_name.y = value.y;
+                },
+                (None, Target::Z) => quote! {
+                    let value = <f32 as #krate::entityview::FromPropValue>::from_prop_value(&prop.value)
+                        #scale
+                        .unwrap_or_default();
+                    self.#field_name.z = value;
+                },
+            };
+
+            handlers.push(quote! {
+                if prop.identifier == #id {
+                    #apply
+                    handled = true;
+                }
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::entityview::FromEntity for #name #ty_generics #where_clause {
+            const CLASS: &'static str = #class;
+
+            #[allow(unused_variables, unused_mut)]
+            fn apply_prop(
+                &mut self,
+                prop: &#krate::sendprop::SendProp,
+                state: &::tf_demo_parser::ParserState,
+            ) -> bool {
+                #(#identifiers)*
+
+                let mut handled = false;
+                #(#handlers)*
+                handled
+            }
+        }
+    })
+}
@@ -1,13 +1,19 @@
+use std::cell::RefCell;
 use std::env;
 use std::fs;
+use std::io::{stdout, BufWriter, Write};
+use std::rc::Rc;
 
+use bitbuffer::BitRead;
 use main_error::MainError;
 use serde::{Deserialize, Serialize};
 use tf_demo_parser::demo::data::ServerTick;
 use tf_demo_parser::demo::header::Header;
 use tf_demo_parser::demo::parser::analyser::Analyser;
 use tf_demo_parser::demo::parser::analyser::MatchState;
+use tf_demo_parser::demo::parser::entitydump::{EntityDumpFilter, EntityDumpMode, EntityDumper};
 use tf_demo_parser::demo::parser::player_summary_analyzer::PlayerSummaryAnalyzer;
+use tf_demo_parser::demo::parser::{DemoHandler, NullHandler, RawPacketStream};
 pub use tf_demo_parser::{Demo, DemoParser, Parse, ParseError, ParserState, Stream};
 
 #[cfg(feature = "jemallocator")]
@@ -38,6 +44,14 @@ fn main() -> Result<(), MainError> {
     let file = fs::read(path)?;
     let demo = Demo::new(&file);
 
+    match args.get(2).map(String::as_str) {
+        Some("entities") => return dump_entities(demo, &args[3..]),
+        Some("baselines") => return dump_baselines(demo, &args[3..]),
+        #[cfg(feature = "voice")]
+        Some("voice") => return dump_voice(demo, &args[3..]),
+        _ => {}
+    }
+
     let parser = DemoParser::new_with_analyser(demo.get_stream(), Analyser::new());
     let (header, state) = parser.parse()?;
 
@@ -95,3 +109,119 @@ fn main() -> Result<(), MainError> {
 
     Ok(())
 }
+
+/// Stream entity updates as NDJSON in the format of the js parser
+///
+/// `parse_demo <demo> entities [--class <name>].. [--entity <id>].. [--from <tick>] [--to <tick>] [--full]`
+fn dump_entities(demo: Demo, args: &[String]) -> Result<(), MainError> {
+    let mut filter = EntityDumpFilter::default();
+    let mut mode = EntityDumpMode::Delta;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--class" => filter.classes.push(value()?.as_str().into()),
+            "--entity" => filter.entities.push(value()?.parse::<u32>()?.into()),
+            "--from" => filter.start_tick = Some(value()?.parse::<u32>()?.into()),
+            "--to" => filter.end_tick = Some(value()?.parse::<u32>()?.into()),
+            "--full" => mode = EntityDumpMode::Full,
+            _ => return Err(format!("unknown argument {}", arg).into()),
+        }
+    }
+
+    // write the dumps while parsing instead of collecting all of them in memory
+    let out = Rc::new(RefCell::new(BufWriter::new(stdout())));
+    let write_error = Rc::new(RefCell::new(None));
+    let dumper = EntityDumper::new()
+        .with_filter(filter)
+        .with_mode(mode)
+        .with_sink({
+            let out = out.clone();
+            let write_error = write_error.clone();
+            move |dump| {
+                let mut out = out.borrow_mut();
+                let result = serde_json::to_writer(&mut *out, &dump)
+                    .map_err(std::io::Error::from)
+                    .and_then(|_| out.write_all(b"\n"));
+                if let Err(e) = result {
+                    write_error.borrow_mut().get_or_insert(e);
+                }
+            }
+        });
+    let mut parser = DemoParser::new_with_analyser(demo.get_stream(), dumper);
+    if mode == EntityDumpMode::Full {
+        parser = parser.with_entity_world();
+    }
+    parser.parse()?;
+
+    if let Some(e) = write_error.take() {
+        return Err(e.into());
+    }
+    out.borrow_mut().flush()?;
+
+    Ok(())
+}
+
+/// Output the baselines at the end of the demo as JSON
+///
+/// `parse_demo <demo> baselines [--diff]`
+fn dump_baselines(demo: Demo, args: &[String]) -> Result<(), MainError> {
+    let mut handler = DemoHandler::parse_all_with_analyser(NullHandler).with_prop_names();
+
+    let mut stream = demo.get_stream();
+    let header = Header::read(&mut stream)?;
+    handler.handle_header(&header);
+
+    let mut packets = RawPacketStream::new(stream);
+    while let Some(packet) = packets.next(&handler.state_handler)? {
+        handler.handle_packet(packet)?;
+    }
+
+    let state = handler.get_parser_state();
+    let out = stdout().lock();
+    if args.iter().any(|arg| arg == "--diff") {
+        serde_json::to_writer_pretty(out, &state.diff_instance_baselines())?;
+    } else {
+        serde_json::to_writer_pretty(out, &state.export_baselines())?;
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Write the voice communication of every speaker to `<dir>/<user id>.wav`
+///
+/// `parse_demo <demo> voice <dir>`
+#[cfg(feature = "voice")]
+fn dump_voice(demo: Demo, args: &[String]) -> Result<(), MainError> {
+    use tf_demo_parser::demo::voice::VoiceAnalyser;
+
+    let dir = std::path::Path::new(args.first().ok_or("missing output directory")?);
+    fs::create_dir_all(dir)?;
+
+    let parser = DemoParser::new_with_analyser(demo.get_stream(), VoiceAnalyser::new());
+    let (_, voice) = parser.parse()?;
+
+    for client in voice.speakers() {
+        let audio = voice.render_speaker(client)?;
+        let name = match voice.user_id(client) {
+            Some(user_id) => format!("{}.wav", u16::from(user_id)),
+            None => format!("client_{}.wav", client),
+        };
+        let path = dir.join(name);
+        audio.write_wav(BufWriter::new(fs::File::create(&path)?))?;
+        if audio.decode_errors > 0 {
+            eprintln!(
+                "{}: skipped {} voice packets that failed to decode",
+                path.display(),
+                audio.decode_errors
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,341 @@
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::parser::MessageHandler;
+use crate::demo::sendprop::{SendPropIdentifier, SendPropParseDefinition};
+use crate::{Demo, DemoParser, MessageType, ParserState};
+use fnv::FnvHashMap;
+use inflector::Inflector;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::collections::HashSet;
+
+struct ViewProp {
+    identifier: SendPropIdentifier,
+    table_name: String,
+    prop_name: String,
+    definition: SendPropParseDefinition,
+}
+
+struct ClassView {
+    class_name: String,
+    props: Vec<ViewProp>,
+}
+
+struct ViewAnalyser {
+    classes: Vec<String>,
+    views: Vec<ClassView>,
+}
+
+impl MessageHandler for ViewAnalyser {
+    type Output = Vec<ClassView>;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _state: &ParserState,
+    ) {
+        let prop_names: FnvHashMap<SendPropIdentifier, (String, String)> = parse_tables
+            .iter()
+            .flat_map(|table| {
+                table.props.iter().map(move |prop| {
+                    (
+                        prop.identifier(),
+                        (table.name.to_string(), prop.name.to_string()),
+                    )
+                })
+            })
+            .collect();
+
+        for class in server_classes {
+            if !self.classes.is_empty() && !self.classes.contains(&class.name.to_string()) {
+                continue;
+            }
+            let flat = match parse_tables
+                .iter()
+                .find(|table| table.name == class.data_table)
+                .map(|table| table.flatten_props(parse_tables))
+            {
+                Some(Ok(flat)) => flat,
+                _ => continue,
+            };
+            let props = flat
+                .into_iter()
+                .filter_map(|definition| {
+                    let (table_name, prop_name) = prop_names.get(&definition.identifier)?.clone();
+                    Some(ViewProp {
+                        identifier: definition.identifier,
+                        table_name,
+                        prop_name,
+                        definition: definition.parse_definition,
+                    })
+                })
+                .collect();
+            self.views.push(ClassView {
+                class_name: class.name.to_string(),
+                props,
+            });
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.views
+    }
+}
+
+/// Entity handles are sent as 21 bit unsigned ints and are prefixed by `m_h` by convention
+fn is_handle(table_name: &str, prop_name: &str, bit_count: u8) -> bool {
+    fn has_handle_prefix(name: &str) -> bool {
+        name.strip_prefix("m_h")
+            .and_then(|rest| rest.chars().next())
+            .map(|c| c.is_ascii_uppercase())
+            .unwrap_or_default()
+    }
+    bit_count == 21 && (has_handle_prefix(prop_name) || has_handle_prefix(table_name))
+}
+
+fn get_type(
+    table_name: &str,
+    prop_name: &str,
+    definition: &SendPropParseDefinition,
+) -> TokenStream {
+    match definition {
+        SendPropParseDefinition::NormalVarInt { unsigned: true, .. } => quote!(u32),
+        SendPropParseDefinition::NormalVarInt {
+            unsigned: false, ..
+        } => quote!(i32),
+        SendPropParseDefinition::UnsignedInt { bit_count, .. }
+            if is_handle(table_name, prop_name, *bit_count) =>
+        {
+            quote!(EntityHandle)
+        }
+        SendPropParseDefinition::UnsignedInt { bit_count: 1, .. } => quote!(bool),
+        SendPropParseDefinition::UnsignedInt { bit_count, .. } => match bit_count {
+            0..=8 => quote!(u8),
+            9..=16 => quote!(u16),
+            17..=32 => quote!(u32),
+            _ => quote!(u64),
+        },
+        SendPropParseDefinition::Int { bit_count, .. } => match bit_count {
+            0..=8 => quote!(i8),
+            9..=16 => quote!(i16),
+            17..=32 => quote!(i32),
+            _ => quote!(i64),
+        },
+        SendPropParseDefinition::Float { .. } => quote!(f32),
+        SendPropParseDefinition::String { .. } => quote!(String),
+        SendPropParseDefinition::Vector { .. } => quote!(Vector),
+        SendPropParseDefinition::VectorXY { .. } => quote!(VectorXY),
+        SendPropParseDefinition::Array {
+            inner_definition, ..
+        } => {
+            let inner = get_type(table_name, prop_name, inner_definition);
+            quote!(Vec<#inner>)
+        }
+    }
+}
+
+/// Strip the `m_` and type prefix from a prop name, `m_iHealth` becomes `health`
+fn strip_prefix(name: &str) -> &str {
+    const TYPE_PREFIXES: &[&str] = &[
+        "vec", "ang", "isz", "psz", "clr", "fl", "sz", "ch", "ub", "us", "ui", "i", "b", "n", "h",
+        "e", "f", "u",
+    ];
+    let name = match name.strip_prefix("m_") {
+        Some(name) => name,
+        None => return name,
+    };
+    for prefix in TYPE_PREFIXES {
+        if let Some(rest) = name.strip_prefix(prefix) {
+            if rest.chars().next().map(|c| c.is_ascii_uppercase()) == Some(true) {
+                return rest;
+            }
+        }
+    }
+    name
+}
+
+fn get_method_name(table_name: &str, prop_name: &str) -> String {
+    // array elements are stored in a table named after the array
+    let name = if prop_name.chars().all(|c| c.is_ascii_digit()) {
+        format!("{}_{}", strip_prefix(table_name), prop_name)
+    } else {
+        strip_prefix(prop_name).to_string()
+    };
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let mut snake = name.to_snake_case();
+    if snake.is_empty() || snake.starts_with(|c: char| c.is_ascii_digit()) {
+        snake = format!("prop_{}", snake);
+    }
+    match snake.as_str() {
+        "type" => "kind".into(),
+        "as" | "break" | "const" | "continue" | "crate" | "else" | "enum" | "extern" | "false"
+        | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod" | "move"
+        | "mut" | "pub" | "ref" | "return" | "self" | "static" | "struct" | "super" | "trait"
+        | "true" | "unsafe" | "use" | "where" | "while" | "async" | "await" | "dyn" | "new"
+        | "entity" => format!("{}_value", snake),
+        _ => snake,
+    }
+}
+
+/// A vector that is sent as separate xy and z props, like `m_vecOrigin` and `m_vecOrigin[2]` for players
+///
+/// The same vector can be sent from multiple tables, like the local and non-local player tables
+struct SplitVector<'a> {
+    prop_name: &'a str,
+    /// The xy and z prop for every table containing the vector
+    parts: Vec<(&'a ViewProp, &'a ViewProp)>,
+}
+
+fn find_split_vectors(props: &[ViewProp]) -> Vec<SplitVector<'_>> {
+    let mut vectors: Vec<SplitVector> = Vec::new();
+    for xy in props {
+        if !matches!(xy.definition, SendPropParseDefinition::VectorXY { .. }) {
+            continue;
+        }
+        let z_name = format!("{}[2]", xy.prop_name);
+        let z = props.iter().find(|z| {
+            z.table_name == xy.table_name
+                && z.prop_name == z_name
+                && matches!(z.definition, SendPropParseDefinition::Float { .. })
+        });
+        if let Some(z) = z {
+            match vectors
+                .iter_mut()
+                .find(|vector| vector.prop_name == xy.prop_name)
+            {
+                Some(vector) => vector.parts.push((xy, z)),
+                None => vectors.push(SplitVector {
+                    prop_name: &xy.prop_name,
+                    parts: vec![(xy, z)],
+                }),
+            }
+        }
+    }
+    vectors
+}
+
+fn generate_split_vector(vector: &SplitVector, method_name: &str) -> TokenStream {
+    let method = Ident::new(method_name, Span::call_site());
+    let doc = vector
+        .parts
+        .iter()
+        .map(|(xy, z)| format!("`{}.{}` + `{}`", xy.table_name, xy.prop_name, z.prop_name))
+        .collect::<Vec<_>>()
+        .join(" or ");
+    let doc = format!("{}, from the first table that is present", doc);
+    let parts = vector.parts.iter().map(|(xy, z)| {
+        let table_name = &xy.table_name;
+        let xy_name = &xy.prop_name;
+        let z_name = &z.prop_name;
+        quote!((
+            SendPropIdentifier::new(#table_name, #xy_name),
+            SendPropIdentifier::new(#table_name, #z_name),
+        ))
+    });
+    quote! {
+        #[doc = #doc]
+        pub fn #method(&self) -> Option<Vector> {
+            const PARTS: &[(SendPropIdentifier, SendPropIdentifier)] = &[#(#parts),*];
+            PARTS.iter().find_map(|(xy, z)| self.entity.get_split_vector(*xy, *z))
+        }
+    }
+}
+
+fn generate_view(view: &ClassView) -> TokenStream {
+    let name = Ident::new(&format!("{}View", view.class_name), Span::call_site());
+    let class_name = &view.class_name;
+    let doc = format!("Typed access to the props of a `{}` entity", class_name);
+
+    let mut used_names = HashSet::new();
+    // the merged vectors get the plain name, the separate parts are still available under the table specific names
+    let split_vectors: Vec<_> = find_split_vectors(&view.props)
+        .iter()
+        .map(|vector| {
+            let method_name = get_method_name("", vector.prop_name);
+            used_names.insert(method_name.clone());
+            generate_split_vector(vector, &method_name)
+        })
+        .collect();
+    let methods = view.props.iter().map(|prop| {
+        let mut method_name = get_method_name(&prop.table_name, &prop.prop_name);
+        // props with the same name from different tables
+        if !used_names.insert(method_name.clone()) {
+            let table = get_method_name("", prop.table_name.trim_start_matches("DT_"));
+            method_name = format!("{}_{}", table, method_name);
+            let mut suffix = 1;
+            while !used_names.insert(method_name.clone()) {
+                suffix += 1;
+                method_name = format!("{}_{}_{}", table, get_method_name(&prop.table_name, &prop.prop_name), suffix);
+            }
+        }
+        let method = Ident::new(&method_name, Span::call_site());
+        let ty = get_type(&prop.table_name, &prop.prop_name, &prop.definition);
+        let table_name = &prop.table_name;
+        let prop_name = &prop.prop_name;
+        let doc = format!("`{}.{}`", table_name, prop_name);
+        debug_assert_eq!(prop.identifier, SendPropIdentifier::new(table_name, prop_name));
+        quote! {
+            #[doc = #doc]
+            pub fn #method(&self) -> Option<#ty> {
+                const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(#table_name, #prop_name);
+                self.entity.get_value(IDENTIFIER)
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #doc]
+        pub struct #name<'a, E: EntityProps + ?Sized> {
+            entity: &'a E,
+        }
+
+        impl<'a, E: EntityProps + ?Sized> #name<'a, E> {
+            pub const CLASS: &'static str = #class_name;
+
+            pub fn new(entity: &'a E) -> Self {
+                #name { entity }
+            }
+
+            pub fn entity(&self) -> &'a E {
+                self.entity
+            }
+
+            #(#split_vectors)*
+
+            #(#methods)*
+        }
+    }
+}
+
+/// Generate typed views for the given server classes, or all classes if no classes are given
+pub fn generate_entity_views(demo: Demo, classes: &[String]) -> TokenStream {
+    let analyser = ViewAnalyser {
+        classes: classes.to_vec(),
+        views: Vec::new(),
+    };
+    let (_, views) = DemoParser::new_with_analyser(demo.get_stream(), analyser)
+        .parse()
+        .unwrap();
+
+    let imports = quote!(
+        use crate::demo::entityview::EntityProps;
+        use crate::demo::message::packetentities::EntityHandle;
+        use crate::demo::sendprop::SendPropIdentifier;
+        use crate::demo::vector::{Vector, VectorXY};
+    );
+
+    let views = views.iter().map(generate_view);
+
+    quote!(
+        #imports
+
+        #(#views)*
+    )
+}
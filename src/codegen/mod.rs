@@ -1,2 +1,3 @@
+pub mod entityview;
 pub mod gameevent;
 pub mod propnames;
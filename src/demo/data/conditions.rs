@@ -0,0 +1,352 @@
+use crate::demo::sendprop::{SendProp, SendPropIdentifier};
+use num_enum::TryFromPrimitive;
+use parse_display::Display;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// A player condition as networked by the server, the discriminant is the condition index (`ETFCond`)
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    TryFromPrimitive,
+    Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[display(style = "snake_case")]
+#[repr(u8)]
+pub enum PlayerCondition {
+    Aiming = 0,
+    Zoomed = 1,
+    Disguising = 2,
+    Disguised = 3,
+    Stealthed = 4,
+    Invulnerable = 5,
+    Teleported = 6,
+    Taunting = 7,
+    InvulnerableWearingOff = 8,
+    StealthedBlink = 9,
+    SelectedToTeleport = 10,
+    CritBoosted = 11,
+    TmpDamageBonus = 12,
+    FeignDeath = 13,
+    Phase = 14,
+    Stunned = 15,
+    OffenseBuff = 16,
+    ShieldCharge = 17,
+    DemoBuff = 18,
+    EnergyBuff = 19,
+    RadiusHeal = 20,
+    HealthBuff = 21,
+    Burning = 22,
+    HealthOverhealed = 23,
+    Urine = 24,
+    Bleeding = 25,
+    DefenseBuff = 26,
+    MadMilk = 27,
+    MegaHeal = 28,
+    RegenOnDamageBuff = 29,
+    MarkedForDeath = 30,
+    NoHealingDamageBuff = 31,
+    SpeedBoost = 32,
+    CritBoostedPumpkin = 33,
+    CritBoostedUserBuff = 34,
+    CritBoostedDemoCharge = 35,
+    SodaPopperHype = 36,
+    CritBoostedFirstBlood = 37,
+    CritBoostedBonusTime = 38,
+    CritBoostedCtfCapture = 39,
+    CritBoostedOnKill = 40,
+    CannotSwitchFromMelee = 41,
+    DefenseBuffNoCritBlock = 42,
+    Reprogrammed = 43,
+    CritBoostedRageBuff = 44,
+    DefenseBuffHigh = 45,
+    SniperChargeRageBuff = 46,
+    DisguiseWearingOff = 47,
+    MarkedForDeathSilent = 48,
+    DisguisedAsDispenser = 49,
+    Sapped = 50,
+    InvulnerableHideUnlessDamaged = 51,
+    InvulnerableUserBuff = 52,
+    HalloweenBombHead = 53,
+    HalloweenThriller = 54,
+    RadiusHealOnDamage = 55,
+    CritBoostedCardEffect = 56,
+    InvulnerableCardEffect = 57,
+    MedigunUberBulletResist = 58,
+    MedigunUberBlastResist = 59,
+    MedigunUberFireResist = 60,
+    MedigunSmallBulletResist = 61,
+    MedigunSmallBlastResist = 62,
+    MedigunSmallFireResist = 63,
+    StealthedUserBuff = 64,
+    MedigunDebuff = 65,
+    StealthedUserBuffFading = 66,
+    BulletImmune = 67,
+    BlastImmune = 68,
+    FireImmune = 69,
+    PreventDeath = 70,
+    MvmBotStunRadiowave = 71,
+    HalloweenSpeedBoost = 72,
+    HalloweenQuickHeal = 73,
+    HalloweenGiant = 74,
+    HalloweenTiny = 75,
+    HalloweenInHell = 76,
+    HalloweenGhostMode = 77,
+    MiniCritBoostedOnKill = 78,
+    ObscuredSmoke = 79,
+    ParachuteActive = 80,
+    BlastJumping = 81,
+    HalloweenKart = 82,
+    HalloweenKartDash = 83,
+    BalloonHead = 84,
+    MeleeOnly = 85,
+    SwimmingCurse = 86,
+    FreezeInput = 87,
+    HalloweenKartCage = 88,
+    DoNotUse0 = 89,
+    RuneStrength = 90,
+    RuneHaste = 91,
+    RuneRegen = 92,
+    RuneResist = 93,
+    RuneVampire = 94,
+    RuneReflect = 95,
+    RunePrecision = 96,
+    RuneAgility = 97,
+    GrapplingHook = 98,
+    GrapplingHookSafeFall = 99,
+    GrapplingHookLatched = 100,
+    GrapplingHookBleeding = 101,
+    AfterburnImmune = 102,
+    RuneKnockout = 103,
+    RuneImbalance = 104,
+    CritBoostedRuneTemp = 105,
+    PasstimeInterception = 106,
+    SwimmingNoEffects = 107,
+    Purgatory = 108,
+    RuneKing = 109,
+    RunePlague = 110,
+    RuneSupernova = 111,
+    Plague = 112,
+    KingBuffed = 113,
+    TeamGlows = 114,
+    KnockedIntoAir = 115,
+    CompetitiveWinner = 116,
+    CompetitiveLoser = 117,
+    HealingDebuff = 118,
+    PasstimePenaltyDebuff = 119,
+    GrappledToPlayer = 120,
+    GrappledByPlayer = 121,
+    ParachuteDeployed = 122,
+    Gas = 123,
+    BurningPyro = 124,
+    RocketPack = 125,
+    LostFooting = 126,
+    AirCurrent = 127,
+    HalloweenHellHeal = 128,
+    PowerupModeDominant = 129,
+    ImmuneToPushback = 130,
+}
+
+impl PlayerCondition {
+    /// Whether this condition makes the player invulnerable to damage, as with an uber charge
+    pub fn is_invulnerable(&self) -> bool {
+        matches!(
+            self,
+            PlayerCondition::Invulnerable
+                | PlayerCondition::InvulnerableWearingOff
+                | PlayerCondition::InvulnerableUserBuff
+                | PlayerCondition::InvulnerableCardEffect
+        )
+    }
+
+    /// Whether this condition gives the player full crits
+    pub fn is_crit_boost(&self) -> bool {
+        matches!(
+            self,
+            PlayerCondition::CritBoosted
+                | PlayerCondition::CritBoostedPumpkin
+                | PlayerCondition::CritBoostedUserBuff
+                | PlayerCondition::CritBoostedDemoCharge
+                | PlayerCondition::CritBoostedFirstBlood
+                | PlayerCondition::CritBoostedBonusTime
+                | PlayerCondition::CritBoostedCtfCapture
+                | PlayerCondition::CritBoostedOnKill
+                | PlayerCondition::CritBoostedRageBuff
+                | PlayerCondition::CritBoostedCardEffect
+                | PlayerCondition::CritBoostedRuneTemp
+        )
+    }
+}
+
+/// Number of 32 bit words used to network the player conditions
+///
+/// The conditions are split over `m_nPlayerCond`, `m_nPlayerCondEx`, `m_nPlayerCondEx2`, `m_nPlayerCondEx3` and `m_nPlayerCondEx4`
+pub const CONDITION_WORDS: usize = 5;
+
+const CONDITION_PROPS: [SendPropIdentifier; CONDITION_WORDS] = [
+    SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCond"),
+    SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx"),
+    SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx2"),
+    SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx3"),
+    SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx4"),
+];
+const CONDITION_BITS_PROP: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFPlayerConditionListExclusive", "_condition_bits");
+
+/// The set of conditions a player is in
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct PlayerConditions {
+    words: [u32; CONDITION_WORDS],
+    /// The first 32 conditions as sent through `_condition_bits` by the condition list
+    condition_bits: u32,
+}
+
+impl PlayerConditions {
+    /// Set the raw bits of one of the condition words, `word` 0 is `m_nPlayerCond`, 1 is `m_nPlayerCondEx`, etc
+    pub fn set_word(&mut self, word: usize, bits: u32) {
+        if let Some(existing) = self.words.get_mut(word) {
+            *existing = bits;
+        }
+    }
+
+    /// Set the raw bits of the `_condition_bits` prop from the condition list
+    pub fn set_condition_bits(&mut self, bits: u32) {
+        self.condition_bits = bits;
+    }
+
+    pub fn word(&self, word: usize) -> u32 {
+        let bits = self.words.get(word).copied().unwrap_or_default();
+        if word == 0 {
+            bits | self.condition_bits
+        } else {
+            bits
+        }
+    }
+
+    pub fn contains(&self, condition: PlayerCondition) -> bool {
+        self.contains_index(condition as u8)
+    }
+
+    fn contains_index(&self, index: u8) -> bool {
+        let word = index as usize / 32;
+        let bit = index as u32 % 32;
+        self.word(word) & (1 << bit) != 0
+    }
+
+    pub fn insert(&mut self, condition: PlayerCondition) {
+        let index = condition as u8;
+        self.words[index as usize / 32] |= 1 << (index as u32 % 32);
+    }
+
+    pub fn remove(&mut self, condition: PlayerCondition) {
+        let index = condition as u8;
+        self.words[index as usize / 32] &= !(1 << (index as u32 % 32));
+        if index < 32 {
+            self.condition_bits &= !(1 << index as u32);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..CONDITION_WORDS).all(|word| self.word(word) == 0)
+    }
+
+    /// Whether the player is ubered or otherwise invulnerable
+    pub fn is_invulnerable(&self) -> bool {
+        self.iter().any(|condition| condition.is_invulnerable())
+    }
+
+    /// Whether the player has full crits
+    pub fn is_crit_boosted(&self) -> bool {
+        self.iter().any(|condition| condition.is_crit_boost())
+    }
+
+    /// Iterate over all known conditions in the set
+    pub fn iter(&self) -> impl Iterator<Item = PlayerCondition> + '_ {
+        (0..(CONDITION_WORDS * 32) as u16)
+            .map(|index| index as u8)
+            .filter(move |index| self.contains_index(*index))
+            .filter_map(|index| PlayerCondition::try_from(index).ok())
+    }
+
+    /// Update the conditions from one of the `m_nPlayerCond*` props or `_condition_bits`
+    ///
+    /// Returns `false` if the prop isn't a condition prop
+    pub fn update_from_prop(&mut self, prop: &SendProp) -> bool {
+        let bits = || i64::try_from(&prop.value).unwrap_or_default() as u32;
+        if prop.identifier == CONDITION_BITS_PROP {
+            self.set_condition_bits(bits());
+            true
+        } else if let Some(word) = CONDITION_PROPS
+            .iter()
+            .position(|identifier| *identifier == prop.identifier)
+        {
+            self.set_word(word, bits());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The conditions that are in `self` but not in `other`
+    pub fn difference<'a>(
+        &'a self,
+        other: &'a PlayerConditions,
+    ) -> impl Iterator<Item = PlayerCondition> + 'a {
+        self.iter()
+            .filter(move |condition| !other.contains(*condition))
+    }
+}
+
+impl FromIterator<PlayerCondition> for PlayerConditions {
+    fn from_iter<T: IntoIterator<Item = PlayerCondition>>(iter: T) -> Self {
+        let mut conditions = PlayerConditions::default();
+        for condition in iter {
+            conditions.insert(condition);
+        }
+        conditions
+    }
+}
+
+#[test]
+fn test_player_conditions() {
+    let mut conditions = PlayerConditions::default();
+    conditions.set_word(0, (1 << 5) | (1 << 22));
+    conditions.set_word(1, 1 << 1);
+    conditions.set_word(4, 1 << 1);
+
+    assert!(conditions.contains(PlayerCondition::Invulnerable));
+    assert!(conditions.contains(PlayerCondition::Burning));
+    assert!(conditions.contains(PlayerCondition::CritBoostedPumpkin));
+    assert!(conditions.contains(PlayerCondition::PowerupModeDominant));
+    assert!(!conditions.contains(PlayerCondition::Urine));
+    assert!(conditions.is_invulnerable());
+    assert!(conditions.is_crit_boosted());
+
+    let other: PlayerConditions = [PlayerCondition::Burning, PlayerCondition::Urine]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        vec![
+            PlayerCondition::Invulnerable,
+            PlayerCondition::CritBoostedPumpkin,
+            PlayerCondition::PowerupModeDominant
+        ],
+        conditions.difference(&other).collect::<Vec<_>>()
+    );
+
+    conditions.set_word(0, 0);
+    conditions.set_condition_bits(1 << 24);
+    assert!(conditions.contains(PlayerCondition::Urine));
+    assert!(!conditions.contains(PlayerCondition::Burning));
+}
@@ -0,0 +1,274 @@
+use crate::{ReadResult, Stream};
+use bitbuffer::{BitError, BitRead, BitWrite, BitWriteStream, LittleEndian};
+use serde::{Deserialize, Serialize};
+
+const TYPE_NONE: u8 = 0;
+const TYPE_STRING: u8 = 1;
+const TYPE_INT: u8 = 2;
+const TYPE_FLOAT: u8 = 3;
+const TYPE_PTR: u8 = 4;
+const TYPE_WSTRING: u8 = 5;
+const TYPE_COLOR: u8 = 6;
+const TYPE_UINT64: u8 = 7;
+const TYPE_COMPILED_INT_BYTE: u8 = 8;
+const TYPE_COMPILED_INT_0: u8 = 9;
+const TYPE_COMPILED_INT_1: u8 = 10;
+/// Marks the end of a list of keys
+const TYPE_END: u8 = 11;
+
+/// Maximum nesting of sections, to prevent malicious payloads from overflowing the stack
+const MAX_DEPTH: usize = 64;
+
+/// A list of keys in the binary KeyValues format, as used by the `CmdKeyValues` and `Menu` messages
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct KeyValues {
+    pub entries: Vec<KeyValueEntry>,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyValueEntry {
+    pub name: String,
+    pub value: KeyValue,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyValue {
+    Section(KeyValues),
+    String(String),
+    Int(i32),
+    Float(f32),
+    Pointer(u32),
+    /// Wide strings can't be stored in the binary format and don't contain any data
+    WString,
+    Color([u8; 4]),
+    UInt64(u64),
+    CompiledIntByte(u8),
+    CompiledInt0,
+    CompiledInt1,
+}
+
+impl KeyValues {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&KeyValue> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+            .map(|entry| &entry.value)
+    }
+
+    /// Get a nested value by a `/` separated path
+    pub fn get_path(&self, path: &str) -> Option<&KeyValue> {
+        let mut parts = path.split('/');
+        let mut value = self.get(parts.next()?)?;
+        for part in parts {
+            value = value.as_section()?.get(part)?;
+        }
+        Some(value)
+    }
+
+    pub fn insert<S: Into<String>>(&mut self, name: S, value: KeyValue) {
+        self.entries.push(KeyValueEntry {
+            name: name.into(),
+            value,
+        });
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &KeyValue)> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.as_str(), &entry.value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl KeyValue {
+    pub fn as_section(&self) -> Option<&KeyValues> {
+        match self {
+            KeyValue::Section(section) => Some(section),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            KeyValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value as integer, strings are parsed the same way as `KeyValues::GetInt` does
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            KeyValue::Int(value) => Some(*value as i64),
+            KeyValue::Pointer(value) => Some(*value as i64),
+            KeyValue::UInt64(value) => Some(*value as i64),
+            KeyValue::Float(value) => Some(*value as i64),
+            KeyValue::CompiledIntByte(value) => Some(*value as i64),
+            KeyValue::CompiledInt0 => Some(0),
+            KeyValue::CompiledInt1 => Some(1),
+            KeyValue::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f32> {
+        match self {
+            KeyValue::Float(value) => Some(*value),
+            KeyValue::String(value) => value.trim().parse().ok(),
+            _ => self.as_int().map(|value| value as f32),
+        }
+    }
+}
+
+impl BitRead<'_, LittleEndian> for KeyValues {
+    fn read(stream: &mut Stream) -> ReadResult<Self> {
+        Self::read_section(stream, 0)
+    }
+}
+
+impl KeyValues {
+    fn read_section(stream: &mut Stream, depth: usize) -> ReadResult<Self> {
+        if depth > MAX_DEPTH {
+            return Err(BitError::UnmatchedDiscriminant {
+                discriminant: TYPE_NONE as usize,
+                enum_name: format!("KeyValue, sections nested deeper than {}", MAX_DEPTH),
+            });
+        }
+        let mut entries = Vec::new();
+        loop {
+            let ty: u8 = stream.read()?;
+            if ty == TYPE_END {
+                break;
+            }
+            let name = stream.read()?;
+            let value = match ty {
+                TYPE_NONE => KeyValue::Section(Self::read_section(stream, depth + 1)?),
+                TYPE_STRING => KeyValue::String(stream.read()?),
+                TYPE_INT => KeyValue::Int(stream.read()?),
+                TYPE_FLOAT => KeyValue::Float(stream.read()?),
+                TYPE_PTR => KeyValue::Pointer(stream.read()?),
+                TYPE_WSTRING => KeyValue::WString,
+                TYPE_COLOR => KeyValue::Color(stream.read()?),
+                TYPE_UINT64 => KeyValue::UInt64(stream.read()?),
+                TYPE_COMPILED_INT_BYTE => KeyValue::CompiledIntByte(stream.read()?),
+                TYPE_COMPILED_INT_0 => KeyValue::CompiledInt0,
+                TYPE_COMPILED_INT_1 => KeyValue::CompiledInt1,
+                _ => {
+                    return Err(BitError::UnmatchedDiscriminant {
+                        discriminant: ty as usize,
+                        enum_name: "KeyValue".into(),
+                    })
+                }
+            };
+            entries.push(KeyValueEntry { name, value });
+        }
+        Ok(KeyValues { entries })
+    }
+}
+
+impl BitWrite<LittleEndian> for KeyValues {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        for entry in &self.entries {
+            entry.value.type_id().write(stream)?;
+            entry.name.write(stream)?;
+            match &entry.value {
+                KeyValue::Section(section) => section.write(stream)?,
+                KeyValue::String(value) => value.write(stream)?,
+                KeyValue::Int(value) => value.write(stream)?,
+                KeyValue::Float(value) => value.write(stream)?,
+                KeyValue::Pointer(value) => value.write(stream)?,
+                KeyValue::Color(value) => value.write(stream)?,
+                KeyValue::UInt64(value) => value.write(stream)?,
+                KeyValue::CompiledIntByte(value) => value.write(stream)?,
+                KeyValue::WString | KeyValue::CompiledInt0 | KeyValue::CompiledInt1 => {}
+            }
+        }
+        TYPE_END.write(stream)
+    }
+}
+
+impl KeyValue {
+    fn type_id(&self) -> u8 {
+        match self {
+            KeyValue::Section(_) => TYPE_NONE,
+            KeyValue::String(_) => TYPE_STRING,
+            KeyValue::Int(_) => TYPE_INT,
+            KeyValue::Float(_) => TYPE_FLOAT,
+            KeyValue::Pointer(_) => TYPE_PTR,
+            KeyValue::WString => TYPE_WSTRING,
+            KeyValue::Color(_) => TYPE_COLOR,
+            KeyValue::UInt64(_) => TYPE_UINT64,
+            KeyValue::CompiledIntByte(_) => TYPE_COMPILED_INT_BYTE,
+            KeyValue::CompiledInt0 => TYPE_COMPILED_INT_0,
+            KeyValue::CompiledInt1 => TYPE_COMPILED_INT_1,
+        }
+    }
+}
+
+#[test]
+fn test_key_values_decode() {
+    use bitbuffer::{BitReadBuffer, BitReadStream};
+
+    let data = b"\x00ServerMsg\x00\x01text\x00hello\x00\x02count\x00\x05\x00\x00\x00\x0b\x0b";
+    let mut stream = BitReadStream::new(BitReadBuffer::new(data, LittleEndian));
+    let key_values: KeyValues = stream.read().unwrap();
+    assert_eq!(stream.bits_left(), 0);
+
+    assert_eq!(
+        key_values
+            .get_path("ServerMsg/text")
+            .and_then(KeyValue::as_str),
+        Some("hello")
+    );
+    assert_eq!(
+        key_values
+            .get_path("servermsg/count")
+            .and_then(KeyValue::as_int),
+        Some(5)
+    );
+}
+
+#[test]
+fn test_key_values_max_depth() {
+    use bitbuffer::{BitReadBuffer, BitReadStream};
+
+    let nested = |depth: usize| {
+        let mut data = b"\x00a\x00".repeat(depth);
+        data.resize(data.len() + depth + 1, TYPE_END);
+        data
+    };
+
+    let data = nested(MAX_DEPTH);
+    let mut stream = BitReadStream::new(BitReadBuffer::new(&data, LittleEndian));
+    assert!(stream.read::<KeyValues>().is_ok());
+
+    let data = nested(100_000);
+    let mut stream = BitReadStream::new(BitReadBuffer::new(&data, LittleEndian));
+    assert!(stream.read::<KeyValues>().is_err());
+}
+
+#[test]
+fn test_key_values_roundtrip() {
+    let mut inner = KeyValues::new();
+    inner.insert("title", KeyValue::String("Vote".into()));
+    inner.insert("level", KeyValue::Int(-3));
+    inner.insert("time", KeyValue::Float(1.5));
+    inner.insert("color", KeyValue::Color([255, 0, 0, 255]));
+    inner.insert("steamid", KeyValue::UInt64(76561198000000000));
+    inner.insert("flag", KeyValue::CompiledInt1);
+    let mut key_values = KeyValues::new();
+    key_values.insert("menu", KeyValue::Section(inner));
+    key_values.insert("empty", KeyValue::Section(KeyValues::new()));
+    crate::test_roundtrip_write(key_values);
+}
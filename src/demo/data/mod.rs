@@ -1,3 +1,5 @@
+pub mod conditions;
+pub mod keyvalues;
 pub mod userinfo;
 
 use bitbuffer::{BitRead, BitReadStream, BitWrite, BitWriteStream, Endianness};
@@ -7,6 +9,8 @@ use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, Sub};
 
+pub use conditions::{PlayerCondition, PlayerConditions};
+pub use keyvalues::{KeyValue, KeyValues};
 pub use userinfo::UserInfo;
 
 #[derive(Eq, PartialEq, Clone)]
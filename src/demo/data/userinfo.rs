@@ -67,6 +67,18 @@ pub struct UserInfo {
 }
 
 impl UserInfo {
+    /// Parse the user info from an entry of the `userinfo` string table
+    pub fn from_string_table_entry(
+        index: usize,
+        entry: &StringTableEntry,
+    ) -> ReadResult<Option<Self>> {
+        Self::parse_from_string_table(
+            index as u16,
+            entry.text.as_ref().map(|s| s.as_ref()),
+            entry.extra_data.as_ref().map(|data| data.data.clone()),
+        )
+    }
+
     pub fn parse_from_string_table(
         index: u16,
         text: Option<&str>,
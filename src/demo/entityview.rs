@@ -0,0 +1,367 @@
+//! Typed access to the props of an entity
+//!
+//! The views in [`entityview_gen`](crate::demo::entityview_gen) are generated from the send tables by
+//! the `codegen` binary with `codegen <demo> views [classes..]`
+//!
+//! For tracking a handful of props over time, [`FromEntity`] can be derived for a struct instead
+//!
+//! ```
+//! use tf_demo_parser::demo::entityview::FromEntity;
+//! use tf_demo_parser::demo::vector::Vector;
+//!
+//! #[derive(Default, FromEntity)]
+//! #[entity(class = "CObjectSentrygun")]
+//! struct Sentry {
+//!     #[prop("DT_ObjectSentrygun", "m_iAmmoShells")]
+//!     shells: u16,
+//!     #[prop("DT_BaseEntity", "m_vecOrigin", unit = "meters")]
+//!     position: Vector,
+//! }
+//! ```
+
+use crate::demo::message::packetentities::{EntityHandle, PacketEntity};
+use crate::demo::parser::entityworld::WorldEntity;
+use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::demo::vector::{Vector, VectorXY};
+use crate::ParserState;
+use std::borrow::Borrow;
+pub use tf_demo_parser_derive::FromEntity;
+
+/// Something containing entity props that a typed view can be created for
+pub trait EntityProps {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue>;
+
+    fn get_value<T: FromPropValue>(&self, identifier: SendPropIdentifier) -> Option<T> {
+        self.prop_value(identifier)
+            .and_then(FromPropValue::from_prop_value)
+    }
+
+    /// Combine a vector that is sent as separate xy and z props
+    fn get_split_vector(&self, xy: SendPropIdentifier, z: SendPropIdentifier) -> Option<Vector> {
+        let xy: VectorXY = self.get_value(xy)?;
+        let z: f32 = self.get_value(z)?;
+        Some(Vector {
+            x: xy.x,
+            y: xy.y,
+            z,
+        })
+    }
+}
+
+impl EntityProps for [SendProp] {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue> {
+        self.iter()
+            .find(|prop| prop.identifier == identifier)
+            .map(|prop| &prop.value)
+    }
+}
+
+impl EntityProps for Vec<SendProp> {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue> {
+        self.as_slice().prop_value(identifier)
+    }
+}
+
+/// Only contains the props included in the update, without the baseline
+impl EntityProps for PacketEntity {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue> {
+        self.props.prop_value(identifier)
+    }
+}
+
+impl EntityProps for WorldEntity {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue> {
+        self.get_prop(identifier)
+    }
+}
+
+/// Conversion from a prop value into the rust type matching the prop definition
+pub trait FromPropValue: Sized {
+    fn from_prop_value(value: &SendPropValue) -> Option<Self>;
+}
+
+macro_rules! impl_from_int_prop {
+    ($($ty:ty),*) => {
+        $(
+            impl FromPropValue for $ty {
+                fn from_prop_value(value: &SendPropValue) -> Option<Self> {
+                    i64::try_from(value).ok().map(|value| value as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int_prop!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl FromPropValue for bool {
+    fn from_prop_value(value: &SendPropValue) -> Option<Self> {
+        i64::try_from(value).ok().map(|value| value != 0)
+    }
+}
+
+impl FromPropValue for f32 {
+    fn from_prop_value(value: &SendPropValue) -> Option<Self> {
+        f32::try_from(value).ok()
+    }
+}
+
+impl FromPropValue for Vector {
+    fn from_prop_value(value: &SendPropValue) -> Option<Self> {
+        Vector::try_from(value).ok()
+    }
+}
+
+impl FromPropValue for VectorXY {
+    fn from_prop_value(value: &SendPropValue) -> Option<Self> {
+        VectorXY::try_from(value).ok()
+    }
+}
+
+impl FromPropValue for String {
+    fn from_prop_value(value: &SendPropValue) -> Option<Self> {
+        match value {
+            SendPropValue::String(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromPropValue for EntityHandle {
+    fn from_prop_value(value: &SendPropValue) -> Option<Self> {
+        EntityHandle::try_from(value).ok()
+    }
+}
+
+impl<T: FromPropValue> FromPropValue for Vec<T> {
+    fn from_prop_value(value: &SendPropValue) -> Option<Self> {
+        match value {
+            SendPropValue::Array(values) => values.iter().map(T::from_prop_value).collect(),
+            _ => None,
+        }
+    }
+}
+
+/// A struct that tracks the props of an entity, see [`FromEntity`](derive@FromEntity) for deriving it
+///
+/// Fields are only updated for props that are included in the update,
+/// applying all updates for an entity results in its current state
+pub trait FromEntity {
+    /// The server class this struct is created from
+    const CLASS: &'static str;
+
+    /// Apply a single prop, returns `false` if the prop isn't tracked by this struct
+    fn apply_prop(&mut self, prop: &SendProp, state: &ParserState) -> bool;
+
+    fn apply_props<P: Borrow<SendProp>>(
+        &mut self,
+        props: impl IntoIterator<Item = P>,
+        state: &ParserState,
+    ) {
+        for prop in props {
+            self.apply_prop(prop.borrow(), state);
+        }
+    }
+
+    /// Apply the props from a packet entity, including the baseline for entering entities
+    fn apply_entity(&mut self, entity: &PacketEntity, state: &ParserState) {
+        self.apply_props(entity.props(state), state);
+    }
+
+    fn from_entity(entity: &PacketEntity, state: &ParserState) -> Self
+    where
+        Self: Default,
+    {
+        let mut value = Self::default();
+        value.apply_entity(entity, state);
+        value
+    }
+}
+
+/// Unit conversions that can be applied to a prop value with `#[prop(.., unit = "..")]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropUnit {
+    /// Angles are send in degrees
+    Radians,
+    /// Coordinates are send in hammer units
+    Meters,
+}
+
+impl PropUnit {
+    pub const fn factor(self) -> f32 {
+        match self {
+            PropUnit::Radians => std::f32::consts::PI / 180.0,
+            PropUnit::Meters => 0.01905,
+        }
+    }
+}
+
+/// Prop values that can be converted to a different unit
+pub trait ScaleProp {
+    fn scale(self, factor: f32) -> Self;
+}
+
+impl ScaleProp for f32 {
+    fn scale(self, factor: f32) -> Self {
+        self * factor
+    }
+}
+
+impl ScaleProp for Vector {
+    fn scale(self, factor: f32) -> Self {
+        Vector {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+}
+
+impl ScaleProp for VectorXY {
+    fn scale(self, factor: f32) -> Self {
+        VectorXY {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+#[test]
+fn test_generated_view() {
+    use crate::demo::entityview_gen::CTFPlayerView;
+    use crate::demo::message::packetentities::EntityId;
+
+    let props = vec![
+        SendProp {
+            index: 0,
+            identifier: SendPropIdentifier::new("DT_BasePlayer", "m_iHealth"),
+            value: SendPropValue::Integer(125),
+        },
+        SendProp {
+            index: 1,
+            identifier: SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin"),
+            value: SendPropValue::VectorXY(VectorXY { x: 1.0, y: 2.0 }),
+        },
+        SendProp {
+            index: 2,
+            identifier: SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin[2]"),
+            value: SendPropValue::Float(3.0),
+        },
+        SendProp {
+            index: 3,
+            identifier: SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon"),
+            value: SendPropValue::Integer(
+                u32::from(EntityHandle::new(EntityId::from(12u32), 3)) as i64
+            ),
+        },
+    ];
+    let view = CTFPlayerView::new(&props);
+    assert_eq!(CTFPlayerView::<[SendProp]>::CLASS, "CTFPlayer");
+    assert_eq!(Some(125), view.health());
+    assert_eq!(
+        Some(VectorXY { x: 1.0, y: 2.0 }),
+        view.tf_local_player_exclusive_origin()
+    );
+    assert_eq!(
+        Some(Vector {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        }),
+        view.origin()
+    );
+    assert_eq!(
+        Some(EntityId::from(12u32)),
+        view.active_weapon().and_then(|handle| handle.entity())
+    );
+    assert_eq!(None, view.class());
+}
+
+#[test]
+fn test_derive_from_entity() {
+    use crate::demo::message::packetentities::EntityId;
+    use crate::demo::vector::Vector;
+
+    fn handle_entity(value: &SendPropValue, _state: &ParserState) -> Option<EntityId> {
+        EntityHandle::try_from(value).ok()?.entity()
+    }
+
+    #[derive(Default, Debug, PartialEq, FromEntity)]
+    #[entity(class = "CTFPlayer")]
+    struct Player {
+        #[prop("DT_BasePlayer", "m_iHealth")]
+        health: u16,
+        #[prop("DT_TFLocalPlayerExclusive", "m_vecOrigin", xy)]
+        #[prop("DT_TFNonLocalPlayerExclusive", "m_vecOrigin", xy)]
+        #[prop("DT_TFLocalPlayerExclusive", "m_vecOrigin[2]", z)]
+        #[prop("DT_TFNonLocalPlayerExclusive", "m_vecOrigin[2]", z)]
+        position: Vector,
+        #[prop("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[1]", unit = "radians")]
+        view_angle: f32,
+        #[prop("DT_BaseCombatCharacter", "m_hActiveWeapon", with = "handle_entity")]
+        weapon: EntityId,
+        untracked: u8,
+    }
+
+    let state = ParserState::new(24, |_| true, false);
+    let prop = |table, name, value| SendProp {
+        index: 0,
+        identifier: SendPropIdentifier::new(table, name),
+        value,
+    };
+
+    let mut player = Player::default();
+    assert_eq!("CTFPlayer", Player::CLASS);
+    player.apply_props(
+        [
+            prop("DT_BasePlayer", "m_iHealth", SendPropValue::Integer(150)),
+            prop(
+                "DT_TFNonLocalPlayerExclusive",
+                "m_vecOrigin",
+                SendPropValue::VectorXY(VectorXY { x: 1.0, y: 2.0 }),
+            ),
+            prop(
+                "DT_TFNonLocalPlayerExclusive",
+                "m_vecOrigin[2]",
+                SendPropValue::Float(3.0),
+            ),
+            prop(
+                "DT_TFNonLocalPlayerExclusive",
+                "m_angEyeAngles[1]",
+                SendPropValue::Float(180.0),
+            ),
+            prop(
+                "DT_BaseCombatCharacter",
+                "m_hActiveWeapon",
+                SendPropValue::Integer(u32::from(EntityHandle::new(EntityId::from(5u32), 1)) as i64),
+            ),
+        ],
+        &state,
+    );
+    assert_eq!(
+        Player {
+            health: 150,
+            position: Vector {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0
+            },
+            view_angle: std::f32::consts::PI,
+            weapon: EntityId::from(5u32),
+            untracked: 0,
+        },
+        player
+    );
+
+    // delta updates only touch the included props
+    assert!(player.apply_prop(
+        &prop("DT_BasePlayer", "m_iHealth", SendPropValue::Integer(10)),
+        &state
+    ));
+    assert!(!player.apply_prop(
+        &prop("DT_BasePlayer", "m_iMaxHealth", SendPropValue::Integer(10)),
+        &state
+    ));
+    assert_eq!(10, player.health);
+    assert_eq!(3.0, player.position.z);
+}
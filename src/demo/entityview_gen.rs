@@ -0,0 +1,10862 @@
+use crate::demo::entityview::EntityProps;
+use crate::demo::message::packetentities::EntityHandle;
+use crate::demo::sendprop::SendPropIdentifier;
+use crate::demo::vector::{Vector, VectorXY};
+///Typed access to the props of a `CObjectDispenser` entity
+pub struct CObjectDispenserView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CObjectDispenserView<'a, E> {
+    pub const CLASS: &'static str = "CObjectDispenser";
+    pub fn new(entity: &'a E) -> Self {
+        CObjectDispenserView { entity }
+    }
+    pub fn entity(&self) -> &'a E {
+        self.entity
+    }
+    ///`DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub fn anim_time(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub fn uniform_trigger_bloat(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_LPT_m_AnimOverlay_15.lengthprop15`
+    pub fn lengthprop_15(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_AnimOverlay_15", "lengthprop15");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn prev_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn weight(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn order(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_2(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_2(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_3(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_3(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_4(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_4(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_5(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_5(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_6(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_6(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_7(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_7(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_8(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_8(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_9(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_9(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_10(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_10(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_11(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_11(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_12(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_12(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_13(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_13(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_14(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_15(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_14(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.000`
+    pub fn flex_weight_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.001`
+    pub fn flex_weight_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.002`
+    pub fn flex_weight_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.003`
+    pub fn flex_weight_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.004`
+    pub fn flex_weight_004(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.005`
+    pub fn flex_weight_005(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.006`
+    pub fn flex_weight_006(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.007`
+    pub fn flex_weight_007(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.008`
+    pub fn flex_weight_008(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.009`
+    pub fn flex_weight_009(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.010`
+    pub fn flex_weight_010(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.011`
+    pub fn flex_weight_011(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.012`
+    pub fn flex_weight_012(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.013`
+    pub fn flex_weight_013(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.014`
+    pub fn flex_weight_014(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.015`
+    pub fn flex_weight_015(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.016`
+    pub fn flex_weight_016(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.017`
+    pub fn flex_weight_017(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.018`
+    pub fn flex_weight_018(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.019`
+    pub fn flex_weight_019(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.020`
+    pub fn flex_weight_020(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.021`
+    pub fn flex_weight_021(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.022`
+    pub fn flex_weight_022(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.023`
+    pub fn flex_weight_023(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.024`
+    pub fn flex_weight_024(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.025`
+    pub fn flex_weight_025(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.026`
+    pub fn flex_weight_026(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.027`
+    pub fn flex_weight_027(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.028`
+    pub fn flex_weight_028(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.029`
+    pub fn flex_weight_029(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.030`
+    pub fn flex_weight_030(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.031`
+    pub fn flex_weight_031(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.032`
+    pub fn flex_weight_032(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.033`
+    pub fn flex_weight_033(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.034`
+    pub fn flex_weight_034(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.035`
+    pub fn flex_weight_035(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.036`
+    pub fn flex_weight_036(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.037`
+    pub fn flex_weight_037(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.038`
+    pub fn flex_weight_038(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.039`
+    pub fn flex_weight_039(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.040`
+    pub fn flex_weight_040(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.041`
+    pub fn flex_weight_041(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.042`
+    pub fn flex_weight_042(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.043`
+    pub fn flex_weight_043(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.044`
+    pub fn flex_weight_044(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.045`
+    pub fn flex_weight_045(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.046`
+    pub fn flex_weight_046(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.047`
+    pub fn flex_weight_047(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.048`
+    pub fn flex_weight_048(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "048");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.049`
+    pub fn flex_weight_049(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "049");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.050`
+    pub fn flex_weight_050(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "050");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.051`
+    pub fn flex_weight_051(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "051");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.052`
+    pub fn flex_weight_052(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "052");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.053`
+    pub fn flex_weight_053(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "053");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.054`
+    pub fn flex_weight_054(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "054");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.055`
+    pub fn flex_weight_055(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "055");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.056`
+    pub fn flex_weight_056(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "056");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.057`
+    pub fn flex_weight_057(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "057");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.058`
+    pub fn flex_weight_058(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "058");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.059`
+    pub fn flex_weight_059(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "059");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.060`
+    pub fn flex_weight_060(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "060");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.061`
+    pub fn flex_weight_061(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "061");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.062`
+    pub fn flex_weight_062(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "062");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.063`
+    pub fn flex_weight_063(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "063");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.064`
+    pub fn flex_weight_064(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "064");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.065`
+    pub fn flex_weight_065(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "065");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.066`
+    pub fn flex_weight_066(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "066");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.067`
+    pub fn flex_weight_067(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "067");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.068`
+    pub fn flex_weight_068(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "068");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.069`
+    pub fn flex_weight_069(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "069");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.070`
+    pub fn flex_weight_070(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "070");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.071`
+    pub fn flex_weight_071(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "071");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.072`
+    pub fn flex_weight_072(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "072");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.073`
+    pub fn flex_weight_073(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "073");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.074`
+    pub fn flex_weight_074(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "074");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.075`
+    pub fn flex_weight_075(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "075");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.076`
+    pub fn flex_weight_076(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "076");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.077`
+    pub fn flex_weight_077(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "077");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.078`
+    pub fn flex_weight_078(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "078");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.079`
+    pub fn flex_weight_079(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "079");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.080`
+    pub fn flex_weight_080(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "080");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.081`
+    pub fn flex_weight_081(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "081");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.082`
+    pub fn flex_weight_082(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "082");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.083`
+    pub fn flex_weight_083(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "083");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.084`
+    pub fn flex_weight_084(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "084");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.085`
+    pub fn flex_weight_085(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "085");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.086`
+    pub fn flex_weight_086(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "086");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.087`
+    pub fn flex_weight_087(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "087");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.088`
+    pub fn flex_weight_088(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "088");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.089`
+    pub fn flex_weight_089(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "089");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.090`
+    pub fn flex_weight_090(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "090");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.091`
+    pub fn flex_weight_091(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "091");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.092`
+    pub fn flex_weight_092(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "092");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.093`
+    pub fn flex_weight_093(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "093");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.094`
+    pub fn flex_weight_094(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "094");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.095`
+    pub fn flex_weight_095(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "095");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub fn next_attack(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.000`
+    pub fn my_weapons_000(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.001`
+    pub fn my_weapons_001(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.002`
+    pub fn my_weapons_002(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.003`
+    pub fn my_weapons_003(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.004`
+    pub fn my_weapons_004(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.005`
+    pub fn my_weapons_005(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.006`
+    pub fn my_weapons_006(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.007`
+    pub fn my_weapons_007(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.008`
+    pub fn my_weapons_008(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.009`
+    pub fn my_weapons_009(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.010`
+    pub fn my_weapons_010(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.011`
+    pub fn my_weapons_011(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.012`
+    pub fn my_weapons_012(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.013`
+    pub fn my_weapons_013(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.014`
+    pub fn my_weapons_014(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.015`
+    pub fn my_weapons_015(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.016`
+    pub fn my_weapons_016(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.017`
+    pub fn my_weapons_017(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.018`
+    pub fn my_weapons_018(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.019`
+    pub fn my_weapons_019(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.020`
+    pub fn my_weapons_020(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.021`
+    pub fn my_weapons_021(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.022`
+    pub fn my_weapons_022(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.023`
+    pub fn my_weapons_023(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.024`
+    pub fn my_weapons_024(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.025`
+    pub fn my_weapons_025(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.026`
+    pub fn my_weapons_026(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.027`
+    pub fn my_weapons_027(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.028`
+    pub fn my_weapons_028(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.029`
+    pub fn my_weapons_029(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.030`
+    pub fn my_weapons_030(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.031`
+    pub fn my_weapons_031(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.032`
+    pub fn my_weapons_032(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.033`
+    pub fn my_weapons_033(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.034`
+    pub fn my_weapons_034(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.035`
+    pub fn my_weapons_035(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.036`
+    pub fn my_weapons_036(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.037`
+    pub fn my_weapons_037(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.038`
+    pub fn my_weapons_038(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.039`
+    pub fn my_weapons_039(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.040`
+    pub fn my_weapons_040(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.041`
+    pub fn my_weapons_041(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.042`
+    pub fn my_weapons_042(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.043`
+    pub fn my_weapons_043(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.044`
+    pub fn my_weapons_044(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.045`
+    pub fn my_weapons_045(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.046`
+    pub fn my_weapons_046(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.047`
+    pub fn my_weapons_047(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nSequence`
+    pub fn base_animating_sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseFlex.m_blinktoggle`
+    pub fn blinktoggle(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseFlex.m_viewtarget`
+    pub fn viewtarget(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub fn glow_enabled(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub fn active_weapon(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iHealth`
+    pub fn health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iMaxHealth`
+    pub fn max_health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bHasSapper`
+    pub fn has_sapper(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iObjectType`
+    pub fn object_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bBuilding`
+    pub fn building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bPlacing`
+    pub fn placing(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlacing");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bCarried`
+    pub fn carried(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarried");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bCarryDeploy`
+    pub fn carry_deploy(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bMiniBuilding`
+    pub fn mini_building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_flPercentageConstructed`
+    pub fn percentage_constructed(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_fObjectFlags`
+    pub fn object_flags(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_hBuiltOnEntity`
+    pub fn built_on_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bDisabled`
+    pub fn disabled(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_hBuilder`
+    pub fn builder(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuilder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_vecBuildMaxs`
+    pub fn build_maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_vecBuildMins`
+    pub fn build_mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iDesiredBuildRotations`
+    pub fn desired_build_rotations(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bServerOverridePlacement`
+    pub fn server_override_placement(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeLevel`
+    pub fn upgrade_level(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeMetal`
+    pub fn upgrade_metal(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeMetalRequired`
+    pub fn upgrade_metal_required(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iHighestUpgradeLevel`
+    pub fn highest_upgrade_level(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iObjectMode`
+    pub fn object_mode(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bDisposableBuilding`
+    pub fn disposable_building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bWasMapPlaced`
+    pub fn was_map_placed(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bWasMapPlaced");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bPlasmaDisable`
+    pub fn plasma_disable(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlasmaDisable");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectDispenser.m_iState`
+    pub fn state(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectDispenser", "m_iState");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectDispenser.m_iAmmoMetal`
+    pub fn ammo_metal(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectDispenser", "m_iAmmoMetal");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectDispenser.m_iMiniBombCounter`
+    pub fn mini_bomb_counter(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectDispenser", "m_iMiniBombCounter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectDispenser."healing_array"`
+    pub fn healing_array(&self) -> Option<Vec<u32>> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectDispenser", "\"healing_array\"");
+        self.entity.get_value(IDENTIFIER)
+    }
+}
+///Typed access to the props of a `CObjectSentrygun` entity
+pub struct CObjectSentrygunView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CObjectSentrygunView<'a, E> {
+    pub const CLASS: &'static str = "CObjectSentrygun";
+    pub fn new(entity: &'a E) -> Self {
+        CObjectSentrygunView { entity }
+    }
+    pub fn entity(&self) -> &'a E {
+        self.entity
+    }
+    ///`DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub fn anim_time(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_SentrygunLocalData.m_iKills`
+    pub fn kills(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_SentrygunLocalData", "m_iKills");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_SentrygunLocalData.m_iAssists`
+    pub fn assists(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_SentrygunLocalData", "m_iAssists");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectSentrygun.m_iAmmoShells`
+    pub fn ammo_shells(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_iAmmoShells");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectSentrygun.m_iAmmoRockets`
+    pub fn ammo_rockets(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_iAmmoRockets");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub fn uniform_trigger_bloat(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_LPT_m_AnimOverlay_15.lengthprop15`
+    pub fn lengthprop_15(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_AnimOverlay_15", "lengthprop15");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn prev_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn weight(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn order(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_2(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_2(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_3(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_3(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_4(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_4(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_5(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_5(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_6(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_6(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_7(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_7(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_8(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_8(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_9(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_9(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_10(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_10(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_11(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_11(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_12(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_12(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_13(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_13(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_14(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_15(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_14(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.000`
+    pub fn flex_weight_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.001`
+    pub fn flex_weight_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.002`
+    pub fn flex_weight_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.003`
+    pub fn flex_weight_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.004`
+    pub fn flex_weight_004(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.005`
+    pub fn flex_weight_005(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.006`
+    pub fn flex_weight_006(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.007`
+    pub fn flex_weight_007(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.008`
+    pub fn flex_weight_008(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.009`
+    pub fn flex_weight_009(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.010`
+    pub fn flex_weight_010(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.011`
+    pub fn flex_weight_011(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.012`
+    pub fn flex_weight_012(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.013`
+    pub fn flex_weight_013(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.014`
+    pub fn flex_weight_014(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.015`
+    pub fn flex_weight_015(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.016`
+    pub fn flex_weight_016(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.017`
+    pub fn flex_weight_017(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.018`
+    pub fn flex_weight_018(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.019`
+    pub fn flex_weight_019(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.020`
+    pub fn flex_weight_020(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.021`
+    pub fn flex_weight_021(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.022`
+    pub fn flex_weight_022(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.023`
+    pub fn flex_weight_023(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.024`
+    pub fn flex_weight_024(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.025`
+    pub fn flex_weight_025(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.026`
+    pub fn flex_weight_026(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.027`
+    pub fn flex_weight_027(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.028`
+    pub fn flex_weight_028(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.029`
+    pub fn flex_weight_029(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.030`
+    pub fn flex_weight_030(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.031`
+    pub fn flex_weight_031(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.032`
+    pub fn flex_weight_032(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.033`
+    pub fn flex_weight_033(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.034`
+    pub fn flex_weight_034(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.035`
+    pub fn flex_weight_035(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.036`
+    pub fn flex_weight_036(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.037`
+    pub fn flex_weight_037(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.038`
+    pub fn flex_weight_038(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.039`
+    pub fn flex_weight_039(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.040`
+    pub fn flex_weight_040(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.041`
+    pub fn flex_weight_041(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.042`
+    pub fn flex_weight_042(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.043`
+    pub fn flex_weight_043(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.044`
+    pub fn flex_weight_044(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.045`
+    pub fn flex_weight_045(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.046`
+    pub fn flex_weight_046(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.047`
+    pub fn flex_weight_047(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.048`
+    pub fn flex_weight_048(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "048");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.049`
+    pub fn flex_weight_049(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "049");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.050`
+    pub fn flex_weight_050(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "050");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.051`
+    pub fn flex_weight_051(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "051");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.052`
+    pub fn flex_weight_052(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "052");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.053`
+    pub fn flex_weight_053(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "053");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.054`
+    pub fn flex_weight_054(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "054");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.055`
+    pub fn flex_weight_055(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "055");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.056`
+    pub fn flex_weight_056(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "056");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.057`
+    pub fn flex_weight_057(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "057");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.058`
+    pub fn flex_weight_058(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "058");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.059`
+    pub fn flex_weight_059(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "059");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.060`
+    pub fn flex_weight_060(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "060");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.061`
+    pub fn flex_weight_061(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "061");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.062`
+    pub fn flex_weight_062(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "062");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.063`
+    pub fn flex_weight_063(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "063");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.064`
+    pub fn flex_weight_064(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "064");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.065`
+    pub fn flex_weight_065(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "065");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.066`
+    pub fn flex_weight_066(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "066");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.067`
+    pub fn flex_weight_067(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "067");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.068`
+    pub fn flex_weight_068(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "068");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.069`
+    pub fn flex_weight_069(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "069");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.070`
+    pub fn flex_weight_070(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "070");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.071`
+    pub fn flex_weight_071(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "071");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.072`
+    pub fn flex_weight_072(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "072");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.073`
+    pub fn flex_weight_073(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "073");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.074`
+    pub fn flex_weight_074(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "074");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.075`
+    pub fn flex_weight_075(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "075");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.076`
+    pub fn flex_weight_076(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "076");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.077`
+    pub fn flex_weight_077(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "077");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.078`
+    pub fn flex_weight_078(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "078");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.079`
+    pub fn flex_weight_079(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "079");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.080`
+    pub fn flex_weight_080(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "080");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.081`
+    pub fn flex_weight_081(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "081");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.082`
+    pub fn flex_weight_082(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "082");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.083`
+    pub fn flex_weight_083(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "083");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.084`
+    pub fn flex_weight_084(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "084");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.085`
+    pub fn flex_weight_085(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "085");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.086`
+    pub fn flex_weight_086(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "086");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.087`
+    pub fn flex_weight_087(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "087");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.088`
+    pub fn flex_weight_088(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "088");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.089`
+    pub fn flex_weight_089(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "089");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.090`
+    pub fn flex_weight_090(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "090");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.091`
+    pub fn flex_weight_091(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "091");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.092`
+    pub fn flex_weight_092(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "092");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.093`
+    pub fn flex_weight_093(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "093");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.094`
+    pub fn flex_weight_094(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "094");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.095`
+    pub fn flex_weight_095(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "095");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub fn next_attack(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.000`
+    pub fn my_weapons_000(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.001`
+    pub fn my_weapons_001(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.002`
+    pub fn my_weapons_002(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.003`
+    pub fn my_weapons_003(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.004`
+    pub fn my_weapons_004(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.005`
+    pub fn my_weapons_005(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.006`
+    pub fn my_weapons_006(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.007`
+    pub fn my_weapons_007(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.008`
+    pub fn my_weapons_008(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.009`
+    pub fn my_weapons_009(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.010`
+    pub fn my_weapons_010(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.011`
+    pub fn my_weapons_011(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.012`
+    pub fn my_weapons_012(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.013`
+    pub fn my_weapons_013(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.014`
+    pub fn my_weapons_014(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.015`
+    pub fn my_weapons_015(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.016`
+    pub fn my_weapons_016(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.017`
+    pub fn my_weapons_017(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.018`
+    pub fn my_weapons_018(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.019`
+    pub fn my_weapons_019(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.020`
+    pub fn my_weapons_020(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.021`
+    pub fn my_weapons_021(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.022`
+    pub fn my_weapons_022(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.023`
+    pub fn my_weapons_023(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.024`
+    pub fn my_weapons_024(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.025`
+    pub fn my_weapons_025(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.026`
+    pub fn my_weapons_026(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.027`
+    pub fn my_weapons_027(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.028`
+    pub fn my_weapons_028(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.029`
+    pub fn my_weapons_029(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.030`
+    pub fn my_weapons_030(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.031`
+    pub fn my_weapons_031(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.032`
+    pub fn my_weapons_032(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.033`
+    pub fn my_weapons_033(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.034`
+    pub fn my_weapons_034(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.035`
+    pub fn my_weapons_035(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.036`
+    pub fn my_weapons_036(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.037`
+    pub fn my_weapons_037(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.038`
+    pub fn my_weapons_038(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.039`
+    pub fn my_weapons_039(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.040`
+    pub fn my_weapons_040(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.041`
+    pub fn my_weapons_041(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.042`
+    pub fn my_weapons_042(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.043`
+    pub fn my_weapons_043(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.044`
+    pub fn my_weapons_044(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.045`
+    pub fn my_weapons_045(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.046`
+    pub fn my_weapons_046(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.047`
+    pub fn my_weapons_047(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nSequence`
+    pub fn base_animating_sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseFlex.m_blinktoggle`
+    pub fn blinktoggle(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseFlex.m_viewtarget`
+    pub fn viewtarget(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub fn glow_enabled(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub fn active_weapon(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iHealth`
+    pub fn health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iMaxHealth`
+    pub fn max_health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bHasSapper`
+    pub fn has_sapper(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iObjectType`
+    pub fn object_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bBuilding`
+    pub fn building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bPlacing`
+    pub fn placing(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlacing");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bCarried`
+    pub fn carried(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarried");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bCarryDeploy`
+    pub fn carry_deploy(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bMiniBuilding`
+    pub fn mini_building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_flPercentageConstructed`
+    pub fn percentage_constructed(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_fObjectFlags`
+    pub fn object_flags(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_hBuiltOnEntity`
+    pub fn built_on_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bDisabled`
+    pub fn disabled(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_hBuilder`
+    pub fn builder(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuilder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_vecBuildMaxs`
+    pub fn build_maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_vecBuildMins`
+    pub fn build_mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iDesiredBuildRotations`
+    pub fn desired_build_rotations(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bServerOverridePlacement`
+    pub fn server_override_placement(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeLevel`
+    pub fn upgrade_level(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeMetal`
+    pub fn upgrade_metal(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeMetalRequired`
+    pub fn upgrade_metal_required(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iHighestUpgradeLevel`
+    pub fn highest_upgrade_level(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iObjectMode`
+    pub fn object_mode(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bDisposableBuilding`
+    pub fn disposable_building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bWasMapPlaced`
+    pub fn was_map_placed(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bWasMapPlaced");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bPlasmaDisable`
+    pub fn plasma_disable(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlasmaDisable");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectSentrygun.m_iState`
+    pub fn state(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_iState");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectSentrygun.m_bPlayerControlled`
+    pub fn player_controlled(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_bPlayerControlled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectSentrygun.m_nShieldLevel`
+    pub fn shield_level(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_nShieldLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectSentrygun.m_hEnemy`
+    pub fn enemy(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_hEnemy");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectSentrygun.m_hAutoAimTarget`
+    pub fn auto_aim_target(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_hAutoAimTarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+}
+///Typed access to the props of a `CObjectTeleporter` entity
+pub struct CObjectTeleporterView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CObjectTeleporterView<'a, E> {
+    pub const CLASS: &'static str = "CObjectTeleporter";
+    pub fn new(entity: &'a E) -> Self {
+        CObjectTeleporterView { entity }
+    }
+    pub fn entity(&self) -> &'a E {
+        self.entity
+    }
+    ///`DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub fn anim_time(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub fn uniform_trigger_bloat(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flPoseParameter", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_LPT_m_AnimOverlay_15.lengthprop15`
+    pub fn lengthprop_15(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_AnimOverlay_15", "lengthprop15");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn prev_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn weight(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn order(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_2(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_2(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_3(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_3(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_3(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_4(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_4(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_4(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_5(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_5(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_5(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_6(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_6(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_6(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_7(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_7(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_7(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_8(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_8(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_8(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_9(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_9(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_9(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_10(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_10(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_10(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_11(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_11(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_11(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_12(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_12(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_12(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_13(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_13(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_13(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nSequence`
+    pub fn animationlayer_sequence_14(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle_15(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub fn animationlayer_prev_cycle_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_flWeight`
+    pub fn animationlayer_weight_14(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Animationlayer.m_nOrder`
+    pub fn animationlayer_order_14(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.000`
+    pub fn flex_weight_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.001`
+    pub fn flex_weight_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.002`
+    pub fn flex_weight_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.003`
+    pub fn flex_weight_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.004`
+    pub fn flex_weight_004(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.005`
+    pub fn flex_weight_005(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.006`
+    pub fn flex_weight_006(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.007`
+    pub fn flex_weight_007(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.008`
+    pub fn flex_weight_008(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.009`
+    pub fn flex_weight_009(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.010`
+    pub fn flex_weight_010(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.011`
+    pub fn flex_weight_011(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.012`
+    pub fn flex_weight_012(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.013`
+    pub fn flex_weight_013(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.014`
+    pub fn flex_weight_014(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.015`
+    pub fn flex_weight_015(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.016`
+    pub fn flex_weight_016(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.017`
+    pub fn flex_weight_017(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.018`
+    pub fn flex_weight_018(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.019`
+    pub fn flex_weight_019(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.020`
+    pub fn flex_weight_020(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.021`
+    pub fn flex_weight_021(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.022`
+    pub fn flex_weight_022(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.023`
+    pub fn flex_weight_023(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.024`
+    pub fn flex_weight_024(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.025`
+    pub fn flex_weight_025(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.026`
+    pub fn flex_weight_026(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.027`
+    pub fn flex_weight_027(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.028`
+    pub fn flex_weight_028(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.029`
+    pub fn flex_weight_029(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.030`
+    pub fn flex_weight_030(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.031`
+    pub fn flex_weight_031(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.032`
+    pub fn flex_weight_032(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.033`
+    pub fn flex_weight_033(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.034`
+    pub fn flex_weight_034(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.035`
+    pub fn flex_weight_035(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.036`
+    pub fn flex_weight_036(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.037`
+    pub fn flex_weight_037(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.038`
+    pub fn flex_weight_038(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.039`
+    pub fn flex_weight_039(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.040`
+    pub fn flex_weight_040(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.041`
+    pub fn flex_weight_041(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.042`
+    pub fn flex_weight_042(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.043`
+    pub fn flex_weight_043(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.044`
+    pub fn flex_weight_044(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.045`
+    pub fn flex_weight_045(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.046`
+    pub fn flex_weight_046(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.047`
+    pub fn flex_weight_047(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.048`
+    pub fn flex_weight_048(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "048");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.049`
+    pub fn flex_weight_049(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "049");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.050`
+    pub fn flex_weight_050(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "050");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.051`
+    pub fn flex_weight_051(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "051");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.052`
+    pub fn flex_weight_052(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "052");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.053`
+    pub fn flex_weight_053(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "053");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.054`
+    pub fn flex_weight_054(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "054");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.055`
+    pub fn flex_weight_055(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "055");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.056`
+    pub fn flex_weight_056(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "056");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.057`
+    pub fn flex_weight_057(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "057");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.058`
+    pub fn flex_weight_058(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "058");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.059`
+    pub fn flex_weight_059(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "059");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.060`
+    pub fn flex_weight_060(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "060");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.061`
+    pub fn flex_weight_061(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "061");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.062`
+    pub fn flex_weight_062(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "062");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.063`
+    pub fn flex_weight_063(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "063");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.064`
+    pub fn flex_weight_064(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "064");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.065`
+    pub fn flex_weight_065(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "065");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.066`
+    pub fn flex_weight_066(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "066");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.067`
+    pub fn flex_weight_067(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "067");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.068`
+    pub fn flex_weight_068(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "068");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.069`
+    pub fn flex_weight_069(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "069");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.070`
+    pub fn flex_weight_070(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "070");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.071`
+    pub fn flex_weight_071(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "071");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.072`
+    pub fn flex_weight_072(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "072");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.073`
+    pub fn flex_weight_073(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "073");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.074`
+    pub fn flex_weight_074(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "074");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.075`
+    pub fn flex_weight_075(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "075");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.076`
+    pub fn flex_weight_076(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "076");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.077`
+    pub fn flex_weight_077(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "077");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.078`
+    pub fn flex_weight_078(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "078");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.079`
+    pub fn flex_weight_079(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "079");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.080`
+    pub fn flex_weight_080(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "080");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.081`
+    pub fn flex_weight_081(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "081");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.082`
+    pub fn flex_weight_082(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "082");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.083`
+    pub fn flex_weight_083(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "083");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.084`
+    pub fn flex_weight_084(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "084");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.085`
+    pub fn flex_weight_085(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "085");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.086`
+    pub fn flex_weight_086(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "086");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.087`
+    pub fn flex_weight_087(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "087");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.088`
+    pub fn flex_weight_088(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "088");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.089`
+    pub fn flex_weight_089(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "089");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.090`
+    pub fn flex_weight_090(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "090");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.091`
+    pub fn flex_weight_091(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "091");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.092`
+    pub fn flex_weight_092(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "092");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.093`
+    pub fn flex_weight_093(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "093");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.094`
+    pub fn flex_weight_094(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "094");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flexWeight.095`
+    pub fn flex_weight_095(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "095");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub fn next_attack(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.000`
+    pub fn my_weapons_000(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.001`
+    pub fn my_weapons_001(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.002`
+    pub fn my_weapons_002(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.003`
+    pub fn my_weapons_003(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.004`
+    pub fn my_weapons_004(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.005`
+    pub fn my_weapons_005(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.006`
+    pub fn my_weapons_006(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.007`
+    pub fn my_weapons_007(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.008`
+    pub fn my_weapons_008(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.009`
+    pub fn my_weapons_009(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.010`
+    pub fn my_weapons_010(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.011`
+    pub fn my_weapons_011(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.012`
+    pub fn my_weapons_012(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.013`
+    pub fn my_weapons_013(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.014`
+    pub fn my_weapons_014(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.015`
+    pub fn my_weapons_015(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.016`
+    pub fn my_weapons_016(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.017`
+    pub fn my_weapons_017(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.018`
+    pub fn my_weapons_018(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.019`
+    pub fn my_weapons_019(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.020`
+    pub fn my_weapons_020(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.021`
+    pub fn my_weapons_021(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.022`
+    pub fn my_weapons_022(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.023`
+    pub fn my_weapons_023(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.024`
+    pub fn my_weapons_024(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.025`
+    pub fn my_weapons_025(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.026`
+    pub fn my_weapons_026(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.027`
+    pub fn my_weapons_027(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.028`
+    pub fn my_weapons_028(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.029`
+    pub fn my_weapons_029(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.030`
+    pub fn my_weapons_030(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.031`
+    pub fn my_weapons_031(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.032`
+    pub fn my_weapons_032(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.033`
+    pub fn my_weapons_033(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.034`
+    pub fn my_weapons_034(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.035`
+    pub fn my_weapons_035(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.036`
+    pub fn my_weapons_036(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.037`
+    pub fn my_weapons_037(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.038`
+    pub fn my_weapons_038(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.039`
+    pub fn my_weapons_039(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.040`
+    pub fn my_weapons_040(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.041`
+    pub fn my_weapons_041(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.042`
+    pub fn my_weapons_042(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.043`
+    pub fn my_weapons_043(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.044`
+    pub fn my_weapons_044(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.045`
+    pub fn my_weapons_045(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.046`
+    pub fn my_weapons_046(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.047`
+    pub fn my_weapons_047(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nSequence`
+    pub fn base_animating_sequence(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseFlex.m_blinktoggle`
+    pub fn blinktoggle(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseFlex.m_viewtarget`
+    pub fn viewtarget(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub fn glow_enabled(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub fn active_weapon(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iHealth`
+    pub fn health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iMaxHealth`
+    pub fn max_health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bHasSapper`
+    pub fn has_sapper(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iObjectType`
+    pub fn object_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bBuilding`
+    pub fn building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bPlacing`
+    pub fn placing(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlacing");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bCarried`
+    pub fn carried(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarried");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bCarryDeploy`
+    pub fn carry_deploy(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bMiniBuilding`
+    pub fn mini_building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_flPercentageConstructed`
+    pub fn percentage_constructed(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_fObjectFlags`
+    pub fn object_flags(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_hBuiltOnEntity`
+    pub fn built_on_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bDisabled`
+    pub fn disabled(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_hBuilder`
+    pub fn builder(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuilder");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_vecBuildMaxs`
+    pub fn build_maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_vecBuildMins`
+    pub fn build_mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iDesiredBuildRotations`
+    pub fn desired_build_rotations(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bServerOverridePlacement`
+    pub fn server_override_placement(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeLevel`
+    pub fn upgrade_level(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeMetal`
+    pub fn upgrade_metal(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iUpgradeMetalRequired`
+    pub fn upgrade_metal_required(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iHighestUpgradeLevel`
+    pub fn highest_upgrade_level(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_iObjectMode`
+    pub fn object_mode(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bDisposableBuilding`
+    pub fn disposable_building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bWasMapPlaced`
+    pub fn was_map_placed(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bWasMapPlaced");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseObject.m_bPlasmaDisable`
+    pub fn plasma_disable(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlasmaDisable");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectTeleporter.m_iState`
+    pub fn state(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_iState");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectTeleporter.m_flRechargeTime`
+    pub fn recharge_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flRechargeTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectTeleporter.m_flCurrentRechargeDuration`
+    pub fn current_recharge_duration(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flCurrentRechargeDuration");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectTeleporter.m_iTimesUsed`
+    pub fn times_used(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_iTimesUsed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectTeleporter.m_flYawToExit`
+    pub fn yaw_to_exit(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flYawToExit");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ObjectTeleporter.m_bMatchBuilding`
+    pub fn match_building(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_bMatchBuilding");
+        self.entity.get_value(IDENTIFIER)
+    }
+}
+///Typed access to the props of a `CTFPlayer` entity
+pub struct CTFPlayerView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CTFPlayerView<'a, E> {
+    pub const CLASS: &'static str = "CTFPlayer";
+    pub fn new(entity: &'a E) -> Self {
+        CTFPlayerView { entity }
+    }
+    pub fn entity(&self) -> &'a E {
+        self.entity
+    }
+    ///`DT_TFLocalPlayerExclusive.m_vecOrigin` + `m_vecOrigin[2]` or `DT_TFNonLocalPlayerExclusive.m_vecOrigin` + `m_vecOrigin[2]`, from the first table that is present
+    pub fn origin(&self) -> Option<Vector> {
+        const PARTS: &[(SendPropIdentifier, SendPropIdentifier)] = &[
+            (
+                SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin"),
+                SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin[2]"),
+            ),
+            (
+                SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin"),
+                SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin[2]"),
+            ),
+        ];
+        PARTS
+            .iter()
+            .find_map(|(xy, z)| self.entity.get_split_vector(*xy, *z))
+    }
+    ///`DT_Local.m_flDucktime`
+    pub fn ducktime(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_flDucktime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_flFallVelocity`
+    pub fn fall_velocity(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_flFallVelocity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_vecPunchAngle`
+    pub fn punch_angle(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_vecPunchAngle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_vecViewOffset[2]`
+    pub fn view_offset_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[2]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_nTickBase`
+    pub fn tick_base(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nTickBase");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_hGroundEntity`
+    pub fn ground_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hGroundEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_vecVelocity[0]`
+    pub fn velocity_0(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[0]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_vecVelocity[1]`
+    pub fn velocity_1(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[1]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_vecVelocity[2]`
+    pub fn velocity_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[2]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_vecOrigin`
+    pub fn tf_local_player_exclusive_origin(&self) -> Option<VectorXY> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_vecOrigin[2]`
+    pub fn origin_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin[2]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_angEyeAngles[0]`
+    pub fn eye_angles_0(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[0]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_angEyeAngles[1]`
+    pub fn eye_angles_1(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[1]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFNonLocalPlayerExclusive.m_vecOrigin`
+    pub fn tf_non_local_player_exclusive_origin(&self) -> Option<VectorXY> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFNonLocalPlayerExclusive.m_vecOrigin[2]`
+    pub fn tf_non_local_player_exclusive_origin_2(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin[2]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFNonLocalPlayerExclusive.m_angEyeAngles[0]`
+    pub fn tf_non_local_player_exclusive_eye_angles_0(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[0]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFNonLocalPlayerExclusive.m_angEyeAngles[1]`
+    pub fn tf_non_local_player_exclusive_eye_angles_1(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[1]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_iHealth`
+    pub fn health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_fFlags`
+    pub fn flags(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_fFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub fn next_attack(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.000`
+    pub fn my_weapons_000(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.001`
+    pub fn my_weapons_001(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.002`
+    pub fn my_weapons_002(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.003`
+    pub fn my_weapons_003(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.004`
+    pub fn my_weapons_004(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.005`
+    pub fn my_weapons_005(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.006`
+    pub fn my_weapons_006(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.007`
+    pub fn my_weapons_007(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.008`
+    pub fn my_weapons_008(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.009`
+    pub fn my_weapons_009(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.010`
+    pub fn my_weapons_010(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.011`
+    pub fn my_weapons_011(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.012`
+    pub fn my_weapons_012(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.013`
+    pub fn my_weapons_013(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.014`
+    pub fn my_weapons_014(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.015`
+    pub fn my_weapons_015(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.016`
+    pub fn my_weapons_016(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.017`
+    pub fn my_weapons_017(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.018`
+    pub fn my_weapons_018(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.019`
+    pub fn my_weapons_019(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.020`
+    pub fn my_weapons_020(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.021`
+    pub fn my_weapons_021(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.022`
+    pub fn my_weapons_022(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.023`
+    pub fn my_weapons_023(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.024`
+    pub fn my_weapons_024(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.025`
+    pub fn my_weapons_025(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.026`
+    pub fn my_weapons_026(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.027`
+    pub fn my_weapons_027(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.028`
+    pub fn my_weapons_028(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.029`
+    pub fn my_weapons_029(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.030`
+    pub fn my_weapons_030(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.031`
+    pub fn my_weapons_031(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.032`
+    pub fn my_weapons_032(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.033`
+    pub fn my_weapons_033(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.034`
+    pub fn my_weapons_034(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.035`
+    pub fn my_weapons_035(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.036`
+    pub fn my_weapons_036(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.037`
+    pub fn my_weapons_037(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.038`
+    pub fn my_weapons_038(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.039`
+    pub fn my_weapons_039(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.040`
+    pub fn my_weapons_040(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.041`
+    pub fn my_weapons_041(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.042`
+    pub fn my_weapons_042(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.043`
+    pub fn my_weapons_043(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.044`
+    pub fn my_weapons_044(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.045`
+    pub fn my_weapons_045(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.046`
+    pub fn my_weapons_046(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_hMyWeapons.047`
+    pub fn my_weapons_047(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_LPT_m_Attributes_20.lengthprop20`
+    pub fn lengthprop_20(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_Attributes_20", "lengthprop20");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn attribute_definition_index(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn raw_value_32(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn refundable_currency(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_2(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_2(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_2(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_3(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_3(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_3(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_4(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_4(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_4(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_5(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_5(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_5(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_6(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_6(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_6(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_7(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_7(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_7(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_8(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_8(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_8(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_9(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_9(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_9(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_10(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_10(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_10(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_11(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_11(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_11(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_12(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_12(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_12(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_13(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_13(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_13(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_14(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_14(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_14(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_15(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_15(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_15(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_16(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_16(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_16(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_17(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_17(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_17(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_18(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_18(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_18(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn script_created_attribute_attribute_definition_index_19(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn script_created_attribute_raw_value_32_19(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn script_created_attribute_refundable_currency_19(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PlayerState.deadflag`
+    pub fn deadflag(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PlayerState", "deadflag");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_LPT_m_hMyWearables_8.lengthprop8`
+    pub fn lengthprop_8(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_hMyWearables_8", "lengthprop8");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_ST_m_hMyWearables_8.000`
+    pub fn st_m_h_my_wearables_8_000(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_ST_m_hMyWearables_8.001`
+    pub fn st_m_h_my_wearables_8_001(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_ST_m_hMyWearables_8.002`
+    pub fn st_m_h_my_wearables_8_002(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_ST_m_hMyWearables_8.003`
+    pub fn st_m_h_my_wearables_8_003(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_ST_m_hMyWearables_8.004`
+    pub fn st_m_h_my_wearables_8_004(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_ST_m_hMyWearables_8.005`
+    pub fn st_m_h_my_wearables_8_005(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_ST_m_hMyWearables_8.006`
+    pub fn st_m_h_my_wearables_8_006(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_ST_m_hMyWearables_8.007`
+    pub fn st_m_h_my_wearables_8_007(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.000`
+    pub fn area_bits_000(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.001`
+    pub fn area_bits_001(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.002`
+    pub fn area_bits_002(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.003`
+    pub fn area_bits_003(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.004`
+    pub fn area_bits_004(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.005`
+    pub fn area_bits_005(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.006`
+    pub fn area_bits_006(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.007`
+    pub fn area_bits_007(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.008`
+    pub fn area_bits_008(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.009`
+    pub fn area_bits_009(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.010`
+    pub fn area_bits_010(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.011`
+    pub fn area_bits_011(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.012`
+    pub fn area_bits_012(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.013`
+    pub fn area_bits_013(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.014`
+    pub fn area_bits_014(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.015`
+    pub fn area_bits_015(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.016`
+    pub fn area_bits_016(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.017`
+    pub fn area_bits_017(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.018`
+    pub fn area_bits_018(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.019`
+    pub fn area_bits_019(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.020`
+    pub fn area_bits_020(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.021`
+    pub fn area_bits_021(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.022`
+    pub fn area_bits_022(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.023`
+    pub fn area_bits_023(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.024`
+    pub fn area_bits_024(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.025`
+    pub fn area_bits_025(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.026`
+    pub fn area_bits_026(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.027`
+    pub fn area_bits_027(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.028`
+    pub fn area_bits_028(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.029`
+    pub fn area_bits_029(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.030`
+    pub fn area_bits_030(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaBits.031`
+    pub fn area_bits_031(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.000`
+    pub fn area_portal_bits_000(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.001`
+    pub fn area_portal_bits_001(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.002`
+    pub fn area_portal_bits_002(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.003`
+    pub fn area_portal_bits_003(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.004`
+    pub fn area_portal_bits_004(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.005`
+    pub fn area_portal_bits_005(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.006`
+    pub fn area_portal_bits_006(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.007`
+    pub fn area_portal_bits_007(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.008`
+    pub fn area_portal_bits_008(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.009`
+    pub fn area_portal_bits_009(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.010`
+    pub fn area_portal_bits_010(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.011`
+    pub fn area_portal_bits_011(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.012`
+    pub fn area_portal_bits_012(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.013`
+    pub fn area_portal_bits_013(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.014`
+    pub fn area_portal_bits_014(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.015`
+    pub fn area_portal_bits_015(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.016`
+    pub fn area_portal_bits_016(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.017`
+    pub fn area_portal_bits_017(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.018`
+    pub fn area_portal_bits_018(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.019`
+    pub fn area_portal_bits_019(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.020`
+    pub fn area_portal_bits_020(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.021`
+    pub fn area_portal_bits_021(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.022`
+    pub fn area_portal_bits_022(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_chAreaPortalBits.023`
+    pub fn area_portal_bits_023(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_chAreaPortalBits", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_iHideHUD`
+    pub fn hide_hud(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_iHideHUD");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_flFOVRate`
+    pub fn fov_rate(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_flFOVRate");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_bDucked`
+    pub fn ducked(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_bDucked");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_bDucking`
+    pub fn ducking(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_bDucking");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_bInDuckJump`
+    pub fn in_duck_jump(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_bInDuckJump");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_flDuckJumpTime`
+    pub fn duck_jump_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_flDuckJumpTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_flJumpTime`
+    pub fn jump_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_flJumpTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_vecPunchAngleVel`
+    pub fn punch_angle_vel(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_vecPunchAngleVel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_bDrawViewmodel`
+    pub fn draw_viewmodel(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bDrawViewmodel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_bWearingSuit`
+    pub fn wearing_suit(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bWearingSuit");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_bPoisoned`
+    pub fn poisoned(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_bPoisoned");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_bForceLocalPlayerDraw`
+    pub fn force_local_player_draw(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bForceLocalPlayerDraw");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_flStepSize`
+    pub fn step_size(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_flStepSize");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_bAllowAutoMovement`
+    pub fn allow_auto_movement(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bAllowAutoMovement");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.scale`
+    pub fn skybox_3d_scale(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.scale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.origin`
+    pub fn skybox_3d_origin(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.origin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.area`
+    pub fn skybox_3d_area(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.area");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.fog.enable`
+    pub fn skybox_3d_fog_enable(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.enable");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.fog.blend`
+    pub fn skybox_3d_fog_blend(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.blend");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.fog.dirPrimary`
+    pub fn skybox_3d_fog_dir_primary(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.dirPrimary");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.fog.colorPrimary`
+    pub fn skybox_3d_fog_color_primary(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.colorPrimary");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.fog.colorSecondary`
+    pub fn skybox_3d_fog_color_secondary(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.colorSecondary");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.fog.start`
+    pub fn skybox_3d_fog_start(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.start");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.fog.end`
+    pub fn skybox_3d_fog_end(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.end");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_skybox3d.fog.maxdensity`
+    pub fn skybox_3d_fog_maxdensity(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.maxdensity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_PlayerFog.m_hCtrl`
+    pub fn player_fog_m_h_ctrl(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_PlayerFog.m_hCtrl");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localSound[0]`
+    pub fn audio_local_sound_0(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[0]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localSound[1]`
+    pub fn audio_local_sound_1(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[1]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localSound[2]`
+    pub fn audio_local_sound_2(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[2]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localSound[3]`
+    pub fn audio_local_sound_3(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[3]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localSound[4]`
+    pub fn audio_local_sound_4(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[4]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localSound[5]`
+    pub fn audio_local_sound_5(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[5]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localSound[6]`
+    pub fn audio_local_sound_6(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[6]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localSound[7]`
+    pub fn audio_local_sound_7(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[7]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.soundscapeIndex`
+    pub fn audio_soundscape_index(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.soundscapeIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.localBits`
+    pub fn audio_local_bits(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localBits");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_audio.entIndex`
+    pub fn audio_ent_index(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.entIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_Local.m_szScriptOverlayMaterial`
+    pub fn script_overlay_material(&self) -> Option<String> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_szScriptOverlayMaterial");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.000`
+    pub fn ammo_000(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.001`
+    pub fn ammo_001(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.002`
+    pub fn ammo_002(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.003`
+    pub fn ammo_003(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.004`
+    pub fn ammo_004(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.005`
+    pub fn ammo_005(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.006`
+    pub fn ammo_006(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.007`
+    pub fn ammo_007(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.008`
+    pub fn ammo_008(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.009`
+    pub fn ammo_009(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.010`
+    pub fn ammo_010(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.011`
+    pub fn ammo_011(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.012`
+    pub fn ammo_012(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.013`
+    pub fn ammo_013(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.014`
+    pub fn ammo_014(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.015`
+    pub fn ammo_015(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.016`
+    pub fn ammo_016(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.017`
+    pub fn ammo_017(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.018`
+    pub fn ammo_018(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.019`
+    pub fn ammo_019(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.020`
+    pub fn ammo_020(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.021`
+    pub fn ammo_021(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.022`
+    pub fn ammo_022(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.023`
+    pub fn ammo_023(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.024`
+    pub fn ammo_024(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.025`
+    pub fn ammo_025(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.026`
+    pub fn ammo_026(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.027`
+    pub fn ammo_027(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.028`
+    pub fn ammo_028(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.029`
+    pub fn ammo_029(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.030`
+    pub fn ammo_030(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_iAmmo.031`
+    pub fn ammo_031(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_vecViewOffset[0]`
+    pub fn view_offset_0(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[0]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_vecViewOffset[1]`
+    pub fn view_offset_1(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[1]");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_flFriction`
+    pub fn friction(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flFriction");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_fOnTarget`
+    pub fn on_target(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_fOnTarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_nNextThinkTick`
+    pub fn next_think_tick(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nNextThinkTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_hLastWeapon`
+    pub fn last_weapon(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hLastWeapon");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub fn uniform_trigger_bloat(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_vecBaseVelocity`
+    pub fn base_velocity(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecBaseVelocity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_hConstraintEntity`
+    pub fn constraint_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hConstraintEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_vecConstraintCenter`
+    pub fn constraint_center(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecConstraintCenter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_flConstraintRadius`
+    pub fn constraint_radius(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintRadius");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_flConstraintWidth`
+    pub fn constraint_width(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintWidth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_flConstraintSpeedFactor`
+    pub fn constraint_speed_factor(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintSpeedFactor");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_flDeathTime`
+    pub fn death_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flDeathTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_nWaterLevel`
+    pub fn water_level(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nWaterLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_LocalPlayerExclusive.m_flLaggedMovementValue`
+    pub fn lagged_movement_value(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flLaggedMovementValue");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_iClass`
+    pub fn class(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iClass");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_iszClassIcon`
+    pub fn class_icon(&self) -> Option<String> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iszClassIcon");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_iszCustomModel`
+    pub fn custom_model(&self) -> Option<String> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iszCustomModel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_vecCustomModelOffset`
+    pub fn custom_model_offset(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_vecCustomModelOffset");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_angCustomModelRotation`
+    pub fn custom_model_rotation(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_angCustomModelRotation");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_bCustomModelRotates`
+    pub fn custom_model_rotates(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelRotates");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_bCustomModelRotationSet`
+    pub fn custom_model_rotation_set(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelRotationSet");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_bCustomModelVisibleToSelf`
+    pub fn custom_model_visible_to_self(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelVisibleToSelf");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_bUseClassAnimations`
+    pub fn use_class_animations(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bUseClassAnimations");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerClassShared.m_iClassModelParity`
+    pub fn class_model_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iClassModelParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.000`
+    pub fn item_charge_meter_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.001`
+    pub fn item_charge_meter_001(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.002`
+    pub fn item_charge_meter_002(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.003`
+    pub fn item_charge_meter_003(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.004`
+    pub fn item_charge_meter_004(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.005`
+    pub fn item_charge_meter_005(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.006`
+    pub fn item_charge_meter_006(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.007`
+    pub fn item_charge_meter_007(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.008`
+    pub fn item_charge_meter_008(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.009`
+    pub fn item_charge_meter_009(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flItemChargeMeter.010`
+    pub fn item_charge_meter_010(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.000`
+    pub fn player_dominated_000(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.001`
+    pub fn player_dominated_001(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.002`
+    pub fn player_dominated_002(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.003`
+    pub fn player_dominated_003(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.004`
+    pub fn player_dominated_004(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.005`
+    pub fn player_dominated_005(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.006`
+    pub fn player_dominated_006(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.007`
+    pub fn player_dominated_007(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.008`
+    pub fn player_dominated_008(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.009`
+    pub fn player_dominated_009(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.010`
+    pub fn player_dominated_010(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.011`
+    pub fn player_dominated_011(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.012`
+    pub fn player_dominated_012(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.013`
+    pub fn player_dominated_013(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.014`
+    pub fn player_dominated_014(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.015`
+    pub fn player_dominated_015(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.016`
+    pub fn player_dominated_016(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.017`
+    pub fn player_dominated_017(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.018`
+    pub fn player_dominated_018(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.019`
+    pub fn player_dominated_019(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.020`
+    pub fn player_dominated_020(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.021`
+    pub fn player_dominated_021(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.022`
+    pub fn player_dominated_022(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.023`
+    pub fn player_dominated_023(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.024`
+    pub fn player_dominated_024(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.025`
+    pub fn player_dominated_025(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.026`
+    pub fn player_dominated_026(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.027`
+    pub fn player_dominated_027(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.028`
+    pub fn player_dominated_028(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.029`
+    pub fn player_dominated_029(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.030`
+    pub fn player_dominated_030(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.031`
+    pub fn player_dominated_031(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.032`
+    pub fn player_dominated_032(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.033`
+    pub fn player_dominated_033(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.034`
+    pub fn player_dominated_034(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.035`
+    pub fn player_dominated_035(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.036`
+    pub fn player_dominated_036(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.037`
+    pub fn player_dominated_037(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.038`
+    pub fn player_dominated_038(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.039`
+    pub fn player_dominated_039(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.040`
+    pub fn player_dominated_040(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.041`
+    pub fn player_dominated_041(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.042`
+    pub fn player_dominated_042(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.043`
+    pub fn player_dominated_043(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.044`
+    pub fn player_dominated_044(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.045`
+    pub fn player_dominated_045(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.046`
+    pub fn player_dominated_046(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.047`
+    pub fn player_dominated_047(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.048`
+    pub fn player_dominated_048(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "048");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.049`
+    pub fn player_dominated_049(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "049");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.050`
+    pub fn player_dominated_050(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "050");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.051`
+    pub fn player_dominated_051(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "051");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.052`
+    pub fn player_dominated_052(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "052");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.053`
+    pub fn player_dominated_053(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "053");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.054`
+    pub fn player_dominated_054(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "054");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.055`
+    pub fn player_dominated_055(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "055");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.056`
+    pub fn player_dominated_056(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "056");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.057`
+    pub fn player_dominated_057(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "057");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.058`
+    pub fn player_dominated_058(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "058");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.059`
+    pub fn player_dominated_059(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "059");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.060`
+    pub fn player_dominated_060(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "060");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.061`
+    pub fn player_dominated_061(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "061");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.062`
+    pub fn player_dominated_062(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "062");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.063`
+    pub fn player_dominated_063(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "063");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.064`
+    pub fn player_dominated_064(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "064");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.065`
+    pub fn player_dominated_065(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "065");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.066`
+    pub fn player_dominated_066(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "066");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.067`
+    pub fn player_dominated_067(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "067");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.068`
+    pub fn player_dominated_068(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "068");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.069`
+    pub fn player_dominated_069(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "069");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.070`
+    pub fn player_dominated_070(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "070");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.071`
+    pub fn player_dominated_071(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "071");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.072`
+    pub fn player_dominated_072(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "072");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.073`
+    pub fn player_dominated_073(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "073");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.074`
+    pub fn player_dominated_074(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "074");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.075`
+    pub fn player_dominated_075(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "075");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.076`
+    pub fn player_dominated_076(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "076");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.077`
+    pub fn player_dominated_077(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "077");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.078`
+    pub fn player_dominated_078(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "078");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.079`
+    pub fn player_dominated_079(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "079");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.080`
+    pub fn player_dominated_080(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "080");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.081`
+    pub fn player_dominated_081(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "081");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.082`
+    pub fn player_dominated_082(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "082");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.083`
+    pub fn player_dominated_083(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "083");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.084`
+    pub fn player_dominated_084(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "084");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.085`
+    pub fn player_dominated_085(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "085");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.086`
+    pub fn player_dominated_086(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "086");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.087`
+    pub fn player_dominated_087(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "087");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.088`
+    pub fn player_dominated_088(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "088");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.089`
+    pub fn player_dominated_089(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "089");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.090`
+    pub fn player_dominated_090(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "090");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.091`
+    pub fn player_dominated_091(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "091");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.092`
+    pub fn player_dominated_092(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "092");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.093`
+    pub fn player_dominated_093(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "093");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.094`
+    pub fn player_dominated_094(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "094");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.095`
+    pub fn player_dominated_095(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "095");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.096`
+    pub fn player_dominated_096(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "096");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.097`
+    pub fn player_dominated_097(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "097");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.098`
+    pub fn player_dominated_098(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "098");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.099`
+    pub fn player_dominated_099(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "099");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.100`
+    pub fn player_dominated_100(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "100");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominated.101`
+    pub fn player_dominated_101(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_bPlayerDominated", "101");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.000`
+    pub fn player_dominating_me_000(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.001`
+    pub fn player_dominating_me_001(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.002`
+    pub fn player_dominating_me_002(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.003`
+    pub fn player_dominating_me_003(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.004`
+    pub fn player_dominating_me_004(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "004");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.005`
+    pub fn player_dominating_me_005(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "005");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.006`
+    pub fn player_dominating_me_006(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "006");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.007`
+    pub fn player_dominating_me_007(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "007");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.008`
+    pub fn player_dominating_me_008(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "008");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.009`
+    pub fn player_dominating_me_009(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "009");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.010`
+    pub fn player_dominating_me_010(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "010");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.011`
+    pub fn player_dominating_me_011(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "011");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.012`
+    pub fn player_dominating_me_012(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "012");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.013`
+    pub fn player_dominating_me_013(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "013");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.014`
+    pub fn player_dominating_me_014(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "014");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.015`
+    pub fn player_dominating_me_015(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "015");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.016`
+    pub fn player_dominating_me_016(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "016");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.017`
+    pub fn player_dominating_me_017(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "017");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.018`
+    pub fn player_dominating_me_018(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "018");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.019`
+    pub fn player_dominating_me_019(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "019");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.020`
+    pub fn player_dominating_me_020(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "020");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.021`
+    pub fn player_dominating_me_021(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "021");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.022`
+    pub fn player_dominating_me_022(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "022");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.023`
+    pub fn player_dominating_me_023(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "023");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.024`
+    pub fn player_dominating_me_024(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "024");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.025`
+    pub fn player_dominating_me_025(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "025");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.026`
+    pub fn player_dominating_me_026(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "026");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.027`
+    pub fn player_dominating_me_027(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "027");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.028`
+    pub fn player_dominating_me_028(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "028");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.029`
+    pub fn player_dominating_me_029(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "029");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.030`
+    pub fn player_dominating_me_030(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "030");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.031`
+    pub fn player_dominating_me_031(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "031");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.032`
+    pub fn player_dominating_me_032(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "032");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.033`
+    pub fn player_dominating_me_033(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "033");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.034`
+    pub fn player_dominating_me_034(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "034");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.035`
+    pub fn player_dominating_me_035(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "035");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.036`
+    pub fn player_dominating_me_036(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "036");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.037`
+    pub fn player_dominating_me_037(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "037");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.038`
+    pub fn player_dominating_me_038(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "038");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.039`
+    pub fn player_dominating_me_039(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "039");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.040`
+    pub fn player_dominating_me_040(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "040");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.041`
+    pub fn player_dominating_me_041(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "041");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.042`
+    pub fn player_dominating_me_042(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "042");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.043`
+    pub fn player_dominating_me_043(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "043");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.044`
+    pub fn player_dominating_me_044(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "044");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.045`
+    pub fn player_dominating_me_045(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "045");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.046`
+    pub fn player_dominating_me_046(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "046");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.047`
+    pub fn player_dominating_me_047(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "047");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.048`
+    pub fn player_dominating_me_048(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "048");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.049`
+    pub fn player_dominating_me_049(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "049");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.050`
+    pub fn player_dominating_me_050(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "050");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.051`
+    pub fn player_dominating_me_051(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "051");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.052`
+    pub fn player_dominating_me_052(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "052");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.053`
+    pub fn player_dominating_me_053(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "053");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.054`
+    pub fn player_dominating_me_054(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "054");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.055`
+    pub fn player_dominating_me_055(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "055");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.056`
+    pub fn player_dominating_me_056(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "056");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.057`
+    pub fn player_dominating_me_057(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "057");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.058`
+    pub fn player_dominating_me_058(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "058");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.059`
+    pub fn player_dominating_me_059(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "059");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.060`
+    pub fn player_dominating_me_060(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "060");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.061`
+    pub fn player_dominating_me_061(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "061");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.062`
+    pub fn player_dominating_me_062(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "062");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.063`
+    pub fn player_dominating_me_063(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "063");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.064`
+    pub fn player_dominating_me_064(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "064");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.065`
+    pub fn player_dominating_me_065(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "065");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.066`
+    pub fn player_dominating_me_066(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "066");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.067`
+    pub fn player_dominating_me_067(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "067");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.068`
+    pub fn player_dominating_me_068(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "068");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.069`
+    pub fn player_dominating_me_069(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "069");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.070`
+    pub fn player_dominating_me_070(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "070");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.071`
+    pub fn player_dominating_me_071(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "071");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.072`
+    pub fn player_dominating_me_072(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "072");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.073`
+    pub fn player_dominating_me_073(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "073");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.074`
+    pub fn player_dominating_me_074(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "074");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.075`
+    pub fn player_dominating_me_075(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "075");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.076`
+    pub fn player_dominating_me_076(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "076");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.077`
+    pub fn player_dominating_me_077(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "077");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.078`
+    pub fn player_dominating_me_078(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "078");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.079`
+    pub fn player_dominating_me_079(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "079");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.080`
+    pub fn player_dominating_me_080(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "080");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.081`
+    pub fn player_dominating_me_081(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "081");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.082`
+    pub fn player_dominating_me_082(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "082");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.083`
+    pub fn player_dominating_me_083(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "083");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.084`
+    pub fn player_dominating_me_084(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "084");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.085`
+    pub fn player_dominating_me_085(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "085");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.086`
+    pub fn player_dominating_me_086(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "086");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.087`
+    pub fn player_dominating_me_087(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "087");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.088`
+    pub fn player_dominating_me_088(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "088");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.089`
+    pub fn player_dominating_me_089(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "089");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.090`
+    pub fn player_dominating_me_090(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "090");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.091`
+    pub fn player_dominating_me_091(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "091");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.092`
+    pub fn player_dominating_me_092(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "092");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.093`
+    pub fn player_dominating_me_093(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "093");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.094`
+    pub fn player_dominating_me_094(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "094");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.095`
+    pub fn player_dominating_me_095(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "095");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.096`
+    pub fn player_dominating_me_096(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "096");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.097`
+    pub fn player_dominating_me_097(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "097");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.098`
+    pub fn player_dominating_me_098(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "098");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.099`
+    pub fn player_dominating_me_099(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "099");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.100`
+    pub fn player_dominating_me_100(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "100");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_bPlayerDominatingMe.101`
+    pub fn player_dominating_me_101(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "101");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iCaptures`
+    pub fn captures(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCaptures");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iDefenses`
+    pub fn defenses(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDefenses");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iKills`
+    pub fn kills(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKills");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iDeaths`
+    pub fn deaths(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDeaths");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iSuicides`
+    pub fn suicides(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iSuicides");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iDominations`
+    pub fn dominations(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDominations");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iRevenge`
+    pub fn revenge(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iRevenge");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iBuildingsBuilt`
+    pub fn buildings_built(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBuildingsBuilt");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iBuildingsDestroyed`
+    pub fn buildings_destroyed(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBuildingsDestroyed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iHeadshots`
+    pub fn headshots(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iHeadshots");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iBackstabs`
+    pub fn backstabs(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBackstabs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iHealPoints`
+    pub fn heal_points(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iHealPoints");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iInvulns`
+    pub fn invulns(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iInvulns");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iTeleports`
+    pub fn teleports(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iTeleports");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iDamageDone`
+    pub fn damage_done(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDamageDone");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iCrits`
+    pub fn crits(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCrits");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iResupplyPoints`
+    pub fn resupply_points(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iResupplyPoints");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iKillAssists`
+    pub fn kill_assists(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKillAssists");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iBonusPoints`
+    pub fn bonus_points(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBonusPoints");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iPoints`
+    pub fn points(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iPoints");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iCaptures`
+    pub fn tf_player_scoring_data_exclusive_captures(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCaptures");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iDefenses`
+    pub fn tf_player_scoring_data_exclusive_defenses(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDefenses");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iKills`
+    pub fn tf_player_scoring_data_exclusive_kills(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKills");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iDeaths`
+    pub fn tf_player_scoring_data_exclusive_deaths(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDeaths");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iSuicides`
+    pub fn tf_player_scoring_data_exclusive_suicides(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iSuicides");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iDominations`
+    pub fn tf_player_scoring_data_exclusive_dominations(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDominations");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iRevenge`
+    pub fn tf_player_scoring_data_exclusive_revenge(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iRevenge");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iBuildingsBuilt`
+    pub fn tf_player_scoring_data_exclusive_buildings_built(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBuildingsBuilt");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iBuildingsDestroyed`
+    pub fn tf_player_scoring_data_exclusive_buildings_destroyed(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBuildingsDestroyed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iHeadshots`
+    pub fn tf_player_scoring_data_exclusive_headshots(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iHeadshots");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iBackstabs`
+    pub fn tf_player_scoring_data_exclusive_backstabs(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBackstabs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iHealPoints`
+    pub fn tf_player_scoring_data_exclusive_heal_points(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iHealPoints");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iInvulns`
+    pub fn tf_player_scoring_data_exclusive_invulns(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iInvulns");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iTeleports`
+    pub fn tf_player_scoring_data_exclusive_teleports(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iTeleports");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iDamageDone`
+    pub fn tf_player_scoring_data_exclusive_damage_done(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDamageDone");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iCrits`
+    pub fn tf_player_scoring_data_exclusive_crits(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCrits");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iResupplyPoints`
+    pub fn tf_player_scoring_data_exclusive_resupply_points(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iResupplyPoints");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iKillAssists`
+    pub fn tf_player_scoring_data_exclusive_kill_assists(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKillAssists");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iBonusPoints`
+    pub fn tf_player_scoring_data_exclusive_bonus_points(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBonusPoints");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerScoringDataExclusive.m_iPoints`
+    pub fn tf_player_scoring_data_exclusive_points(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iPoints");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_nDesiredDisguiseTeam`
+    pub fn desired_disguise_team(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_nDesiredDisguiseTeam");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_nDesiredDisguiseClass`
+    pub fn desired_disguise_class(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_nDesiredDisguiseClass");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_bLastDisguisedAsOwnTeam`
+    pub fn last_disguised_as_own_team(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bLastDisguisedAsOwnTeam");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_flStealthNoAttackExpire`
+    pub fn stealth_no_attack_expire(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flStealthNoAttackExpire");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_flStealthNextChangeTime`
+    pub fn stealth_next_change_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flStealthNextChangeTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_flRageMeter`
+    pub fn rage_meter(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flRageMeter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_bRageDraining`
+    pub fn rage_draining(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bRageDraining");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_flNextRageEarnTime`
+    pub fn next_rage_earn_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flNextRageEarnTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerSharedLocal.m_bInUpgradeZone`
+    pub fn in_upgrade_zone(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bInUpgradeZone");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionListExclusive._condition_bits`
+    pub fn condition_bits(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionListExclusive", "_condition_bits");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nStreaks.000`
+    pub fn streaks_000(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_nStreaks", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nStreaks.001`
+    pub fn streaks_001(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_nStreaks", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nStreaks.002`
+    pub fn streaks_002(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_nStreaks", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nStreaks.003`
+    pub fn streaks_003(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("m_nStreaks", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`_LPT_m_ConditionData_131.lengthprop131`
+    pub fn lengthprop_131(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_ConditionData_131", "lengthprop131");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn p_provider(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_2(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_3(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_4(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_5(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_6(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_7(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_8(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_9(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_10(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_11(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_12(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_13(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_14(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_15(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_16(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_17(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_18(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_19(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_20(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_21(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_22(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_23(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_24(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_25(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_26(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_27(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_28(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_29(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_30(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_31(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_32(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_33(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_34(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_35(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_36(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_37(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_38(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_39(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_40(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_41(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_42(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_43(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_44(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_45(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_46(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_47(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_48(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_49(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_50(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_51(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_52(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_53(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_54(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_55(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_56(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_57(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_58(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_59(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_60(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_61(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_62(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_63(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_64(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_65(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_66(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_67(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_68(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_69(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_70(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_71(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_72(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_73(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_74(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_75(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_76(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_77(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_78(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_79(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_80(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_81(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_82(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_83(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_84(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_85(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_86(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_87(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_88(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_89(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_90(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_91(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_92(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_93(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_94(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_95(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_96(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_97(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_98(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_99(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_100(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_101(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_102(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_103(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_104(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_105(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_106(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_107(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_108(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_109(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_110(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_111(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_112(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_113(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_114(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_115(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_116(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_117(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_118(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_119(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_120(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_121(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_122(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_123(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_124(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_125(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_126(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_127(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_128(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_129(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub fn tf_player_condition_source_p_provider_130(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nPlayerCond`
+    pub fn player_cond(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCond");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bJumping`
+    pub fn jumping(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bJumping");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nNumHealers`
+    pub fn num_healers(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nNumHealers");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iCritMult`
+    pub fn crit_mult(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iCritMult");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iAirDash`
+    pub fn air_dash(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iAirDash");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nAirDucked`
+    pub fn air_ducked(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nAirDucked");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flDuckTimer`
+    pub fn duck_timer(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flDuckTimer");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nPlayerState`
+    pub fn player_state(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerState");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iDesiredPlayerClass`
+    pub fn desired_player_class(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDesiredPlayerClass");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flMovementStunTime`
+    pub fn movement_stun_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flMovementStunTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iMovementStunAmount`
+    pub fn movement_stun_amount(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iMovementStunAmount");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iMovementStunParity`
+    pub fn movement_stun_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iMovementStunParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_hStunner`
+    pub fn stunner(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hStunner");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iStunFlags`
+    pub fn stun_flags(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iStunFlags");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nArenaNumChanges`
+    pub fn arena_num_changes(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nArenaNumChanges");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bArenaFirstBloodBoost`
+    pub fn arena_first_blood_boost(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bArenaFirstBloodBoost");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iWeaponKnockbackID`
+    pub fn weapon_knockback_id(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iWeaponKnockbackID");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bLoadoutUnavailable`
+    pub fn loadout_unavailable(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bLoadoutUnavailable");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iItemFindBonus`
+    pub fn item_find_bonus(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iItemFindBonus");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bShieldEquipped`
+    pub fn shield_equipped(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bShieldEquipped");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bParachuteEquipped`
+    pub fn parachute_equipped(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bParachuteEquipped");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iNextMeleeCrit`
+    pub fn next_melee_crit(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iNextMeleeCrit");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iDecapitations`
+    pub fn decapitations(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDecapitations");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iRevengeCrits`
+    pub fn revenge_crits(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iRevengeCrits");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iDisguiseBody`
+    pub fn disguise_body(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDisguiseBody");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_hCarriedObject`
+    pub fn carried_object(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hCarriedObject");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bCarryingObject`
+    pub fn carrying_object(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bCarryingObject");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flNextNoiseMakerTime`
+    pub fn next_noise_maker_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flNextNoiseMakerTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iSpawnRoomTouchCount`
+    pub fn spawn_room_touch_count(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iSpawnRoomTouchCount");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iKillCountSinceLastDeploy`
+    pub fn kill_count_since_last_deploy(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iKillCountSinceLastDeploy");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flFirstPrimaryAttack`
+    pub fn first_primary_attack(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flFirstPrimaryAttack");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flEnergyDrinkMeter`
+    pub fn energy_drink_meter(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flEnergyDrinkMeter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flHypeMeter`
+    pub fn hype_meter(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flHypeMeter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flChargeMeter`
+    pub fn charge_meter(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flChargeMeter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flInvisChangeCompleteTime`
+    pub fn invis_change_complete_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flInvisChangeCompleteTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nDisguiseTeam`
+    pub fn disguise_team(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseTeam");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nDisguiseClass`
+    pub fn disguise_class(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseClass");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nDisguiseSkinOverride`
+    pub fn disguise_skin_override(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseSkinOverride");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nMaskClass`
+    pub fn mask_class(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nMaskClass");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_hDisguiseTarget`
+    pub fn disguise_target(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hDisguiseTarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iDisguiseHealth`
+    pub fn disguise_health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDisguiseHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bFeignDeathReady`
+    pub fn feign_death_ready(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bFeignDeathReady");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_hDisguiseWeapon`
+    pub fn disguise_weapon(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hDisguiseWeapon");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nTeamTeleporterUsed`
+    pub fn team_teleporter_used(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nTeamTeleporterUsed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flCloakMeter`
+    pub fn cloak_meter(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flCloakMeter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flSpyTranqBuffDuration`
+    pub fn spy_tranq_buff_duration(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flSpyTranqBuffDuration");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iTauntIndex`
+    pub fn taunt_index(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iTauntIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iTauntConcept`
+    pub fn taunt_concept(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iTauntConcept");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nPlayerCondEx`
+    pub fn player_cond_ex(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_iStunIndex`
+    pub fn stun_index(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iStunIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nHalloweenBombHeadStage`
+    pub fn halloween_bomb_head_stage(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nHalloweenBombHeadStage");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nPlayerCondEx2`
+    pub fn player_cond_ex_2(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx2");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nPlayerCondEx3`
+    pub fn player_cond_ex_3(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx3");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_unTauntSourceItemID_Low`
+    pub fn un_taunt_source_item_id_low(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_unTauntSourceItemID_Low");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_unTauntSourceItemID_High`
+    pub fn un_taunt_source_item_id_high(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_unTauntSourceItemID_High");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flRuneCharge`
+    pub fn rune_charge(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flRuneCharge");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bHasPasstimeBall`
+    pub fn has_passtime_ball(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bHasPasstimeBall");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bIsTargetedForPasstimePass`
+    pub fn is_targeted_for_passtime_pass(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bIsTargetedForPasstimePass");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_hPasstimePassTarget`
+    pub fn passtime_pass_target(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hPasstimePassTarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_askForBallTime`
+    pub fn ask_for_ball_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_askForBallTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_bKingRuneBuffActive`
+    pub fn king_rune_buff_active(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bKingRuneBuffActive");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_nPlayerCondEx4`
+    pub fn player_cond_ex_4(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx4");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_flHolsterAnimTime`
+    pub fn holster_anim_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flHolsterAnimTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayerShared.m_hSwitchTo`
+    pub fn switch_to(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hSwitchTo");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive."player_object_array"`
+    pub fn player_object_array(&self) -> Option<Vec<u32>> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "\"player_object_array\"");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_bIsCoaching`
+    pub fn is_coaching(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_bIsCoaching");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_hCoach`
+    pub fn coach(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_hCoach");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_hStudent`
+    pub fn student(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_hStudent");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_nCurrency`
+    pub fn currency(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nCurrency");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_nExperienceLevel`
+    pub fn experience_level(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nExperienceLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_nExperienceLevelProgress`
+    pub fn experience_level_progress(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nExperienceLevelProgress");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFLocalPlayerExclusive.m_bMatchSafeToLeave`
+    pub fn match_safe_to_leave(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_bMatchSafeToLeave");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_AttributeManager.m_hOuter`
+    pub fn outer(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeManager", "m_hOuter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_AttributeManager.m_ProviderType`
+    pub fn provider_type(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeManager", "m_ProviderType");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_AttributeManager.m_iReapplyProvisionParity`
+    pub fn reapply_provision_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeManager", "m_iReapplyProvisionParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFSendHealersDataTable.m_nActiveWpnClip`
+    pub fn active_wpn_clip(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFSendHealersDataTable", "m_nActiveWpnClip");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<u16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<u32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub fn glow_enabled(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub fn active_weapon(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_hVehicle`
+    pub fn vehicle(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hVehicle");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_hUseEntity`
+    pub fn use_entity(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hUseEntity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_lifeState`
+    pub fn life_state(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_lifeState");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_iBonusProgress`
+    pub fn bonus_progress(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iBonusProgress");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_iBonusChallenge`
+    pub fn bonus_challenge(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iBonusChallenge");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_flMaxspeed`
+    pub fn maxspeed(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_flMaxspeed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_iObserverMode`
+    pub fn observer_mode(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iObserverMode");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_hObserverTarget`
+    pub fn observer_target(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hObserverTarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_iFOV`
+    pub fn fov(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iFOV");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_iFOVStart`
+    pub fn fov_start(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iFOVStart");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_flFOVTime`
+    pub fn fov_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_flFOVTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_iDefaultFOV`
+    pub fn default_fov(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iDefaultFOV");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_hZoomOwner`
+    pub fn zoom_owner(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hZoomOwner");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_hViewModel`
+    pub fn view_model(&self) -> Option<Vec<EntityHandle>> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hViewModel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_BasePlayer.m_szLastPlaceName`
+    pub fn last_place_name(&self) -> Option<String> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_szLastPlaceName");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bSaveMeParity`
+    pub fn save_me_parity(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bSaveMeParity");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bIsMiniBoss`
+    pub fn is_mini_boss(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bIsMiniBoss");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bIsABot`
+    pub fn is_a_bot(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_TFPlayer", "m_bIsABot");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_nBotSkill`
+    pub fn bot_skill(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nBotSkill");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_nWaterLevel`
+    pub fn tf_player_water_level(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nWaterLevel");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_hRagdoll`
+    pub fn ragdoll(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_TFPlayer", "m_hRagdoll");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_hItem`
+    pub fn item(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier = SendPropIdentifier::new("DT_TFPlayer", "m_hItem");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bAllowMoveDuringTaunt`
+    pub fn allow_move_during_taunt(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bAllowMoveDuringTaunt");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bIsReadyToHighFive`
+    pub fn is_ready_to_high_five(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bIsReadyToHighFive");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_hHighFivePartner`
+    pub fn high_five_partner(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_hHighFivePartner");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_nForceTauntCam`
+    pub fn force_taunt_cam(&self) -> Option<u8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nForceTauntCam");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flTauntYaw`
+    pub fn taunt_yaw(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flTauntYaw");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_nActiveTauntSlot`
+    pub fn active_taunt_slot(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nActiveTauntSlot");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_iTauntItemDefIndex`
+    pub fn taunt_item_def_index(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iTauntItemDefIndex");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flCurrentTauntMoveSpeed`
+    pub fn current_taunt_move_speed(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flCurrentTauntMoveSpeed");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flVehicleReverseTime`
+    pub fn vehicle_reverse_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flVehicleReverseTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flMvMLastDamageTime`
+    pub fn mv_m_last_damage_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flMvMLastDamageTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bInPowerPlay`
+    pub fn in_power_play(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bInPowerPlay");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_iSpawnCounter`
+    pub fn spawn_counter(&self) -> Option<i8> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iSpawnCounter");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bArenaSpectator`
+    pub fn arena_spectator(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bArenaSpectator");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flHeadScale`
+    pub fn head_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flHeadScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flTorsoScale`
+    pub fn torso_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flTorsoScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flHandScale`
+    pub fn hand_scale(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flHandScale");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bUseBossHealthBar`
+    pub fn use_boss_health_bar(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUseBossHealthBar");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bUsingVRHeadset`
+    pub fn using_vr_headset(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUsingVRHeadset");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bForcedSkin`
+    pub fn forced_skin(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bForcedSkin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_nForcedSkin`
+    pub fn tf_player_forced_skin(&self) -> Option<i16> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nForcedSkin");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flKartNextAvailableBoost`
+    pub fn kart_next_available_boost(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flKartNextAvailableBoost");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_iKartHealth`
+    pub fn kart_health(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iKartHealth");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_iKartState`
+    pub fn kart_state(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iKartState");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_hGrapplingHookTarget`
+    pub fn grappling_hook_target(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_hGrapplingHookTarget");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_hSecondaryLastWeapon`
+    pub fn secondary_last_weapon(&self) -> Option<EntityHandle> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_hSecondaryLastWeapon");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bUsingActionSlot`
+    pub fn using_action_slot(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUsingActionSlot");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flInspectTime`
+    pub fn inspect_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flInspectTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_flHelpmeButtonPressTime`
+    pub fn helpme_button_press_time(&self) -> Option<f32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flHelpmeButtonPressTime");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_iCampaignMedals`
+    pub fn campaign_medals(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iCampaignMedals");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_iPlayerSkinOverride`
+    pub fn player_skin_override(&self) -> Option<i32> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iPlayerSkinOverride");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bViewingCYOAPDA`
+    pub fn viewing_cyoapda(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bViewingCYOAPDA");
+        self.entity.get_value(IDENTIFIER)
+    }
+    ///`DT_TFPlayer.m_bRegenerating`
+    pub fn regenerating(&self) -> Option<bool> {
+        const IDENTIFIER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bRegenerating");
+        self.entity.get_value(IDENTIFIER)
+    }
+}
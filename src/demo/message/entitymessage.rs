@@ -0,0 +1,241 @@
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::packet::datatable::ServerClassName;
+use crate::{ParserState, ReadResult, Stream};
+use bitbuffer::{BitRead, BitWrite, BitWriteSized, BitWriteStream, LittleEndian};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(bound(deserialize = "'a: 'static"))]
+pub struct EntityMessage<'a> {
+    pub index: u16,
+    pub class_id: u16,
+    /// Name of the server class from `class_id`, resolved against the data tables when the message is parsed
+    #[serde(default)]
+    pub class_name: Option<ServerClassName>,
+    pub length: u16,
+    pub data: Stream<'a>,
+}
+
+impl<'a> BitRead<'a, LittleEndian> for EntityMessage<'a> {
+    fn read(stream: &mut Stream<'a>) -> ReadResult<Self> {
+        let index = stream.read_sized(11)?;
+        let class_id = stream.read_sized(9)?;
+        let length = stream.read_sized(11)?;
+        let data = stream.read_sized(length as usize)?;
+        Ok(EntityMessage {
+            index,
+            class_id,
+            class_name: None,
+            length,
+            data,
+        })
+    }
+}
+
+impl BitWrite<LittleEndian> for EntityMessage<'_> {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        self.index.write_sized(stream, 11)?;
+        self.class_id.write_sized(stream, 9)?;
+        self.length.write_sized(stream, 11)?;
+        self.data.write(stream)
+    }
+}
+
+impl fmt::Display for EntityMessage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.class_name {
+            Some(class) => write!(f, "{}({})", self.index, class)?,
+            None => write!(f, "{}(class {})", self.index, self.class_id)?,
+        }
+        write!(f, ": {} bits", self.length)
+    }
+}
+
+/// `BASEENTITY_MSG_REMOVE_DECALS`
+const BASE_ENTITY_MESSAGE_REMOVE_DECALS: u8 = 1;
+
+/// The decoded payload of an entity message
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(bound(deserialize = "'a: 'static"))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EntityMessagePayload<'a> {
+    /// Remove all decals from the entity
+    RemoveDecals,
+    /// A payload that has no known decoder for the class of the entity or that failed to decode
+    Raw { data: Stream<'a> },
+}
+
+/// Function that decodes the payload of an entity message
+pub type EntityMessageDecoder = for<'a> fn(&mut Stream<'a>) -> ReadResult<EntityMessagePayload<'a>>;
+
+/// Decode the messages handled by `C_BaseEntity::ReceiveMessage`
+pub fn decode_base_entity_message<'a>(
+    stream: &mut Stream<'a>,
+) -> ReadResult<EntityMessagePayload<'a>> {
+    let message_type: u8 = stream.read()?;
+    match message_type {
+        BASE_ENTITY_MESSAGE_REMOVE_DECALS => Ok(EntityMessagePayload::RemoveDecals),
+        _ => Err(bitbuffer::BitError::UnmatchedDiscriminant {
+            discriminant: message_type as usize,
+            enum_name: "EntityMessagePayload".into(),
+        }),
+    }
+}
+
+/// Decoders for entity message payloads by server class name
+///
+/// Classes that don't override `ReceiveMessage` on the client handle the messages of their base class,
+/// for TF2 this means every class, including `CBasePlayer`, `CTFPlayer` and `CBaseViewModel`, only receives
+/// the base entity messages. The default registry decodes those for all classes, decoders for classes with
+/// their own messages can be registered on top of that.
+#[derive(Debug, Clone)]
+pub struct EntityMessageRegistry {
+    decoders: HashMap<String, EntityMessageDecoder>,
+    fallback: EntityMessageDecoder,
+}
+
+impl Default for EntityMessageRegistry {
+    fn default() -> Self {
+        EntityMessageRegistry {
+            decoders: HashMap::new(),
+            fallback: decode_base_entity_message,
+        }
+    }
+}
+
+impl EntityMessageRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a decoder for messages sent by entities of a server class, replacing any existing decoder
+    pub fn register<S: Into<String>>(&mut self, class: S, decoder: EntityMessageDecoder) {
+        self.decoders.insert(class.into(), decoder);
+    }
+
+    pub fn with_decoder<S: Into<String>>(
+        mut self,
+        class: S,
+        decoder: EntityMessageDecoder,
+    ) -> Self {
+        self.register(class, decoder);
+        self
+    }
+
+    /// Decode the payload of an entity message, the payload is kept raw when it doesn't match the decoder for the class
+    pub fn decode<'a>(
+        &self,
+        message: &EntityMessage<'a>,
+        state: &ParserState,
+    ) -> EntityMessagePayload<'a> {
+        let decoder = message
+            .class_name
+            .as_ref()
+            .or_else(|| message.resolve_class_name(state))
+            .and_then(|class| self.decoders.get(class.as_str()))
+            .unwrap_or(&self.fallback);
+        let mut data = message.data.clone();
+        match decoder(&mut data) {
+            Ok(payload) if data.bits_left() < 8 => payload,
+            _ => EntityMessagePayload::Raw {
+                data: message.data.clone(),
+            },
+        }
+    }
+}
+
+impl<'a> EntityMessage<'a> {
+    pub fn entity(&self) -> EntityId {
+        EntityId::from(self.index as u32)
+    }
+
+    /// The name of the server class the entity message is sent for
+    pub fn resolve_class_name<'s>(&self, state: &'s ParserState) -> Option<&'s ServerClassName> {
+        state
+            .server_classes
+            .get(self.class_id as usize)
+            .map(|class| &class.name)
+    }
+
+    /// Store the name of the server class of the message
+    pub fn with_class_name(self, state: &ParserState) -> Self {
+        EntityMessage {
+            class_name: self.resolve_class_name(state).cloned(),
+            ..self
+        }
+    }
+}
+
+#[test]
+fn test_decode_entity_message() {
+    use crate::demo::packet::datatable::{ClassId, SendTableName, ServerClass};
+    use bitbuffer::{BitError, BitReadBuffer, BitReadStream};
+
+    let mut state = ParserState::new(24, |_| true, false);
+    for (id, name) in ["CBaseEntity", "CTFPlayer", "CTFRagdoll"]
+        .iter()
+        .enumerate()
+    {
+        state.server_classes.push(ServerClass {
+            id: ClassId::from(id as u16),
+            name: ServerClassName::from(name.to_string()),
+            data_table: SendTableName::from(format!("DT_{}", &name[1..])),
+        });
+    }
+
+    let message = |class_id: u16, data: &'static [u8]| {
+        EntityMessage {
+            index: 1,
+            class_id,
+            class_name: None,
+            length: data.len() as u16 * 8,
+            data: BitReadStream::new(BitReadBuffer::new(data, LittleEndian)),
+        }
+        .with_class_name(&state)
+    };
+    fn reject<'a>(_stream: &mut Stream<'a>) -> ReadResult<EntityMessagePayload<'a>> {
+        Err(BitError::NotEnoughData {
+            requested: 8,
+            bits_left: 0,
+        })
+    }
+    let registry = EntityMessageRegistry::default().with_decoder("CTFRagdoll", reject);
+
+    let player = message(1, &[1]);
+    assert_eq!(
+        player.class_name.as_ref().map(ServerClassName::as_str),
+        Some("CTFPlayer")
+    );
+    assert_eq!(player.to_string(), "1(CTFPlayer): 8 bits");
+    assert_eq!(
+        registry.decode(&player, &state),
+        EntityMessagePayload::RemoveDecals
+    );
+
+    let unknown_type = message(1, &[5]);
+    assert_eq!(
+        registry.decode(&unknown_type, &state),
+        EntityMessagePayload::Raw {
+            data: unknown_type.data.clone()
+        }
+    );
+
+    let custom_decoder = message(2, &[1]);
+    assert_eq!(
+        registry.decode(&custom_decoder, &state),
+        EntityMessagePayload::Raw {
+            data: custom_decoder.data.clone()
+        }
+    );
+
+    let unknown_class = message(5, &[1]);
+    assert_eq!(unknown_class.to_string(), "1(class 5): 8 bits");
+    assert_eq!(
+        registry.decode(&unknown_class, &state),
+        EntityMessagePayload::RemoveDecals
+    );
+}
@@ -0,0 +1,127 @@
+use crate::demo::data::KeyValues;
+use crate::{ReadResult, Stream};
+use bitbuffer::{BitRead, BitReadBuffer, BitReadStream, BitWrite, BitWriteStream, LittleEndian};
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "'a: 'static"))]
+pub struct MenuMessage<'a> {
+    pub kind: u16,
+    /// The raw payload, written back as-is when encoding the message
+    pub data: Stream<'a>,
+    /// The decoded payload, `None` if the payload isn't valid binary KeyValues
+    pub key_values: Option<KeyValues>,
+}
+
+impl<'a> BitRead<'a, LittleEndian> for MenuMessage<'a> {
+    fn read(stream: &mut Stream<'a>) -> ReadResult<Self> {
+        let kind = stream.read()?;
+        let length: u16 = stream.read()?;
+        let data = stream.read_bits(length as usize * 8)?;
+        Ok(MenuMessage {
+            kind,
+            key_values: data.clone().read().ok(),
+            data,
+        })
+    }
+}
+
+impl BitWrite<LittleEndian> for MenuMessage<'_> {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        self.kind.write(stream)?;
+        ((self.data.bit_len() / 8) as u16).write(stream)?;
+        self.data.write(stream)
+    }
+}
+
+impl MenuMessage<'static> {
+    pub fn new(kind: u16, key_values: KeyValues) -> ReadResult<Self> {
+        Ok(MenuMessage {
+            kind,
+            data: encode_key_values(&key_values)?,
+            key_values: Some(key_values),
+        })
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "'a: 'static"))]
+pub struct CmdKeyValuesMessage<'a> {
+    /// The raw payload, written back as-is when encoding the message
+    pub data: Stream<'a>,
+    /// The decoded payload, `None` if the payload isn't valid binary KeyValues
+    pub key_values: Option<KeyValues>,
+}
+
+impl<'a> BitRead<'a, LittleEndian> for CmdKeyValuesMessage<'a> {
+    fn read(stream: &mut Stream<'a>) -> ReadResult<Self> {
+        let length: u32 = stream.read()?;
+        let data = stream.read_bits(length as usize * 8)?;
+        Ok(CmdKeyValuesMessage {
+            key_values: data.clone().read().ok(),
+            data,
+        })
+    }
+}
+
+impl BitWrite<LittleEndian> for CmdKeyValuesMessage<'_> {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        ((self.data.bit_len() / 8) as u32).write(stream)?;
+        self.data.write(stream)
+    }
+}
+
+impl CmdKeyValuesMessage<'static> {
+    pub fn new(key_values: KeyValues) -> ReadResult<Self> {
+        Ok(CmdKeyValuesMessage {
+            data: encode_key_values(&key_values)?,
+            key_values: Some(key_values),
+        })
+    }
+}
+
+fn encode_key_values(key_values: &KeyValues) -> ReadResult<Stream<'static>> {
+    let mut data = Vec::new();
+    key_values.write(&mut BitWriteStream::new(&mut data, LittleEndian))?;
+    Ok(BitReadStream::new(BitReadBuffer::new_owned(
+        data,
+        LittleEndian,
+    )))
+}
+
+#[test]
+fn test_key_values_message_roundtrip() {
+    use crate::demo::data::KeyValue;
+
+    let mut data = KeyValues::new();
+    data.insert("title", KeyValue::String("Vote".into()));
+    data.insert("level", KeyValue::Int(1));
+    crate::test_roundtrip_write(MenuMessage::new(1, data.clone()).unwrap());
+    crate::test_roundtrip_write(CmdKeyValuesMessage::new(data).unwrap());
+}
+
+#[test]
+fn test_key_values_message_keeps_raw_payload() {
+    // trailing bytes after the end marker and payloads that aren't valid KeyValues are kept as-is
+    let trailing = [4, 0, 0, 0, 0x0b, 1, 2, 3];
+    let message: CmdKeyValuesMessage =
+        BitReadStream::new(BitReadBuffer::new(&trailing, LittleEndian))
+            .read()
+            .unwrap();
+    assert_eq!(message.key_values, Some(KeyValues::new()));
+    let mut written = Vec::new();
+    message
+        .write(&mut BitWriteStream::new(&mut written, LittleEndian))
+        .unwrap();
+    assert_eq!(written, trailing);
+
+    let invalid = [2, 0, 0, 0, 0x0c, 0];
+    let message: CmdKeyValuesMessage =
+        BitReadStream::new(BitReadBuffer::new(&invalid, LittleEndian))
+            .read()
+            .unwrap();
+    assert_eq!(message.key_values, None);
+    crate::test_roundtrip_write(message);
+}
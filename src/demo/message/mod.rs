@@ -2,7 +2,9 @@ pub use generated::*;
 
 use crate::demo::message::bspdecal::*;
 use crate::demo::message::classinfo::*;
+use crate::demo::message::entitymessage::*;
 use crate::demo::message::gameevent::*;
+use crate::demo::message::keyvalues::*;
 use crate::demo::message::packetentities::*;
 use crate::demo::message::setconvar::*;
 use crate::demo::message::stringtable::*;
@@ -17,8 +19,10 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 pub mod bspdecal;
 pub mod classinfo;
+pub mod entitymessage;
 pub mod gameevent;
 pub mod generated;
+pub mod keyvalues;
 pub mod packetentities;
 pub mod setconvar;
 pub mod stringtable;
@@ -175,7 +179,7 @@ impl<'a> Message<'a> {
             MessageType::BspDecal => Message::BspDecal(BSPDecalMessage::parse(stream, state)?),
             MessageType::UserMessage => Message::UserMessage(UserMessage::parse(stream, state)?),
             MessageType::EntityMessage => {
-                Message::EntityMessage(EntityMessage::parse(stream, state)?)
+                Message::EntityMessage(EntityMessage::parse(stream, state)?.with_class_name(state))
             }
             MessageType::GameEvent => Message::GameEvent(GameEventMessage::parse(stream, state)?),
             MessageType::PacketEntities => {
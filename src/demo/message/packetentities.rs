@@ -5,6 +5,7 @@ use std::borrow::Cow;
 
 use crate::demo::message::stringtable::log_base2;
 use crate::demo::packet::datatable::{ClassId, SendTable};
+use crate::demo::parser::MalformedSendPropDefinitionError;
 use crate::demo::parser::{Encode, ParseBitSkip};
 use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
 use crate::{Parse, ParseError, ParserState, ReadResult, Result, Stream};
@@ -72,6 +73,71 @@ impl PartialOrd<u32> for EntityId {
     }
 }
 
+/// A networked entity handle (`EHANDLE`), 11 bits of entity index followed by 10 bits of serial number
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EntityHandle(u32);
+
+impl EntityHandle {
+    const INDEX_BITS: u32 = 11;
+    const SERIAL_BITS: u32 = 10;
+    /// All bits set marks a handle that doesn't point to any entity
+    pub const INVALID: EntityHandle =
+        EntityHandle((1 << (Self::INDEX_BITS + Self::SERIAL_BITS)) - 1);
+
+    pub fn new(entity: EntityId, serial_number: u32) -> Self {
+        EntityHandle(
+            (entity.0 & ((1 << Self::INDEX_BITS) - 1))
+                | ((serial_number & ((1 << Self::SERIAL_BITS) - 1)) << Self::INDEX_BITS),
+        )
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.0 != 0 && *self != Self::INVALID
+    }
+
+    /// The entity index the handle points to, without checking if the entity still exists
+    pub fn entity(&self) -> Option<EntityId> {
+        self.is_valid()
+            .then_some(EntityId(self.0 & ((1 << Self::INDEX_BITS) - 1)))
+    }
+
+    pub fn serial_number(&self) -> u32 {
+        self.0 >> Self::INDEX_BITS
+    }
+
+    /// Check if the handle points to the entity with the given serial number
+    pub fn matches(&self, entity: EntityId, serial_number: u32) -> bool {
+        self.entity() == Some(entity) && self.serial_number() == serial_number
+    }
+}
+
+impl Default for EntityHandle {
+    fn default() -> Self {
+        EntityHandle::INVALID
+    }
+}
+
+impl From<u32> for EntityHandle {
+    fn from(raw: u32) -> Self {
+        EntityHandle(raw)
+    }
+}
+
+impl From<EntityHandle> for u32 {
+    fn from(handle: EntityHandle) -> Self {
+        handle.0
+    }
+}
+
+impl TryFrom<&SendPropValue> for EntityHandle {
+    type Error = MalformedSendPropDefinitionError;
+
+    fn try_from(value: &SendPropValue) -> std::result::Result<Self, Self::Error> {
+        i64::try_from(value).map(|raw| EntityHandle(raw as u32))
+    }
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     BitRead, BitWrite, Clone, Copy, Debug, PartialEq, Eq, Serialize_repr, Deserialize_repr,
@@ -255,6 +321,46 @@ fn get_send_table(state: &ParserState, class: ClassId) -> Result<&SendTable> {
         .ok_or(ParseError::UnknownServerClass(class))
 }
 
+fn validate_props(
+    entity: &PacketEntity,
+    props: &[SendProp],
+    send_table: &SendTable,
+    state: &ParserState,
+) -> Result<()> {
+    for prop in props {
+        let definition = send_table
+            .flattened_props
+            .get(prop.index as usize)
+            .ok_or_else(|| ParseError::PropIndexOutOfBounds {
+                index: prop.index as i32,
+                prop_count: send_table.flattened_props.len(),
+                table: send_table.name.to_string(),
+            })?;
+        // definitions are collected from the data tables when strict mode is enabled
+        let definition = match state.prop_definitions.get(&definition.identifier) {
+            Some(definition) => definition,
+            None => continue,
+        };
+        prop.value
+            .validate(definition)
+            .map_err(|error| ParseError::InvalidPropValue {
+                entity: entity.entity_index,
+                class: state
+                    .server_classes
+                    .get(usize::from(entity.server_class))
+                    .map(|class| class.name.clone())
+                    .unwrap_or_default(),
+                prop: prop.identifier,
+                prop_name: state
+                    .prop_full_name(prop.identifier)
+                    .unwrap_or_else(|| prop.identifier.to_string()),
+                value: prop.value.clone(),
+                error,
+            })?;
+    }
+    Ok(())
+}
+
 fn get_entity_for_update(
     state: &ParserState,
     entity_index: EntityId,
@@ -309,6 +415,17 @@ impl Parse<'_> for PacketEntitiesMessage {
                     Self::read_enter(&mut data, entity_index, state, base_line as usize, delta)?;
                 let send_table = get_send_table(state, entity.server_class)?;
                 Self::read_update(&mut data, send_table, &mut entity.props, entity_index)?;
+                if state.strict_props {
+                    validate_props(&entity, &entity.props, send_table, state)?;
+                    let baseline = state.get_baseline(
+                        base_line as usize,
+                        entity_index,
+                        entity.server_class,
+                        send_table,
+                        delta.is_some(),
+                    )?;
+                    validate_props(&entity, &baseline, send_table, state)?;
+                }
 
                 entities.push(entity);
             } else if update_type == UpdateType::Preserve {
@@ -316,6 +433,9 @@ impl Parse<'_> for PacketEntitiesMessage {
                 let send_table = get_send_table(state, entity.server_class)?;
 
                 Self::read_update(&mut data, send_table, &mut entity.props, entity_index)?;
+                if state.strict_props {
+                    validate_props(&entity, &entity.props, send_table, state)?;
+                }
                 entity.in_pvs = true;
 
                 entities.push(entity);
@@ -694,3 +814,16 @@ fn test_packet_entitier_message_roundtrip() {
         &state,
     );
 }
+
+#[test]
+fn test_entity_handle() {
+    assert_eq!(None, EntityHandle::from(0).entity());
+    assert_eq!(None, EntityHandle::INVALID.entity());
+
+    let handle = EntityHandle::from((57 << 11) | 1234);
+    assert_eq!(Some(EntityId::from(1234u32)), handle.entity());
+    assert_eq!(57, handle.serial_number());
+    assert_eq!(handle, EntityHandle::new(EntityId::from(1234u32), 57));
+    assert!(handle.matches(EntityId::from(1234u32), 57));
+    assert!(!handle.matches(EntityId::from(1234u32), 58));
+}
@@ -1,13 +1,16 @@
 use super::stringtable::read_var_int;
-use crate::demo::message::packetentities::PacketEntitiesMessage;
+use crate::demo::message::packetentities::{EntityHandle, EntityId, PacketEntitiesMessage};
 use crate::demo::message::stringtable::{encode_var_int_fixed, log_base2};
 use crate::demo::packet::datatable::ClassId;
+use crate::demo::parser::stringtables::StringTables;
 use crate::demo::parser::{Encode, ParseBitSkip};
-use crate::demo::sendprop::SendProp;
+use crate::demo::sendprop::{SendProp, SendPropIdentifier};
+use crate::demo::vector::Vector;
 use crate::Result;
 use crate::{Parse, ParseError, ParserState, Stream};
 use bitbuffer::{BitWrite, BitWriteSized, BitWriteStream, LittleEndian};
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -142,3 +145,517 @@ impl Encode for TempEntitiesMessage {
         Ok(())
     }
 }
+
+/// Look up a name from a string table index prop
+fn name_prop<'a>(prop: &SendProp, lookup: impl FnOnce(usize) -> Option<&'a str>) -> Option<String> {
+    usize::try_from(int_prop(prop))
+        .ok()
+        .and_then(lookup)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct FireBullets {
+    /// Entity index of the player that fired
+    pub player: EntityId,
+    pub origin: Vector,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub weapon_id: u16,
+    pub mode: u8,
+    pub seed: u32,
+    pub spread: f32,
+    pub critical: bool,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct Explosion {
+    pub entity: EntityId,
+    pub origin: Vector,
+    pub normal: Vector,
+    pub weapon_id: u16,
+    pub item_definition_index: u32,
+    pub sound: i32,
+    pub custom_particle: Option<String>,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct Blood {
+    pub entity: EntityId,
+    pub origin: Vector,
+    pub normal: Vector,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct EffectDispatch {
+    pub effect: Option<String>,
+    pub entity: EntityId,
+    pub origin: Vector,
+    pub start: Vector,
+    pub angles: Vector,
+    pub normal: Vector,
+    pub flags: u32,
+    pub magnitude: f32,
+    pub scale: f32,
+    pub radius: f32,
+    pub damage_type: u32,
+    pub hitbox: u32,
+    pub attachment_index: u32,
+    pub material: u32,
+    pub surface_prop: u32,
+    pub color: u8,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ParticleEffect {
+    pub particle: Option<String>,
+    pub entity: EntityId,
+    pub origin: Vector,
+    pub start: Vector,
+    pub angles: Vector,
+    pub attach_type: u8,
+    pub attachment_point: u32,
+    pub reset_particles: bool,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct PlayerAnimEvent {
+    pub player: EntityHandle,
+    pub event: u32,
+    pub data: i32,
+}
+
+/// A decal placed on the world or an entity
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct Decal {
+    pub entity: EntityId,
+    pub origin: Vector,
+    pub start: Vector,
+    pub hitbox: u32,
+    /// Index into the `decalprecache` table
+    pub index: u16,
+}
+
+/// A spray placed by a player
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct PlayerDecal {
+    pub player: EntityId,
+    pub entity: EntityId,
+    pub origin: Vector,
+}
+
+/// A temp entity event decoded into the fields of its server class
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TempEntity {
+    FireBullets(FireBullets),
+    Explosion(Explosion),
+    Blood(Blood),
+    EffectDispatch(Box<EffectDispatch>),
+    ParticleEffect(Box<ParticleEffect>),
+    PlayerAnimEvent(PlayerAnimEvent),
+    Decal(Decal),
+    PlayerDecal(PlayerDecal),
+    Other(EventInfo),
+}
+
+impl EventInfo {
+    /// Decode the event into a typed temp entity based on its server class
+    ///
+    /// The effect and particle names are resolved from the `EffectDispatch` and `ParticleEffectNames` tables,
+    /// when the parser keeps the [string table contents](ParserState::string_table_contents)
+    pub fn typed(&self, state: &ParserState) -> TempEntity {
+        let string_tables = state.string_table_contents();
+        let class_name = state
+            .server_classes
+            .get(usize::from(self.class_id))
+            .map(|class| class.name.as_str())
+            .unwrap_or_default();
+        match class_name {
+            "CTEFireBullets" => TempEntity::FireBullets(self.fire_bullets()),
+            "CTETFExplosion" => TempEntity::Explosion(self.explosion(string_tables)),
+            "CTETFBlood" => TempEntity::Blood(self.blood()),
+            "CTEEffectDispatch" => {
+                TempEntity::EffectDispatch(Box::new(self.effect_dispatch(string_tables)))
+            }
+            "CTETFParticleEffect" => {
+                TempEntity::ParticleEffect(Box::new(self.particle_effect(string_tables)))
+            }
+            "CTEPlayerAnimEvent" => TempEntity::PlayerAnimEvent(self.player_anim_event()),
+            "CTEDecal" => TempEntity::Decal(self.decal("DT_TEDecal")),
+            "CTEBSPDecal" => TempEntity::Decal(self.decal("DT_TEBSPDecal")),
+            "CTEWorldDecal" => TempEntity::Decal(self.decal("DT_TEWorldDecal")),
+            "CTEPlayerDecal" => TempEntity::PlayerDecal(self.player_decal()),
+            _ => TempEntity::Other(self.clone()),
+        }
+    }
+
+    fn fire_bullets(&self) -> FireBullets {
+        const PLAYER: SendPropIdentifier = SendPropIdentifier::new("DT_TEFireBullets", "m_iPlayer");
+        const ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEFireBullets", "m_vecOrigin");
+        const PITCH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEFireBullets", "m_vecAngles[0]");
+        const YAW: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEFireBullets", "m_vecAngles[1]");
+        const WEAPON_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEFireBullets", "m_iWeaponID");
+        const MODE: SendPropIdentifier = SendPropIdentifier::new("DT_TEFireBullets", "m_iMode");
+        const SEED: SendPropIdentifier = SendPropIdentifier::new("DT_TEFireBullets", "m_iSeed");
+        const SPREAD: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEFireBullets", "m_flSpread");
+        const CRITICAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEFireBullets", "m_bCritical");
+
+        let mut bullets = FireBullets::default();
+        for prop in &self.props {
+            match prop.identifier {
+                // the player index is send as the entity index minus one
+                PLAYER => bullets.player = EntityId::from(int_prop(prop) as u32 + 1),
+                ORIGIN => bullets.origin = Vector::try_from(&prop.value).unwrap_or_default(),
+                PITCH => bullets.pitch = float_prop(prop),
+                YAW => bullets.yaw = float_prop(prop),
+                WEAPON_ID => bullets.weapon_id = int_prop(prop) as u16,
+                MODE => bullets.mode = int_prop(prop) as u8,
+                SEED => bullets.seed = int_prop(prop) as u32,
+                SPREAD => bullets.spread = float_prop(prop),
+                CRITICAL => bullets.critical = int_prop(prop) != 0,
+                _ => {}
+            }
+        }
+        bullets
+    }
+
+    fn explosion(&self, string_tables: Option<&StringTables>) -> Explosion {
+        const ENTITY: SendPropIdentifier = SendPropIdentifier::new("DT_TETFExplosion", "entindex");
+        const ORIGIN_X: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFExplosion", "m_vecOrigin[0]");
+        const ORIGIN_Y: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFExplosion", "m_vecOrigin[1]");
+        const ORIGIN_Z: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFExplosion", "m_vecOrigin[2]");
+        const NORMAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFExplosion", "m_vecNormal");
+        const WEAPON_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFExplosion", "m_iWeaponID");
+        const DEF_ID: SendPropIdentifier = SendPropIdentifier::new("DT_TETFExplosion", "m_nDefID");
+        const SOUND: SendPropIdentifier = SendPropIdentifier::new("DT_TETFExplosion", "m_nSound");
+        const PARTICLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFExplosion", "m_iCustomParticleIndex");
+
+        let mut explosion = Explosion::default();
+        for prop in &self.props {
+            match prop.identifier {
+                ENTITY => explosion.entity = EntityId::from(int_prop(prop) as u32),
+                ORIGIN_X => explosion.origin.x = float_prop(prop),
+                ORIGIN_Y => explosion.origin.y = float_prop(prop),
+                ORIGIN_Z => explosion.origin.z = float_prop(prop),
+                NORMAL => explosion.normal = Vector::try_from(&prop.value).unwrap_or_default(),
+                WEAPON_ID => explosion.weapon_id = int_prop(prop) as u16,
+                DEF_ID => explosion.item_definition_index = int_prop(prop) as u32,
+                SOUND => explosion.sound = int_prop(prop) as i32,
+                PARTICLE => {
+                    explosion.custom_particle =
+                        name_prop(prop, |index| string_tables?.particle(index))
+                }
+                _ => {}
+            }
+        }
+        explosion
+    }
+
+    fn blood(&self) -> Blood {
+        const ENTITY: SendPropIdentifier = SendPropIdentifier::new("DT_TETFBlood", "entindex");
+        const ORIGIN_X: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFBlood", "m_vecOrigin[0]");
+        const ORIGIN_Y: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFBlood", "m_vecOrigin[1]");
+        const ORIGIN_Z: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFBlood", "m_vecOrigin[2]");
+        const NORMAL: SendPropIdentifier = SendPropIdentifier::new("DT_TETFBlood", "m_vecNormal");
+
+        let mut blood = Blood::default();
+        for prop in &self.props {
+            match prop.identifier {
+                ENTITY => blood.entity = EntityId::from(int_prop(prop) as u32),
+                ORIGIN_X => blood.origin.x = float_prop(prop),
+                ORIGIN_Y => blood.origin.y = float_prop(prop),
+                ORIGIN_Z => blood.origin.z = float_prop(prop),
+                NORMAL => blood.normal = Vector::try_from(&prop.value).unwrap_or_default(),
+                _ => {}
+            }
+        }
+        blood
+    }
+
+    fn effect_dispatch(&self, string_tables: Option<&StringTables>) -> EffectDispatch {
+        const EFFECT_NAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_iEffectName");
+        const ENTITY: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "entindex");
+        const ORIGIN_X: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_vOrigin[0]");
+        const ORIGIN_Y: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_vOrigin[1]");
+        const ORIGIN_Z: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_vOrigin[2]");
+        const START_X: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_vStart[0]");
+        const START_Y: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_vStart[1]");
+        const START_Z: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_vStart[2]");
+        const ANGLES: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_vAngles");
+        const NORMAL: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_vNormal");
+        const FLAGS: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_fFlags");
+        const MAGNITUDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_flMagnitude");
+        const SCALE: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_flScale");
+        const RADIUS: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_flRadius");
+        const DAMAGE_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_nDamageType");
+        const HITBOX: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_nHitBox");
+        const ATTACHMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_nAttachmentIndex");
+        const MATERIAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_nMaterial");
+        const SURFACE_PROP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EffectData", "m_nSurfaceProp");
+        const COLOR: SendPropIdentifier = SendPropIdentifier::new("DT_EffectData", "m_nColor");
+
+        let mut effect = EffectDispatch::default();
+        for prop in &self.props {
+            match prop.identifier {
+                EFFECT_NAME => {
+                    effect.effect = name_prop(prop, |index| string_tables?.effect(index))
+                }
+                ENTITY => effect.entity = EntityId::from(int_prop(prop) as u32),
+                ORIGIN_X => effect.origin.x = float_prop(prop),
+                ORIGIN_Y => effect.origin.y = float_prop(prop),
+                ORIGIN_Z => effect.origin.z = float_prop(prop),
+                START_X => effect.start.x = float_prop(prop),
+                START_Y => effect.start.y = float_prop(prop),
+                START_Z => effect.start.z = float_prop(prop),
+                ANGLES => effect.angles = Vector::try_from(&prop.value).unwrap_or_default(),
+                NORMAL => effect.normal = Vector::try_from(&prop.value).unwrap_or_default(),
+                FLAGS => effect.flags = int_prop(prop) as u32,
+                MAGNITUDE => effect.magnitude = float_prop(prop),
+                SCALE => effect.scale = float_prop(prop),
+                RADIUS => effect.radius = float_prop(prop),
+                DAMAGE_TYPE => effect.damage_type = int_prop(prop) as u32,
+                HITBOX => effect.hitbox = int_prop(prop) as u32,
+                ATTACHMENT => effect.attachment_index = int_prop(prop) as u32,
+                MATERIAL => effect.material = int_prop(prop) as u32,
+                SURFACE_PROP => effect.surface_prop = int_prop(prop) as u32,
+                COLOR => effect.color = int_prop(prop) as u8,
+                _ => {}
+            }
+        }
+        effect
+    }
+
+    fn particle_effect(&self, string_tables: Option<&StringTables>) -> ParticleEffect {
+        const PARTICLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_iParticleSystemIndex");
+        const ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "entindex");
+        const ORIGIN_X: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_vecOrigin[0]");
+        const ORIGIN_Y: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_vecOrigin[1]");
+        const ORIGIN_Z: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_vecOrigin[2]");
+        const START_X: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_vecStart[0]");
+        const START_Y: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_vecStart[1]");
+        const START_Z: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_vecStart[2]");
+        const ANGLES: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_vecAngles");
+        const ATTACH_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_iAttachType");
+        const ATTACHMENT_POINT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_iAttachmentPointIndex");
+        const RESET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TETFParticleEffect", "m_bResetParticles");
+
+        let mut effect = ParticleEffect::default();
+        for prop in &self.props {
+            match prop.identifier {
+                PARTICLE => {
+                    effect.particle = name_prop(prop, |index| string_tables?.particle(index))
+                }
+                ENTITY => effect.entity = EntityId::from(int_prop(prop) as u32),
+                ORIGIN_X => effect.origin.x = float_prop(prop),
+                ORIGIN_Y => effect.origin.y = float_prop(prop),
+                ORIGIN_Z => effect.origin.z = float_prop(prop),
+                START_X => effect.start.x = float_prop(prop),
+                START_Y => effect.start.y = float_prop(prop),
+                START_Z => effect.start.z = float_prop(prop),
+                ANGLES => effect.angles = Vector::try_from(&prop.value).unwrap_or_default(),
+                ATTACH_TYPE => effect.attach_type = int_prop(prop) as u8,
+                ATTACHMENT_POINT => effect.attachment_point = int_prop(prop) as u32,
+                RESET => effect.reset_particles = int_prop(prop) != 0,
+                _ => {}
+            }
+        }
+        effect
+    }
+
+    fn player_anim_event(&self) -> PlayerAnimEvent {
+        const PLAYER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEPlayerAnimEvent", "m_hPlayer");
+        const EVENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEPlayerAnimEvent", "m_iEvent");
+        const DATA: SendPropIdentifier = SendPropIdentifier::new("DT_TEPlayerAnimEvent", "m_nData");
+
+        let mut event = PlayerAnimEvent::default();
+        for prop in &self.props {
+            match prop.identifier {
+                PLAYER => event.player = EntityHandle::from(int_prop(prop) as u32),
+                EVENT => event.event = int_prop(prop) as u32,
+                DATA => event.data = int_prop(prop) as i32,
+                _ => {}
+            }
+        }
+        event
+    }
+
+    /// The decal temp entities share their prop names but are sent from different tables
+    fn decal(&self, table: &str) -> Decal {
+        let entity = SendPropIdentifier::new(table, "m_nEntity");
+        let origin = SendPropIdentifier::new(table, "m_vecOrigin");
+        let start = SendPropIdentifier::new(table, "m_vecStart");
+        let hitbox = SendPropIdentifier::new(table, "m_nHitbox");
+        let index = SendPropIdentifier::new(table, "m_nIndex");
+
+        let mut decal = Decal::default();
+        for prop in &self.props {
+            if prop.identifier == entity {
+                decal.entity = EntityId::from(int_prop(prop) as u32);
+            } else if prop.identifier == origin {
+                decal.origin = Vector::try_from(&prop.value).unwrap_or_default();
+            } else if prop.identifier == start {
+                decal.start = Vector::try_from(&prop.value).unwrap_or_default();
+            } else if prop.identifier == hitbox {
+                decal.hitbox = int_prop(prop) as u32;
+            } else if prop.identifier == index {
+                decal.index = int_prop(prop) as u16;
+            }
+        }
+        decal
+    }
+
+    fn player_decal(&self) -> PlayerDecal {
+        const PLAYER: SendPropIdentifier = SendPropIdentifier::new("DT_TEPlayerDecal", "m_nPlayer");
+        const ENTITY: SendPropIdentifier = SendPropIdentifier::new("DT_TEPlayerDecal", "m_nEntity");
+        const ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TEPlayerDecal", "m_vecOrigin");
+
+        let mut decal = PlayerDecal::default();
+        for prop in &self.props {
+            match prop.identifier {
+                PLAYER => decal.player = EntityId::from(int_prop(prop) as u32),
+                ENTITY => decal.entity = EntityId::from(int_prop(prop) as u32),
+                ORIGIN => decal.origin = Vector::try_from(&prop.value).unwrap_or_default(),
+                _ => {}
+            }
+        }
+        decal
+    }
+}
+
+fn int_prop(prop: &SendProp) -> i64 {
+    i64::try_from(&prop.value).unwrap_or_default()
+}
+
+fn float_prop(prop: &SendProp) -> f32 {
+    f32::try_from(&prop.value).unwrap_or_default()
+}
+
+#[test]
+fn test_typed_fire_bullets() {
+    use crate::demo::sendprop::SendPropValue;
+
+    let event = EventInfo {
+        class_id: ClassId::from(0u16),
+        fire_delay: 0.0,
+        reliable: false,
+        props: vec![
+            SendProp {
+                index: 0,
+                identifier: SendPropIdentifier::new("DT_TEFireBullets", "m_iPlayer"),
+                value: SendPropValue::Integer(2),
+            },
+            SendProp {
+                index: 1,
+                identifier: SendPropIdentifier::new("DT_TEFireBullets", "m_vecAngles[1]"),
+                value: SendPropValue::Float(90.0),
+            },
+            SendProp {
+                index: 2,
+                identifier: SendPropIdentifier::new("DT_TEFireBullets", "m_iSeed"),
+                value: SendPropValue::Integer(123),
+            },
+            SendProp {
+                index: 3,
+                identifier: SendPropIdentifier::new("DT_TEFireBullets", "m_bCritical"),
+                value: SendPropValue::Integer(1),
+            },
+        ],
+    };
+    let bullets = event.fire_bullets();
+    assert_eq!(EntityId::from(3u32), bullets.player);
+    assert_eq!(90.0, bullets.yaw);
+    assert_eq!(123, bullets.seed);
+    assert!(bullets.critical);
+}
+
+#[test]
+fn test_particle_effect_name() {
+    use crate::demo::packet::stringtable::StringTableEntry;
+    use crate::demo::sendprop::SendPropValue;
+
+    let mut string_tables = StringTables::default();
+    string_tables.handle_string_entry(
+        "ParticleEffectNames",
+        4,
+        &StringTableEntry {
+            text: Some("critical_rocket_blue".into()),
+            extra_data: None,
+        },
+        0.into(),
+    );
+    let event = |index: i64| EventInfo {
+        class_id: ClassId::from(0u16),
+        fire_delay: 0.0,
+        reliable: false,
+        props: vec![SendProp {
+            index: 0,
+            identifier: SendPropIdentifier::new("DT_TETFParticleEffect", "m_iParticleSystemIndex"),
+            value: SendPropValue::Integer(index),
+        }],
+    };
+    assert_eq!(
+        Some("critical_rocket_blue"),
+        event(4)
+            .particle_effect(Some(&string_tables))
+            .particle
+            .as_deref()
+    );
+    assert_eq!(
+        None,
+        event(5).particle_effect(Some(&string_tables)).particle
+    );
+    assert_eq!(
+        None,
+        event(-1).particle_effect(Some(&string_tables)).particle
+    );
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::demo::data::MaybeUtf8String;
 use crate::demo::message::packetentities::EntityId;
+use crate::demo::sendprop::{read_bit_vec3_coord, write_bit_vec3_coord};
+use crate::demo::vector::Vector;
 use crate::{ReadResult, Stream};
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -92,6 +94,26 @@ pub enum UserMessage<'a> {
     Rumble(RumbleMessage),
     Fade(FadeMessage),
     HapMeleeContact(HapMeleeContactMessage),
+    SayText(Box<SayTextMessage>),
+    HudText(HudTextMessage),
+    HudMsg(Box<HudMsgMessage>),
+    HintText(HintTextMessage),
+    KeyHintText(KeyHintTextMessage),
+    Damage(DamageMessage),
+    PlayerStatsUpdate(PlayerStatsUpdateMessage),
+    PlayerIgnited(PlayerIgnitedMessage),
+    PlayerJarated(PlayerJaratedMessage),
+    PlayerShieldBlocked(PlayerShieldBlockedMessage),
+    BreakModel(BreakModelMessage),
+    VoteStart(Box<VoteStartMessage>),
+    VotePass(Box<VotePassMessage>),
+    VoteFailed(VoteFailedMessage),
+    VoteSetup(VoteSetupMessage),
+    PlayerBonusPoints(PlayerBonusPointsMessage),
+    AchievementEvent(AchievementEventMessage),
+    CloseCaption(CloseCaptionMessage),
+    UpdateRadar(UpdateRadarMessage),
+    TrainingMsg(TrainingMessage),
     Unknown(UnknownUserMessage<'a>),
 }
 
@@ -108,6 +130,26 @@ impl UserMessage<'_> {
             UserMessage::Rumble(_) => UserMessageType::Rumble as u8,
             UserMessage::Fade(_) => UserMessageType::Fade as u8,
             UserMessage::HapMeleeContact(_) => UserMessageType::HapMeleeContact as u8,
+            UserMessage::SayText(_) => UserMessageType::SayText as u8,
+            UserMessage::HudText(_) => UserMessageType::HudText as u8,
+            UserMessage::HudMsg(_) => UserMessageType::HudMsg as u8,
+            UserMessage::HintText(_) => UserMessageType::HintText as u8,
+            UserMessage::KeyHintText(_) => UserMessageType::KeyHintText as u8,
+            UserMessage::Damage(_) => UserMessageType::Damage as u8,
+            UserMessage::PlayerStatsUpdate(_) => UserMessageType::PlayerStatsUpdate as u8,
+            UserMessage::PlayerIgnited(_) => UserMessageType::PlayerIgnited as u8,
+            UserMessage::PlayerJarated(_) => UserMessageType::PlayerJarated as u8,
+            UserMessage::PlayerShieldBlocked(_) => UserMessageType::PlayerShieldBlocked as u8,
+            UserMessage::BreakModel(_) => UserMessageType::BreakModel as u8,
+            UserMessage::VoteStart(_) => UserMessageType::VoteStart as u8,
+            UserMessage::VotePass(_) => UserMessageType::VotePass as u8,
+            UserMessage::VoteFailed(_) => UserMessageType::VoteFailed as u8,
+            UserMessage::VoteSetup(_) => UserMessageType::VoteSetup as u8,
+            UserMessage::PlayerBonusPoints(_) => UserMessageType::PlayerBonusPoints as u8,
+            UserMessage::AchievementEvent(_) => UserMessageType::AchievementEvent as u8,
+            UserMessage::CloseCaption(_) => UserMessageType::CloseCaption as u8,
+            UserMessage::UpdateRadar(_) => UserMessageType::UpdateRadar as u8,
+            UserMessage::TrainingMsg(_) => UserMessageType::TrainingMsg as u8,
             UserMessage::Unknown(msg) => msg.raw_type,
         }
     }
@@ -130,6 +172,34 @@ impl<'a> BitRead<'a, LittleEndian> for UserMessage<'a> {
                     UserMessageType::Rumble => UserMessage::Rumble(data.read()?),
                     UserMessageType::Fade => UserMessage::Fade(data.read()?),
                     UserMessageType::HapMeleeContact => UserMessage::HapMeleeContact(data.read()?),
+                    UserMessageType::SayText => UserMessage::SayText(data.read()?),
+                    UserMessageType::HudText => UserMessage::HudText(data.read()?),
+                    UserMessageType::HudMsg => UserMessage::HudMsg(data.read()?),
+                    UserMessageType::HintText => UserMessage::HintText(data.read()?),
+                    UserMessageType::KeyHintText => UserMessage::KeyHintText(data.read()?),
+                    UserMessageType::Damage => UserMessage::Damage(data.read()?),
+                    UserMessageType::PlayerStatsUpdate => {
+                        UserMessage::PlayerStatsUpdate(data.read()?)
+                    }
+                    UserMessageType::PlayerIgnited => UserMessage::PlayerIgnited(data.read()?),
+                    UserMessageType::PlayerJarated => UserMessage::PlayerJarated(data.read()?),
+                    UserMessageType::PlayerShieldBlocked => {
+                        UserMessage::PlayerShieldBlocked(data.read()?)
+                    }
+                    UserMessageType::BreakModel => UserMessage::BreakModel(data.read()?),
+                    UserMessageType::VoteStart => UserMessage::VoteStart(data.read()?),
+                    UserMessageType::VotePass => UserMessage::VotePass(data.read()?),
+                    UserMessageType::VoteFailed => UserMessage::VoteFailed(data.read()?),
+                    UserMessageType::VoteSetup => UserMessage::VoteSetup(data.read()?),
+                    UserMessageType::PlayerBonusPoints => {
+                        UserMessage::PlayerBonusPoints(data.read()?)
+                    }
+                    UserMessageType::AchievementEvent => {
+                        UserMessage::AchievementEvent(data.read()?)
+                    }
+                    UserMessageType::CloseCaption => UserMessage::CloseCaption(data.read()?),
+                    UserMessageType::UpdateRadar => UserMessage::UpdateRadar(data.read()?),
+                    UserMessageType::TrainingMsg => UserMessage::TrainingMsg(data.read()?),
                     _ => UserMessage::Unknown(UnknownUserMessage {
                         raw_type: message_type as u8,
                         data,
@@ -171,6 +241,26 @@ impl<'a> BitWrite<LittleEndian> for UserMessage<'a> {
             UserMessage::Rumble(body) => stream.write(body),
             UserMessage::Fade(body) => stream.write(body),
             UserMessage::HapMeleeContact(body) => stream.write(body),
+            UserMessage::SayText(body) => stream.write(body),
+            UserMessage::HudText(body) => stream.write(body),
+            UserMessage::HudMsg(body) => stream.write(body),
+            UserMessage::HintText(body) => stream.write(body),
+            UserMessage::KeyHintText(body) => stream.write(body),
+            UserMessage::Damage(body) => stream.write(body),
+            UserMessage::PlayerStatsUpdate(body) => stream.write(body),
+            UserMessage::PlayerIgnited(body) => stream.write(body),
+            UserMessage::PlayerJarated(body) => stream.write(body),
+            UserMessage::PlayerShieldBlocked(body) => stream.write(body),
+            UserMessage::BreakModel(body) => stream.write(body),
+            UserMessage::VoteStart(body) => stream.write(body),
+            UserMessage::VotePass(body) => stream.write(body),
+            UserMessage::VoteFailed(body) => stream.write(body),
+            UserMessage::VoteSetup(body) => stream.write(body),
+            UserMessage::PlayerBonusPoints(body) => stream.write(body),
+            UserMessage::AchievementEvent(body) => stream.write(body),
+            UserMessage::CloseCaption(body) => stream.write(body),
+            UserMessage::UpdateRadar(body) => stream.write(body),
+            UserMessage::TrainingMsg(body) => stream.write(body),
             UserMessage::Unknown(body) => stream.write(&body.data),
         })?;
 
@@ -444,6 +534,560 @@ pub struct HapMeleeContactMessage {
     pub data: u8,
 }
 
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SayTextMessage {
+    pub client: u8,
+    pub text: MaybeUtf8String,
+    pub chat: u8,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HudTextMessage {
+    pub text: MaybeUtf8String,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HudMsgMessage {
+    pub channel: u8,
+    pub x: f32,
+    pub y: f32,
+    pub color1: [u8; 4],
+    pub color2: [u8; 4],
+    pub effect: u8,
+    pub fade_in_time: f32,
+    pub fade_out_time: f32,
+    pub hold_time: f32,
+    pub fx_time: f32,
+    pub text: MaybeUtf8String,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HintTextMessage {
+    pub text: MaybeUtf8String,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyHintTextMessage {
+    #[size_bits = 8]
+    pub hints: Vec<MaybeUtf8String>,
+}
+
+impl BitWrite<LittleEndian> for KeyHintTextMessage {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        (self.hints.len() as u8).write(stream)?;
+        for hint in &self.hints {
+            hint.write(stream)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DamageMessage {
+    pub damage: u16,
+    pub damage_type: u32,
+    /// Origin of the damage, only set when the damage indicator should be shown
+    pub origin: Option<Vector>,
+}
+
+impl BitRead<'_, LittleEndian> for DamageMessage {
+    fn read(stream: &mut Stream) -> ReadResult<Self> {
+        let damage = stream.read()?;
+        let damage_type = stream.read()?;
+        let origin = if stream.read()? {
+            Some(read_bit_vec3_coord(stream)?)
+        } else {
+            None
+        };
+        Ok(DamageMessage {
+            damage,
+            damage_type,
+            origin,
+        })
+    }
+}
+
+impl BitWrite<LittleEndian> for DamageMessage {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        self.damage.write(stream)?;
+        self.damage_type.write(stream)?;
+        self.origin.is_some().write(stream)?;
+        if let Some(origin) = self.origin {
+            write_bit_vec3_coord(origin, stream)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStat {
+    /// The index of the stat (`TFStatType_t`)
+    pub stat: u8,
+    pub value: u32,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStatsUpdateMessage {
+    pub class: u8,
+    pub alive: u8,
+    /// The stats that changed since the last update
+    pub stats: Vec<PlayerStat>,
+}
+
+impl BitRead<'_, LittleEndian> for PlayerStatsUpdateMessage {
+    fn read(stream: &mut Stream) -> ReadResult<Self> {
+        let class = stream.read()?;
+        let alive = stream.read()?;
+        let send_bits: u32 = stream.read()?;
+        let stats = (0..32u8)
+            .filter(|stat| send_bits & (1 << stat) != 0)
+            .map(|stat| {
+                Ok(PlayerStat {
+                    stat,
+                    value: stream.read()?,
+                })
+            })
+            .collect::<ReadResult<_>>()?;
+        Ok(PlayerStatsUpdateMessage {
+            class,
+            alive,
+            stats,
+        })
+    }
+}
+
+impl BitWrite<LittleEndian> for PlayerStatsUpdateMessage {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        self.class.write(stream)?;
+        self.alive.write(stream)?;
+        let send_bits = self
+            .stats
+            .iter()
+            .fold(0u32, |bits, stat| bits | (1 << stat.stat));
+        send_bits.write(stream)?;
+        let mut stats = self.stats.clone();
+        stats.sort_by_key(|stat| stat.stat);
+        for stat in stats {
+            stat.value.write(stream)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerIgnitedMessage {
+    pub attacker: u8,
+    pub victim: u8,
+    pub weapon_id: u16,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerJaratedMessage {
+    pub attacker: u8,
+    pub victim: u8,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerShieldBlockedMessage {
+    pub attacker: u8,
+    pub blocker: u8,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreakModelMessage {
+    pub model_index: u16,
+    pub origin: Vector,
+    pub angles: Vector,
+    pub skin: u16,
+}
+
+impl BitRead<'_, LittleEndian> for BreakModelMessage {
+    fn read(stream: &mut Stream) -> ReadResult<Self> {
+        Ok(BreakModelMessage {
+            model_index: stream.read()?,
+            origin: read_bit_vec3_coord(stream)?,
+            angles: read_bit_vec3_coord(stream)?,
+            skin: stream.read()?,
+        })
+    }
+}
+
+impl BitWrite<LittleEndian> for BreakModelMessage {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        self.model_index.write(stream)?;
+        write_bit_vec3_coord(self.origin, stream)?;
+        write_bit_vec3_coord(self.angles, stream)?;
+        self.skin.write(stream)
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteStartMessage {
+    pub team: u8,
+    /// Not included in demos from before the vote index was added to the message
+    pub vote_index: Option<u32>,
+    pub caller: u8,
+    pub issue: MaybeUtf8String,
+    pub details: MaybeUtf8String,
+    pub yes_no: bool,
+    /// The entity index of the player targeted by the vote, if any
+    pub target: u8,
+}
+
+impl BitRead<'_, LittleEndian> for VoteStartMessage {
+    fn read(stream: &mut Stream) -> ReadResult<Self> {
+        let team = stream.read()?;
+        // older demos don't include the vote index, the protocol version is the same for both layouts
+        // so they are told apart by where the issue starts, which is always a localization token
+        let vote_index = if issue_starts_at(stream, 5) || !issue_starts_at(stream, 1) {
+            Some(stream.read()?)
+        } else {
+            None
+        };
+        let caller = stream.read()?;
+        let issue = stream.read()?;
+        let details = stream.read()?;
+        let yes_no = stream.read()?;
+        // older demos don't include the vote target
+        let target = if stream.bits_left() >= 8 {
+            stream.read()?
+        } else {
+            0
+        };
+        Ok(VoteStartMessage {
+            team,
+            vote_index,
+            caller,
+            issue,
+            details,
+            yes_no,
+            target,
+        })
+    }
+}
+
+/// Whether the byte at `offset` bytes from the current position is the start of a localization token
+fn issue_starts_at(stream: &Stream, offset: usize) -> bool {
+    let mut stream = stream.clone();
+    stream.skip_bits(offset * 8).is_ok() && stream.read::<u8>().ok() == Some(b'#')
+}
+
+impl BitWrite<LittleEndian> for VoteStartMessage {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        self.team.write(stream)?;
+        if let Some(vote_index) = self.vote_index {
+            vote_index.write(stream)?;
+        }
+        self.caller.write(stream)?;
+        self.issue.write(stream)?;
+        self.details.write(stream)?;
+        self.yes_no.write(stream)?;
+        self.target.write(stream)
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VotePassMessage {
+    pub team: u8,
+    pub vote_index: u32,
+    pub result: MaybeUtf8String,
+    pub details: MaybeUtf8String,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteFailedMessage {
+    pub team: u8,
+    pub vote_index: u32,
+    pub reason: u8,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteIssue {
+    pub name: MaybeUtf8String,
+    pub translation: MaybeUtf8String,
+    pub enabled: u8,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteSetupMessage {
+    #[size_bits = 8]
+    pub issues: Vec<VoteIssue>,
+}
+
+impl BitWrite<LittleEndian> for VoteSetupMessage {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        (self.issues.len() as u8).write(stream)?;
+        for issue in &self.issues {
+            issue.write(stream)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerBonusPointsMessage {
+    pub points: u8,
+    pub player: u8,
+    pub source: u16,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementEventMessage {
+    pub achievement: u16,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloseCaptionMessage {
+    pub token: MaybeUtf8String,
+    /// Duration in tenths of a second
+    pub duration: u16,
+    pub flags: u8,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RadarPlayer {
+    pub index: u8,
+    pub position: Vector,
+    pub yaw: i16,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateRadarMessage {
+    pub players: Vec<RadarPlayer>,
+}
+
+impl BitRead<'_, LittleEndian> for UpdateRadarMessage {
+    fn read(stream: &mut Stream) -> ReadResult<Self> {
+        let mut players = Vec::new();
+        loop {
+            let index: u8 = stream.read()?;
+            if index == 0 {
+                break;
+            }
+            // positions are send with a precision of 4 units
+            let x: i16 = stream.read_int(13)?;
+            let y: i16 = stream.read_int(13)?;
+            let z: i16 = stream.read_int(13)?;
+            let yaw = stream.read_int(9)?;
+            players.push(RadarPlayer {
+                index,
+                position: Vector {
+                    x: x as f32 * 4.0,
+                    y: y as f32 * 4.0,
+                    z: z as f32 * 4.0,
+                },
+                yaw,
+            });
+        }
+        Ok(UpdateRadarMessage { players })
+    }
+}
+
+impl BitWrite<LittleEndian> for UpdateRadarMessage {
+    fn write(&self, stream: &mut BitWriteStream<LittleEndian>) -> ReadResult<()> {
+        for player in &self.players {
+            player.index.write(stream)?;
+            stream.write_int((player.position.x / 4.0) as i16, 13)?;
+            stream.write_int((player.position.y / 4.0) as i16, 13)?;
+            stream.write_int((player.position.z / 4.0) as i16, 13)?;
+            stream.write_int(player.yaw, 9)?;
+        }
+        0u8.write(stream)
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(BitRead, BitWrite, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrainingMessage {
+    pub text: MaybeUtf8String,
+}
+
+#[cfg(test)]
+use test_case::test_case;
+
+#[cfg(test)]
+#[test_case(UserMessage::SayText(Box::new(SayTextMessage {
+    client: 0,
+    text: "Server will restart in 5 minutes".into(),
+    chat: 1,
+})) ; "say_text")]
+#[test_case(UserMessage::HudText(HudTextMessage {
+    text: "#TF_Welcome".into(),
+}) ; "hud_text")]
+#[test_case(UserMessage::HudMsg(Box::new(HudMsgMessage {
+    channel: 1,
+    x: -1.0,
+    y: 0.3,
+    color1: [255, 0, 0, 255],
+    color2: [0, 255, 0, 128],
+    effect: 2,
+    fade_in_time: 0.1,
+    fade_out_time: 0.5,
+    hold_time: 5.0,
+    fx_time: 0.25,
+    text: "Round starts in 10 seconds".into(),
+})) ; "hud_msg")]
+#[test_case(UserMessage::HintText(HintTextMessage {
+    text: "#Hint_spy_cloak".into(),
+}) ; "hint_text")]
+#[test_case(UserMessage::KeyHintText(KeyHintTextMessage {
+    hints: vec!["#Hint_use_key".into()],
+}) ; "key_hint_text")]
+#[test_case(UserMessage::Damage(DamageMessage {
+    damage: 72,
+    damage_type: 1 << 6,
+    origin: Some(Vector {
+        x: 128.5,
+        y: -1024.0,
+        z: 0.0,
+    }),
+}) ; "damage")]
+#[test_case(UserMessage::Damage(DamageMessage {
+    damage: 4,
+    damage_type: 1 << 3,
+    origin: None,
+}) ; "damage_without_origin")]
+#[test_case(UserMessage::PlayerStatsUpdate(PlayerStatsUpdateMessage {
+    class: 3,
+    alive: 1,
+    stats: vec![
+        PlayerStat { stat: 1, value: 5 },
+        PlayerStat {
+            stat: 12,
+            value: 1234,
+        },
+    ],
+}) ; "player_stats_update")]
+#[test_case(UserMessage::PlayerIgnited(PlayerIgnitedMessage {
+    attacker: 4,
+    victim: 12,
+    weapon_id: 21,
+}) ; "player_ignited")]
+#[test_case(UserMessage::PlayerJarated(PlayerJaratedMessage {
+    attacker: 2,
+    victim: 8,
+}) ; "player_jarated")]
+#[test_case(UserMessage::PlayerShieldBlocked(PlayerShieldBlockedMessage {
+    attacker: 5,
+    blocker: 9,
+}) ; "player_shield_blocked")]
+#[test_case(UserMessage::BreakModel(BreakModelMessage {
+    model_index: 312,
+    origin: Vector {
+        x: 12.0,
+        y: 1500.5,
+        z: -64.0,
+    },
+    angles: Vector {
+        x: 0.0,
+        y: 90.0,
+        z: 0.0,
+    },
+    skin: 1,
+}) ; "break_model")]
+#[test_case(UserMessage::VoteStart(Box::new(VoteStartMessage {
+    team: 0,
+    vote_index: Some(3),
+    caller: 7,
+    issue: "#TF_vote_kick_player_other".into(),
+    details: "Old Billy Riley".into(),
+    yes_no: true,
+    target: 9,
+})) ; "vote_start")]
+#[test_case(UserMessage::VoteStart(Box::new(VoteStartMessage {
+    team: 0,
+    vote_index: None,
+    caller: 7,
+    issue: "#TF_vote_kick_player_other".into(),
+    details: "Old Billy Riley".into(),
+    yes_no: true,
+    target: 0,
+})) ; "vote_start_without_vote_index")]
+#[test_case(UserMessage::VotePass(Box::new(VotePassMessage {
+    team: 0,
+    vote_index: 3,
+    result: "#TF_vote_passed_kick_player".into(),
+    details: "Old Billy Riley".into(),
+})) ; "vote_pass")]
+#[test_case(UserMessage::VoteFailed(VoteFailedMessage {
+    team: 2,
+    vote_index: 4,
+    reason: 3,
+}) ; "vote_failed")]
+#[test_case(UserMessage::VoteSetup(VoteSetupMessage {
+    issues: vec![
+        VoteIssue {
+            name: "Kick".into(),
+            translation: "#TF_Kick".into(),
+            enabled: 1,
+        },
+        VoteIssue {
+            name: "ChangeLevel".into(),
+            translation: "#TF_ChangeLevel".into(),
+            enabled: 0,
+        },
+    ],
+}) ; "vote_setup")]
+#[test_case(UserMessage::PlayerBonusPoints(PlayerBonusPointsMessage {
+    points: 1,
+    player: 3,
+    source: 120,
+}) ; "player_bonus_points")]
+#[test_case(UserMessage::AchievementEvent(AchievementEventMessage {
+    achievement: 1701,
+}) ; "achievement_event")]
+#[test_case(UserMessage::CloseCaption(CloseCaptionMessage {
+    token: "scout.battlecry01".into(),
+    duration: 25,
+    flags: 0,
+}) ; "close_caption")]
+#[test_case(UserMessage::UpdateRadar(UpdateRadarMessage {
+    players: vec![
+        RadarPlayer {
+            index: 1,
+            position: Vector {
+                x: 1024.0,
+                y: -512.0,
+                z: 64.0,
+            },
+            yaw: -90,
+        },
+        RadarPlayer {
+            index: 2,
+            position: Vector::default(),
+            yaw: 180,
+        },
+    ],
+}) ; "update_radar")]
+#[test_case(UserMessage::TrainingMsg(TrainingMessage {
+    text: "#TF_Training_Intro".into(),
+}) ; "training_msg")]
+fn test_typed_user_message_roundtrip(message: UserMessage) {
+    crate::test_roundtrip_write(message);
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(bound(deserialize = "'a: 'static"))]
@@ -1,6 +1,10 @@
 use bitbuffer::{BitRead, BitWrite, BitWriteSized, BitWriteStream, LittleEndian};
+use enumflags2::{bitflags, BitFlags};
+use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::vector::Vector;
 use crate::{ReadResult, Stream};
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -11,6 +15,29 @@ pub struct VoiceInitMessage {
     sampling_rate: u16,
 }
 
+impl VoiceInitMessage {
+    pub fn new(codec: String, quality: u8, sampling_rate: u16) -> Self {
+        VoiceInitMessage {
+            codec,
+            quality,
+            sampling_rate,
+        }
+    }
+
+    /// Name of the codec used for the voice data, e.g. `steam` or `vaudio_celt`
+    pub fn codec(&self) -> &str {
+        &self.codec
+    }
+
+    pub fn quality(&self) -> u8 {
+        self.quality
+    }
+
+    pub fn sampling_rate(&self) -> u16 {
+        self.sampling_rate
+    }
+}
+
 impl BitRead<'_, LittleEndian> for VoiceInitMessage {
     fn read(stream: &mut Stream) -> ReadResult<Self> {
         let codec = stream.read()?;
@@ -74,6 +101,36 @@ pub struct VoiceDataMessage<'a> {
     data: Stream<'a>,
 }
 
+impl<'a> VoiceDataMessage<'a> {
+    /// Index of the client that is speaking, the entity id of the speaker is `client + 1`
+    pub fn client(&self) -> u8 {
+        self.client
+    }
+
+    pub fn proximity(&self) -> bool {
+        self.proximity != 0
+    }
+
+    /// Length of the voice payload in bits
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// The raw voice payload, encoded with the codec from the [`VoiceInitMessage`]
+    pub fn data(&self) -> Stream<'a> {
+        self.data.clone()
+    }
+
+    /// The voice payload as bytes
+    pub fn bytes(&self) -> Vec<u8> {
+        let mut data = self.data.clone();
+        let len = data.bits_left() / 8;
+        data.read_bytes(len)
+            .map(|bytes| bytes.into_owned())
+            .unwrap_or_default()
+    }
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(bound(deserialize = "'a: 'static"))]
@@ -123,6 +180,411 @@ impl<'a> BitWrite<LittleEndian> for ParseSoundsMessage<'a> {
     }
 }
 
+impl ParseSoundsMessage<'_> {
+    /// Decode the individual sounds contained in the message.
+    ///
+    /// Sounds are delta encoded against the previous sound in the message, the layout depends on
+    /// the network protocol version of the demo.
+    pub fn sounds(&self, protocol_version: u32) -> ReadResult<Vec<SoundEvent>> {
+        let mut stream = self.data.clone();
+        let mut previous = SoundEvent::default();
+        let mut sounds = Vec::with_capacity(self.num as usize);
+        for _ in 0..self.num {
+            let sound = SoundEvent::read_delta(&mut stream, &previous, protocol_version)?;
+            previous = sound.clone();
+            sounds.push(sound);
+        }
+        Ok(sounds)
+    }
+}
+
+#[bitflags]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[repr(u16)]
+pub enum SoundFlag {
+    ChangeVolume = 1,
+    ChangePitch = 2,
+    Stop = 4,
+    /// Sound is being played as part of an entity spawning
+    Spawning = 8,
+    Delay = 16,
+    StopLooping = 32,
+    /// Sound is played through a speaker entity
+    Speaker = 64,
+    ShouldPause = 128,
+    IgnorePhonemes = 256,
+    IgnoreName = 512,
+    DoNotOverwriteExistingOnChannel = 1024,
+}
+
+pub type SoundFlags = BitFlags<SoundFlag>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TryFromPrimitive, Default)]
+#[repr(u8)]
+pub enum SoundChannel {
+    Auto = 0,
+    Weapon = 1,
+    Voice = 2,
+    Item = 3,
+    Body = 4,
+    Stream = 5,
+    #[default]
+    Static = 6,
+    VoiceBase = 7,
+}
+
+const SOUND_INDEX_BITS: u32 = 14;
+const SOUND_FLAG_BITS: u32 = 11;
+const SOUND_SEQUENCE_BITS: u32 = 10;
+const SOUND_LEVEL_BITS: u32 = 9;
+/// `SNDLVL_NONE`, the sound level of a stopped sound
+const SOUND_LEVEL_NONE: u16 = 0;
+const SOUND_DELAY_BITS: u32 = 13;
+const SOUND_DELAY_OFFSET: f32 = 0.1;
+const SOUND_ORIGIN_BITS: u32 = 12;
+const SOUND_ORIGIN_SCALE: f32 = 8.0;
+const MAX_EDICT_BITS: u32 = 11;
+
+/// A single sound played by the server
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoundEvent {
+    pub entity: EntityId,
+    /// Index into the `soundprecache` string table
+    pub sound_index: u16,
+    pub flags: SoundFlags,
+    pub channel: SoundChannel,
+    pub ambient: bool,
+    pub sentence: bool,
+    pub sequence: u16,
+    pub volume: f32,
+    pub sound_level: u16,
+    pub pitch: u8,
+    pub special_dsp: u8,
+    /// Delay in seconds before the sound starts playing
+    pub delay: f32,
+    pub origin: Vector,
+    pub speaker: i32,
+}
+
+impl Default for SoundEvent {
+    fn default() -> Self {
+        SoundEvent {
+            entity: EntityId::default(),
+            sound_index: 0,
+            flags: SoundFlags::empty(),
+            channel: SoundChannel::Static,
+            ambient: false,
+            sentence: false,
+            sequence: 0,
+            volume: 1.0,
+            sound_level: 75,
+            pitch: 100,
+            special_dsp: 0,
+            delay: 0.0,
+            origin: Vector::default(),
+            speaker: -1,
+        }
+    }
+}
+
+fn read_delta<T, F>(stream: &mut Stream, previous: T, read: F) -> ReadResult<T>
+where
+    F: FnOnce(&mut Stream) -> ReadResult<T>,
+{
+    if stream.read_bool()? {
+        read(stream)
+    } else {
+        Ok(previous)
+    }
+}
+
+fn write_delta<T, F>(
+    stream: &mut BitWriteStream<LittleEndian>,
+    value: T,
+    previous: T,
+    write: F,
+) -> ReadResult<()>
+where
+    T: PartialEq,
+    F: FnOnce(&mut BitWriteStream<LittleEndian>, T) -> ReadResult<()>,
+{
+    let changed = value != previous;
+    changed.write(stream)?;
+    if changed {
+        write(stream, value)?;
+    }
+    Ok(())
+}
+
+fn write_signed(
+    stream: &mut BitWriteStream<LittleEndian>,
+    value: i32,
+    bits: u32,
+) -> ReadResult<()> {
+    stream.write_int(value as u32 & ((1 << bits) - 1), bits as usize)
+}
+
+impl SoundEvent {
+    /// Whether this event stops a previously started sound
+    pub fn is_stop(&self) -> bool {
+        self.flags == SoundFlag::Stop
+    }
+
+    fn read_delta(
+        stream: &mut Stream,
+        previous: &SoundEvent,
+        protocol_version: u32,
+    ) -> ReadResult<Self> {
+        let entity = read_delta(stream, previous.entity, |stream| {
+            let bits = if stream.read_bool()? {
+                5
+            } else {
+                MAX_EDICT_BITS
+            };
+            Ok(stream.read_int::<u32>(bits as usize)?.into())
+        })?;
+        let index_bits = if protocol_version > 22 {
+            SOUND_INDEX_BITS
+        } else {
+            13
+        };
+        let sound_index = read_delta(stream, previous.sound_index, |stream| {
+            stream.read_int(index_bits as usize)
+        })?;
+        // there were 9 flag bits prior to protocol 19
+        let flag_bits = if protocol_version > 18 {
+            SOUND_FLAG_BITS
+        } else {
+            9
+        };
+        let flags = read_delta(stream, previous.flags, |stream| {
+            Ok(SoundFlags::from_bits_truncate(
+                stream.read_int(flag_bits as usize)?,
+            ))
+        })?;
+        let channel = read_delta(stream, previous.channel, |stream| {
+            Ok(SoundChannel::try_from(stream.read_int::<u8>(3)?).unwrap_or_default())
+        })?;
+        let ambient = stream.read_bool()?;
+        let sentence = stream.read_bool()?;
+
+        let mut sound = SoundEvent {
+            entity,
+            sound_index,
+            flags,
+            channel,
+            ambient,
+            sentence,
+            ..SoundEvent::default()
+        };
+
+        // stop messages don't contain any of the playback fields, the engine clears them
+        if sound.is_stop() {
+            sound.volume = 0.0;
+            sound.sound_level = SOUND_LEVEL_NONE;
+            return Ok(sound);
+        }
+
+        sound.sequence = if stream.read_bool()? {
+            previous.sequence
+        } else if stream.read_bool()? {
+            previous.sequence.wrapping_add(1)
+        } else {
+            stream.read_int(SOUND_SEQUENCE_BITS as usize)?
+        };
+        sound.volume = read_delta(stream, previous.volume, |stream| {
+            Ok(stream.read_int::<u8>(7)? as f32 / 127.0)
+        })?;
+        sound.sound_level = read_delta(stream, previous.sound_level, |stream| {
+            stream.read_int(SOUND_LEVEL_BITS as usize)
+        })?;
+        sound.pitch = read_delta(stream, previous.pitch, |stream| stream.read_int(8))?;
+        if protocol_version > 21 {
+            sound.special_dsp =
+                read_delta(stream, previous.special_dsp, |stream| stream.read_int(8))?;
+        }
+        sound.delay = read_delta(stream, previous.delay, |stream| {
+            let mut delay = stream.read_int::<i32>(SOUND_DELAY_BITS as usize)? as f32 / 1000.0;
+            if delay < 0.0 {
+                delay *= 10.0;
+            }
+            Ok(delay - SOUND_DELAY_OFFSET)
+        })?;
+        let read_coord = |stream: &mut Stream| {
+            Ok(stream.read_int::<i32>(SOUND_ORIGIN_BITS as usize)? as f32 * SOUND_ORIGIN_SCALE)
+        };
+        sound.origin = Vector {
+            x: read_delta(stream, previous.origin.x, read_coord)?,
+            y: read_delta(stream, previous.origin.y, read_coord)?,
+            z: read_delta(stream, previous.origin.z, read_coord)?,
+        };
+        sound.speaker = read_delta(stream, previous.speaker, |stream| {
+            stream.read_int(MAX_EDICT_BITS as usize + 1)
+        })?;
+
+        Ok(sound)
+    }
+
+    /// Encode the sound for the latest protocol version
+    pub fn write_delta(
+        &self,
+        stream: &mut BitWriteStream<LittleEndian>,
+        previous: &SoundEvent,
+    ) -> ReadResult<()> {
+        write_delta(stream, self.entity, previous.entity, |stream, entity| {
+            let entity = u32::from(entity);
+            let short = entity <= 31;
+            short.write(stream)?;
+            stream.write_int(entity, if short { 5 } else { MAX_EDICT_BITS as usize })
+        })?;
+        write_delta(
+            stream,
+            self.sound_index,
+            previous.sound_index,
+            |stream, index| stream.write_int(index, SOUND_INDEX_BITS as usize),
+        )?;
+        write_delta(stream, self.flags, previous.flags, |stream, flags| {
+            stream.write_int(flags.bits(), SOUND_FLAG_BITS as usize)
+        })?;
+        write_delta(stream, self.channel, previous.channel, |stream, channel| {
+            stream.write_int(channel as u8, 3)
+        })?;
+        self.ambient.write(stream)?;
+        self.sentence.write(stream)?;
+
+        if self.is_stop() {
+            return Ok(());
+        }
+
+        if self.sequence == previous.sequence {
+            true.write(stream)?;
+        } else if self.sequence == previous.sequence.wrapping_add(1) {
+            false.write(stream)?;
+            true.write(stream)?;
+        } else {
+            stream.write_int(0u8, 2)?;
+            stream.write_int(self.sequence, SOUND_SEQUENCE_BITS as usize)?;
+        }
+        write_delta(stream, self.volume, previous.volume, |stream, volume| {
+            stream.write_int((volume * 127.0).round() as u8, 7)
+        })?;
+        write_delta(
+            stream,
+            self.sound_level,
+            previous.sound_level,
+            |stream, level| stream.write_int(level, SOUND_LEVEL_BITS as usize),
+        )?;
+        write_delta(stream, self.pitch, previous.pitch, |stream, pitch| {
+            stream.write_int(pitch, 8)
+        })?;
+        write_delta(
+            stream,
+            self.special_dsp,
+            previous.special_dsp,
+            |stream, dsp| stream.write_int(dsp, 8),
+        )?;
+        write_delta(stream, self.delay, previous.delay, |stream, delay| {
+            let mut delay = ((delay + SOUND_DELAY_OFFSET) * 1000.0).round() as i32;
+            if delay < 0 {
+                delay /= 10;
+            }
+            write_signed(stream, delay, SOUND_DELAY_BITS)
+        })?;
+        let write_coord = |stream: &mut BitWriteStream<LittleEndian>, coord: f32| {
+            write_signed(
+                stream,
+                (coord / SOUND_ORIGIN_SCALE) as i32,
+                SOUND_ORIGIN_BITS,
+            )
+        };
+        write_delta(stream, self.origin.x, previous.origin.x, write_coord)?;
+        write_delta(stream, self.origin.y, previous.origin.y, write_coord)?;
+        write_delta(stream, self.origin.z, previous.origin.z, write_coord)?;
+        write_delta(stream, self.speaker, previous.speaker, |stream, speaker| {
+            write_signed(stream, speaker, MAX_EDICT_BITS + 1)
+        })
+    }
+}
+
+#[test]
+fn test_sound_delta_roundtrip() {
+    use bitbuffer::BitReadBuffer;
+
+    let sounds = vec![
+        SoundEvent {
+            entity: 12u32.into(),
+            sound_index: 1034,
+            channel: SoundChannel::Body,
+            sequence: 3,
+            volume: 64.0 / 127.0,
+            pitch: 95,
+            origin: Vector {
+                x: 1024.0,
+                y: -256.0,
+                z: 64.0,
+            },
+            ..SoundEvent::default()
+        },
+        SoundEvent {
+            entity: 12u32.into(),
+            sound_index: 1035,
+            channel: SoundChannel::Body,
+            sequence: 4,
+            volume: 64.0 / 127.0,
+            pitch: 95,
+            special_dsp: 3,
+            delay: 0.4,
+            origin: Vector {
+                x: 1024.0,
+                y: -248.0,
+                z: 64.0,
+            },
+            ..SoundEvent::default()
+        },
+        SoundEvent {
+            entity: 1500u32.into(),
+            sound_index: 1034,
+            flags: SoundFlag::Stop.into(),
+            channel: SoundChannel::Body,
+            volume: 0.0,
+            sound_level: 0,
+            ..SoundEvent::default()
+        },
+    ];
+
+    let mut data = Vec::new();
+    {
+        let mut stream = BitWriteStream::new(&mut data, LittleEndian);
+        let mut previous = SoundEvent::default();
+        for sound in &sounds {
+            sound.write_delta(&mut stream, &previous).unwrap();
+            previous = sound.clone();
+        }
+    }
+    let buffer = BitReadBuffer::new(&data, LittleEndian);
+    let message = ParseSoundsMessage {
+        reliable: false,
+        num: sounds.len() as u8,
+        length: buffer.bit_len() as u16,
+        data: buffer.into(),
+    };
+    let parsed = message.sounds(24).unwrap();
+    assert_eq!(sounds, parsed);
+
+    // a stop clears the playback fields instead of inheriting them from the previous sound
+    let stop = parsed.last().unwrap();
+    assert_eq!(0.0, stop.volume);
+    assert_eq!(0, stop.sound_level);
+    assert_eq!(100, stop.pitch);
+    assert_eq!(0, stop.special_dsp);
+
+    // older protocols don't send the dsp, only an unchanged sequence is set
+    let mut stream = Stream::new(BitReadBuffer::new(&[0b0100_0000, 0, 0, 0], LittleEndian));
+    let sound = SoundEvent::read_delta(&mut stream, &parsed[1], 21).unwrap();
+    assert_eq!(4, sound.sequence);
+    assert_eq!(0, sound.special_dsp);
+}
+
 #[test]
 fn test_parse_sounds_roundtrip() {
     use bitbuffer::BitReadBuffer;
@@ -1,6 +1,8 @@
 use bitbuffer::{BitReadBuffer, BitReadStream, LittleEndian};
 
 pub mod data;
+pub mod entityview;
+pub mod entityview_gen;
 pub mod gameevent_gen;
 pub mod gamevent;
 pub mod header;
@@ -11,6 +13,7 @@ pub mod parser;
 pub mod sendprop;
 mod sendprop_gen;
 pub mod vector;
+pub mod voice;
 
 pub type Buffer<'a> = BitReadBuffer<'a, LittleEndian>;
 pub type Stream<'a> = BitReadStream<'a, LittleEndian>;
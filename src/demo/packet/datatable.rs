@@ -59,7 +59,9 @@ impl PartialEq<u16> for ClassId {
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(BitRead, BitWrite, PartialEq, Eq, Hash, Debug, Serialize, Deserialize, Clone, Display)]
+#[derive(
+    BitRead, BitWrite, PartialEq, Eq, Hash, Debug, Serialize, Deserialize, Clone, Display, Default,
+)]
 pub struct ServerClassName(String);
 
 impl ServerClassName {
@@ -1,5 +1,6 @@
 use crate::demo::data::DemoTick;
 use bitbuffer::{BitRead, BitReadStream, BitWrite, BitWriteStream, LittleEndian};
+use enumflags2::{bitflags, BitFlags};
 use serde::{Deserialize, Serialize};
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -43,8 +44,87 @@ pub struct UserCmd {
     pub buttons: Option<u32>,
     pub impulse: Option<u8>,
     pub weapon_select: Option<WeaponSelect>,
-    pub mouse_dx: Option<u16>,
-    pub mouse_dy: Option<u16>,
+    pub mouse_dx: Option<i16>,
+    pub mouse_dy: Option<i16>,
+}
+
+impl UserCmd {
+    pub fn buttons(&self) -> Option<Buttons> {
+        self.buttons.map(Buttons::from_bits_truncate)
+    }
+
+    /// Fill in the fields that aren't present in this command from the command it was encoded against,
+    /// the same way the engine's `ReadUsercmd` does.
+    pub fn resolve(&self, from: &ResolvedUserCmd) -> ResolvedUserCmd {
+        let mut cmd = from.clone();
+        cmd.command_number = self
+            .command_number
+            .unwrap_or_else(|| from.command_number.wrapping_add(1));
+        cmd.tick_count = self
+            .tick_count
+            .unwrap_or_else(|| from.tick_count.wrapping_add(1));
+        for (i, angle) in self.view_angles.iter().enumerate() {
+            if let Some(angle) = angle {
+                cmd.view_angles[i] = *angle;
+            }
+        }
+        for (i, movement) in self.movement.iter().enumerate() {
+            if let Some(movement) = movement {
+                cmd.movement[i] = *movement;
+            }
+        }
+        if let Some(buttons) = self.buttons() {
+            cmd.buttons = buttons;
+        }
+        if let Some(impulse) = self.impulse {
+            cmd.impulse = impulse;
+        }
+        if let Some(weapon_select) = &self.weapon_select {
+            cmd.weapon_select = weapon_select.select;
+            if let Some(subtype) = weapon_select.subtype {
+                cmd.weapon_subtype = subtype;
+            }
+        }
+        if let Some(mouse_dx) = self.mouse_dx {
+            cmd.mouse_dx = mouse_dx;
+        }
+        if let Some(mouse_dy) = self.mouse_dy {
+            cmd.mouse_dy = mouse_dy;
+        }
+        cmd
+    }
+}
+
+/// A user command with all fields filled in
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+pub struct ResolvedUserCmd {
+    pub command_number: u32,
+    pub tick_count: u32,
+    pub view_angles: [f32; 3],
+    /// Forward, side and up movement
+    pub movement: [f32; 3],
+    pub buttons: Buttons,
+    pub impulse: u8,
+    pub weapon_select: u16,
+    pub weapon_subtype: u8,
+    pub mouse_dx: i16,
+    pub mouse_dy: i16,
+}
+
+impl ResolvedUserCmd {
+    pub fn is_pressed(&self, button: Button) -> bool {
+        self.buttons.contains(button)
+    }
+
+    /// Buttons that are pressed in this command but weren't in the previous one
+    pub fn pressed_since(&self, previous: &ResolvedUserCmd) -> Buttons {
+        self.buttons & !previous.buttons
+    }
+
+    /// Buttons that were pressed in the previous command but aren't anymore
+    pub fn released_since(&self, previous: &ResolvedUserCmd) -> Buttons {
+        previous.buttons & !self.buttons
+    }
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -55,3 +135,82 @@ pub struct WeaponSelect {
     #[size = 6]
     subtype: Option<u8>,
 }
+
+#[bitflags]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[repr(u32)]
+pub enum Button {
+    Attack = 1 << 0,
+    Jump = 1 << 1,
+    Duck = 1 << 2,
+    Forward = 1 << 3,
+    Back = 1 << 4,
+    Use = 1 << 5,
+    Cancel = 1 << 6,
+    Left = 1 << 7,
+    Right = 1 << 8,
+    MoveLeft = 1 << 9,
+    MoveRight = 1 << 10,
+    Attack2 = 1 << 11,
+    Run = 1 << 12,
+    Reload = 1 << 13,
+    Alt1 = 1 << 14,
+    Alt2 = 1 << 15,
+    Score = 1 << 16,
+    Speed = 1 << 17,
+    Walk = 1 << 18,
+    Zoom = 1 << 19,
+    Weapon1 = 1 << 20,
+    Weapon2 = 1 << 21,
+    BullRush = 1 << 22,
+    Grenade1 = 1 << 23,
+    Grenade2 = 1 << 24,
+    Attack3 = 1 << 25,
+}
+
+pub type Buttons = BitFlags<Button>;
+
+#[test]
+fn test_resolve_user_cmd() {
+    let from = ResolvedUserCmd {
+        command_number: 10,
+        tick_count: 100,
+        view_angles: [1.0, 2.0, 0.0],
+        buttons: Button::Attack | Button::Duck,
+        ..ResolvedUserCmd::default()
+    };
+    let cmd = UserCmd {
+        command_number: None,
+        tick_count: Some(105),
+        view_angles: [None, Some(3.0), None],
+        movement: [Some(450.0), None, None],
+        buttons: Some(Button::Duck as u32 | Button::Jump as u32),
+        impulse: None,
+        weapon_select: Some(WeaponSelect {
+            select: 42,
+            subtype: None,
+        }),
+        mouse_dx: Some(-12),
+        mouse_dy: None,
+    };
+
+    let resolved = cmd.resolve(&from);
+    assert_eq!(
+        resolved,
+        ResolvedUserCmd {
+            command_number: 11,
+            tick_count: 105,
+            view_angles: [1.0, 3.0, 0.0],
+            movement: [450.0, 0.0, 0.0],
+            buttons: Button::Duck | Button::Jump,
+            impulse: 0,
+            weapon_select: 42,
+            weapon_subtype: 0,
+            mouse_dx: -12,
+            mouse_dy: 0,
+        }
+    );
+    assert!(resolved.is_pressed(Button::Jump));
+    assert_eq!(resolved.pressed_since(&from), Button::Jump);
+    assert_eq!(resolved.released_since(&from), Button::Attack);
+}
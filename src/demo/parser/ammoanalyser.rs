@@ -0,0 +1,428 @@
+use crate::demo::data::DemoTick;
+use crate::demo::gamevent::GameEvent;
+use crate::demo::message::packetentities::{EntityId, PacketEntity, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::packet::stringtable::StringTableEntry;
+pub use crate::demo::parser::analyser::UserId;
+use crate::demo::parser::entitylookup::EntityLookup;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::parser::loadoutanalyser::{Item, ITEM_DEFINITION_INDEX};
+use crate::demo::sendprop::SendPropIdentifier;
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::TryFrom;
+
+const CLIP: SendPropIdentifier = SendPropIdentifier::new("DT_LocalWeaponData", "m_iClip1");
+const RELOAD_MODE: SendPropIdentifier = SendPropIdentifier::new("DT_TFWeaponBase", "m_iReloadMode");
+const AMMO: [SendPropIdentifier; 7] = [
+    SendPropIdentifier::new("m_iAmmo", "000"),
+    SendPropIdentifier::new("m_iAmmo", "001"),
+    SendPropIdentifier::new("m_iAmmo", "002"),
+    SendPropIdentifier::new("m_iAmmo", "003"),
+    SendPropIdentifier::new("m_iAmmo", "004"),
+    SendPropIdentifier::new("m_iAmmo", "005"),
+    SendPropIdentifier::new("m_iAmmo", "006"),
+];
+
+/// Where ammo that a player gained came from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", content = "item", rename_all = "snake_case")]
+pub enum AmmoSource {
+    /// An item picked up by the player, such as `ammopack_small`
+    Pickup(String),
+    /// An ammo pack dropped by a dead player or a destroyed building
+    DroppedPack,
+    /// A resupply cabinet or respawn
+    Resupply,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct AmmoChange {
+    pub tick: DemoTick,
+    pub ammo_type: u8,
+    pub ammo: u16,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ClipChange {
+    pub tick: DemoTick,
+    pub weapon: EntityId,
+    pub item_definition_index: u16,
+    pub clip: u16,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Reload {
+    /// The tick the reload started, if the weapon networks its reload state
+    pub start: Option<DemoTick>,
+    pub tick: DemoTick,
+    pub weapon: EntityId,
+    pub item_definition_index: u16,
+    pub from: u16,
+    pub to: u16,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Shot {
+    pub tick: DemoTick,
+    pub weapon: EntityId,
+    pub item_definition_index: u16,
+    pub count: u16,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AmmoGain {
+    pub tick: DemoTick,
+    pub ammo_type: u8,
+    pub amount: u16,
+    pub source: AmmoSource,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Pickup {
+    pub tick: DemoTick,
+    pub item: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PlayerAmmo {
+    pub ammo: Vec<AmmoChange>,
+    pub clips: Vec<ClipChange>,
+    pub reloads: Vec<Reload>,
+    /// Shots fired, detected from the clip being emptied
+    pub shots: Vec<Shot>,
+    pub gains: Vec<AmmoGain>,
+    pub pickups: Vec<Pickup>,
+}
+
+impl PlayerAmmo {
+    /// The reserve ammo of the given type at the given tick
+    pub fn ammo_at(&self, ammo_type: u8, tick: DemoTick) -> Option<u16> {
+        self.ammo
+            .iter()
+            .rev()
+            .find(|change| change.ammo_type == ammo_type && change.tick <= tick)
+            .map(|change| change.ammo)
+    }
+
+    /// The number of rounds in the clip of the weapon at the given tick
+    pub fn clip_at(&self, weapon: EntityId, tick: DemoTick) -> Option<u16> {
+        self.clips
+            .iter()
+            .rev()
+            .find(|change| change.weapon == weapon && change.tick <= tick)
+            .map(|change| change.clip)
+    }
+
+    fn current_ammo(&self, ammo_type: u8) -> Option<u16> {
+        self.ammo
+            .iter()
+            .rev()
+            .find(|change| change.ammo_type == ammo_type)
+            .map(|change| change.ammo)
+    }
+
+    /// Record a new reserve ammo value, returns the amount of ammo gained
+    fn record_ammo(&mut self, tick: DemoTick, ammo_type: u8, ammo: u16) -> Option<u16> {
+        let previous = self.current_ammo(ammo_type);
+        if previous == Some(ammo) {
+            return None;
+        }
+        self.ammo.push(AmmoChange {
+            tick,
+            ammo_type,
+            ammo,
+        });
+        // the first value seeds the ammo count and isn't a gain
+        previous
+            .filter(|previous| ammo > *previous)
+            .map(|previous| ammo - previous)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AmmoState {
+    pub players: BTreeMap<UserId, PlayerAmmo>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct WeaponAmmo {
+    item: Item,
+    clip: Option<u16>,
+    reload_start: Option<DemoTick>,
+}
+
+/// Tracks the reserve ammo and weapon clips of every player over time
+///
+/// The reserve ammo (`m_iAmmo`) and clips (`m_iClip1`) are only networked to the player that owns them,
+/// so this only tracks the player that recorded a POV demo and tracks nothing in STV demos.
+#[derive(Default, Debug)]
+pub struct AmmoAnalyser {
+    state: AmmoState,
+    lookup: EntityLookup,
+    weapons: HashMap<EntityId, WeaponAmmo>,
+    /// Players that got resupplied in the current tick
+    resupplied: HashSet<UserId>,
+    /// Dropped ammo packs removed in the current tick
+    dropped_packs_taken: bool,
+}
+
+impl MessageHandler for AmmoAnalyser {
+    type Output = AmmoState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(
+            message_type,
+            MessageType::GameEvent | MessageType::PacketEntities
+        )
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        match message {
+            Message::GameEvent(message) => match &message.event {
+                GameEvent::ItemPickup(event) => {
+                    self.state
+                        .players
+                        .entry(UserId::from(event.user_id))
+                        .or_default()
+                        .pickups
+                        .push(Pickup {
+                            tick,
+                            item: event.item.to_string(),
+                        });
+                }
+                GameEvent::PostInventoryApplication(event) => {
+                    self.resupplied.insert(UserId::from(event.user_id));
+                }
+                _ => {}
+            },
+            Message::PacketEntities(message) => {
+                self.dropped_packs_taken = message.entities.iter().any(|entity| {
+                    entity.update_type == UpdateType::Delete
+                        && self.class_name(entity) == "CTFAmmoPack"
+                });
+                for entity in &message.entities {
+                    self.handle_entity(entity, tick, parser_state)
+                }
+                self.resupplied.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        _parser_state: &ParserState,
+    ) {
+        self.lookup.handle_string_entry(table, index, entry);
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        _parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _parser_state: &ParserState,
+    ) {
+        self.lookup.handle_data_tables(server_classes);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for AmmoAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl AmmoAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn class_name(&self, entity: &PacketEntity) -> &str {
+        self.lookup
+            .class_name(entity)
+            .map(|class_name| class_name.as_str())
+            .unwrap_or("")
+    }
+
+    fn handle_entity(&mut self, entity: &PacketEntity, tick: DemoTick, parser_state: &ParserState) {
+        if entity.update_type == UpdateType::Delete {
+            self.weapons.remove(&entity.entity_index);
+            return;
+        }
+
+        if self.class_name(entity) == "CTFPlayer" {
+            self.handle_player_entity(entity, tick, parser_state);
+        } else if self.weapons.contains_key(&entity.entity_index)
+            || entity
+                .get_prop_by_identifier(&ITEM_DEFINITION_INDEX, parser_state)
+                .is_some()
+        {
+            self.handle_weapon_entity(entity, tick, parser_state);
+        }
+    }
+
+    fn handle_player_entity(
+        &mut self,
+        entity: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        let user_id = match self.lookup.user_id(entity.entity_index) {
+            Some(user_id) => user_id,
+            None => return,
+        };
+        let resupplied = self.resupplied.contains(&user_id);
+        let player = self.state.players.entry(user_id).or_default();
+
+        for prop in entity.props(parser_state) {
+            if let Some(ammo_type) = AMMO.iter().position(|id| *id == prop.identifier) {
+                let ammo_type = ammo_type as u8;
+                let ammo = i64::try_from(&prop.value).unwrap_or_default() as u16;
+                let gained = player.record_ammo(tick, ammo_type, ammo);
+                if let Some(amount) = gained.filter(|_| entity.update_type != UpdateType::Enter) {
+                    let source = if resupplied {
+                        AmmoSource::Resupply
+                    } else if let Some(pickup) =
+                        player.pickups.last().filter(|pickup| pickup.tick == tick)
+                    {
+                        AmmoSource::Pickup(pickup.item.clone())
+                    } else if self.dropped_packs_taken {
+                        AmmoSource::DroppedPack
+                    } else {
+                        AmmoSource::Unknown
+                    };
+                    player.gains.push(AmmoGain {
+                        tick,
+                        ammo_type,
+                        amount,
+                        source,
+                    });
+                }
+            }
+        }
+    }
+
+    fn handle_weapon_entity(
+        &mut self,
+        entity: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        let class_name = self.lookup.class_name(entity).cloned().unwrap_or_default();
+        let weapon = self.weapons.entry(entity.entity_index).or_default();
+        if entity.update_type == UpdateType::Enter && weapon.item.class != class_name {
+            *weapon = WeaponAmmo::default();
+        }
+        weapon.item.entity = entity.entity_index;
+        weapon.item.class = class_name;
+        weapon.item.update(entity, parser_state);
+
+        let mut clip = None;
+        for prop in entity.props(parser_state) {
+            match prop.identifier {
+                CLIP => clip = Some(i64::try_from(&prop.value).unwrap_or_default() as u16),
+                RELOAD_MODE => {
+                    let reloading = i64::try_from(&prop.value).unwrap_or_default() != 0;
+                    if !reloading {
+                        weapon.reload_start = None;
+                    } else if weapon.reload_start.is_none() {
+                        weapon.reload_start = Some(tick);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (clip, owner) = match (clip, weapon.item.owner) {
+            (Some(clip), Some(owner)) => (clip, owner),
+            _ => return,
+        };
+        let user_id = match self.lookup.user_id(owner) {
+            Some(user_id) => user_id,
+            None => return,
+        };
+        let player = self.state.players.entry(user_id).or_default();
+        let previous = weapon.clip.replace(clip);
+        let item_definition_index = weapon.item.item_definition_index;
+
+        match previous {
+            Some(previous) if clip < previous => player.shots.push(Shot {
+                tick,
+                weapon: entity.entity_index,
+                item_definition_index,
+                count: previous - clip,
+            }),
+            Some(previous) if clip > previous && !self.resupplied.contains(&user_id) => {
+                player.reloads.push(Reload {
+                    start: weapon.reload_start,
+                    tick,
+                    weapon: entity.entity_index,
+                    item_definition_index,
+                    from: previous,
+                    to: clip,
+                })
+            }
+            Some(previous) if clip == previous => return,
+            _ => {}
+        }
+        player.clips.push(ClipChange {
+            tick,
+            weapon: entity.entity_index,
+            item_definition_index,
+            clip,
+        });
+    }
+}
+
+#[test]
+fn test_ammo_at() {
+    let player = PlayerAmmo {
+        ammo: vec![
+            AmmoChange {
+                tick: DemoTick::from(10),
+                ammo_type: 1,
+                ammo: 20,
+            },
+            AmmoChange {
+                tick: DemoTick::from(15),
+                ammo_type: 2,
+                ammo: 32,
+            },
+            AmmoChange {
+                tick: DemoTick::from(20),
+                ammo_type: 1,
+                ammo: 16,
+            },
+        ],
+        ..PlayerAmmo::default()
+    };
+
+    assert_eq!(None, player.ammo_at(1, DemoTick::from(5)));
+    assert_eq!(Some(20), player.ammo_at(1, DemoTick::from(19)));
+    assert_eq!(Some(16), player.ammo_at(1, DemoTick::from(20)));
+    assert_eq!(Some(32), player.ammo_at(2, DemoTick::from(100)));
+    assert_eq!(Some(16), player.current_ammo(1));
+}
+
+#[test]
+fn test_record_ammo() {
+    let mut player = PlayerAmmo::default();
+    assert_eq!(None, player.record_ammo(DemoTick::from(10), 1, 20));
+    assert_eq!(None, player.record_ammo(DemoTick::from(11), 1, 20));
+    assert_eq!(None, player.record_ammo(DemoTick::from(12), 1, 16));
+    assert_eq!(Some(4), player.record_ammo(DemoTick::from(13), 1, 20));
+    assert_eq!(None, player.record_ammo(DemoTick::from(13), 2, 32));
+    assert_eq!(4, player.ammo.len());
+    assert_eq!(Some(16), player.ammo_at(1, DemoTick::from(12)));
+}
@@ -0,0 +1,256 @@
+//! Inspection of the baselines entity updates are applied on top of
+//!
+//! Entities entering the PVS start from either the static baseline of their class or,
+//! for delta updates, from one of the two instance baselines maintained by the server.
+//! When entities decode wrong the baseline is a likely culprit, see [`ParserState::export_baselines`].
+
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::packet::datatable::{ClassId, ServerClassName};
+use crate::demo::parser::state::BaselineEntity;
+use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::{ParserState, Result};
+use serde::{Deserialize, Serialize};
+
+/// A prop in a baseline together with its name from the data tables
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineProp {
+    pub index: u32,
+    pub identifier: SendPropIdentifier,
+    pub name: Option<String>,
+    pub value: SendPropValue,
+}
+
+impl BaselineProp {
+    fn new(prop: SendProp, state: &ParserState) -> Self {
+        BaselineProp {
+            index: prop.index,
+            identifier: prop.identifier,
+            name: state.prop_full_name(prop.identifier),
+            value: prop.value,
+        }
+    }
+}
+
+/// The decoded static baseline of a server class
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassBaseline {
+    pub class_id: ClassId,
+    pub class_name: ServerClassName,
+    pub props: Vec<BaselineProp>,
+    /// The error if the baseline failed to decode, `props` is empty in that case
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ClassBaseline {
+    pub(crate) fn new(
+        class_id: ClassId,
+        props: Result<Vec<SendProp>>,
+        state: &ParserState,
+    ) -> Self {
+        let (props, error) = match props {
+            Ok(props) => (props, None),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+        ClassBaseline {
+            class_id,
+            class_name: class_name(class_id, state),
+            props: props
+                .into_iter()
+                .map(|prop| BaselineProp::new(prop, state))
+                .collect(),
+            error,
+        }
+    }
+}
+
+/// The baseline for a single entity from one of the instance baselines
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstanceBaseline {
+    pub entity: EntityId,
+    pub class_id: ClassId,
+    pub class_name: ServerClassName,
+    pub serial: u32,
+    pub props: Vec<BaselineProp>,
+}
+
+impl InstanceBaseline {
+    pub(crate) fn new(entity: &BaselineEntity, state: &ParserState) -> Self {
+        InstanceBaseline {
+            entity: entity.entity_id,
+            class_id: entity.server_class,
+            class_name: class_name(entity.server_class, state),
+            serial: entity.serial,
+            props: entity
+                .props
+                .iter()
+                .cloned()
+                .map(|prop| BaselineProp::new(prop, state))
+                .collect(),
+        }
+    }
+}
+
+/// A prop that differs between the two instance baselines
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselinePropDiff {
+    pub identifier: SendPropIdentifier,
+    pub name: Option<String>,
+    pub first: Option<SendPropValue>,
+    pub second: Option<SendPropValue>,
+}
+
+/// The differences for an entity between instance baseline 0 and 1
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineDiff {
+    pub entity: EntityId,
+    /// The class in each baseline, `None` if the entity isn't in that baseline
+    pub first_class: Option<ServerClassName>,
+    pub second_class: Option<ServerClassName>,
+    pub props: Vec<BaselinePropDiff>,
+}
+
+impl BaselineDiff {
+    pub(crate) fn new(
+        entity: EntityId,
+        first: Option<&BaselineEntity>,
+        second: Option<&BaselineEntity>,
+        state: &ParserState,
+    ) -> Option<Self> {
+        let first_props = first
+            .map(|entity| entity.props.as_slice())
+            .unwrap_or_default();
+        let second_props = second
+            .map(|entity| entity.props.as_slice())
+            .unwrap_or_default();
+        let find = |props: &[SendProp], identifier| {
+            props
+                .iter()
+                .find(|prop| prop.identifier == identifier)
+                .map(|prop| prop.value.clone())
+        };
+
+        let mut identifiers: Vec<SendPropIdentifier> = first_props
+            .iter()
+            .chain(second_props)
+            .map(|prop| prop.identifier)
+            .collect();
+        identifiers.sort();
+        identifiers.dedup();
+
+        let props: Vec<BaselinePropDiff> = identifiers
+            .into_iter()
+            .map(|identifier| BaselinePropDiff {
+                identifier,
+                name: state.prop_full_name(identifier),
+                first: find(first_props, identifier),
+                second: find(second_props, identifier),
+            })
+            .filter(|diff| diff.first != diff.second)
+            .collect();
+
+        let first_class = first.map(|entity| entity.server_class);
+        let second_class = second.map(|entity| entity.server_class);
+        if props.is_empty() && first_class == second_class {
+            return None;
+        }
+
+        Some(BaselineDiff {
+            entity,
+            first_class: first_class.map(|class| class_name(class, state)),
+            second_class: second_class.map(|class| class_name(class, state)),
+            props,
+        })
+    }
+}
+
+/// All baselines known at a point in the demo
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineExport {
+    pub static_baselines: Vec<ClassBaseline>,
+    pub instance_baselines: [Vec<InstanceBaseline>; 2],
+}
+
+pub(crate) fn class_name(class: ClassId, state: &ParserState) -> ServerClassName {
+    state
+        .server_classes
+        .get(usize::from(class))
+        .map(|class| class.name.clone())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_baseline_diff() {
+    let prop = |name, value| SendProp {
+        index: 0,
+        identifier: SendPropIdentifier::new("DT_BaseEntity", name),
+        value: SendPropValue::Integer(value),
+    };
+    let entity = |class: u16, props| BaselineEntity {
+        entity_id: EntityId::from(1u32),
+        server_class: ClassId::from(class),
+        props,
+        serial: 0,
+    };
+    let state = ParserState::new(24, |_| false, false);
+
+    let first = entity(1, vec![prop("m_iTeamNum", 2), prop("m_nModelIndex", 10)]);
+    let second = entity(1, vec![prop("m_iTeamNum", 3), prop("m_nModelIndex", 10)]);
+    let diff = BaselineDiff::new(EntityId::from(1u32), Some(&first), Some(&second), &state)
+        .expect("baselines differ");
+    assert_eq!(
+        vec![BaselinePropDiff {
+            identifier: SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+            name: Some("DT_BaseEntity.m_iTeamNum".into()),
+            first: Some(SendPropValue::Integer(2)),
+            second: Some(SendPropValue::Integer(3)),
+        }],
+        diff.props
+    );
+
+    assert_eq!(
+        None,
+        BaselineDiff::new(EntityId::from(1u32), Some(&first), Some(&first), &state)
+    );
+    assert_eq!(
+        None,
+        BaselineDiff::new(EntityId::from(1u32), None, None, &state)
+    );
+
+    let diff = BaselineDiff::new(EntityId::from(1u32), Some(&first), None, &state).unwrap();
+    assert_eq!(2, diff.props.len());
+    assert!(diff.first_class.is_some());
+    assert_eq!(None, diff.second_class);
+
+    let baseline = InstanceBaseline::new(&first, &state);
+    assert_eq!(
+        Some("DT_BaseEntity.m_iTeamNum".into()),
+        baseline.props[0].name
+    );
+    assert_eq!(SendPropValue::Integer(2), baseline.props[0].value);
+}
+
+#[test]
+fn test_class_baseline_error() {
+    use crate::demo::parser::state::StaticBaseline;
+    use bitbuffer::{BitReadBuffer, BitReadStream, LittleEndian};
+
+    let mut state = ParserState::new(24, |_| false, false);
+    let class_id = ClassId::from(3);
+    state.static_baselines.insert(
+        class_id,
+        StaticBaseline {
+            class_id,
+            raw: BitReadStream::new(BitReadBuffer::new_owned(vec![0; 4], LittleEndian)),
+        },
+    );
+
+    // the send table for the class is missing, this should be reported in the baseline
+    // instead of failing the export
+    let export = state.export_baselines();
+    assert_eq!(1, export.static_baselines.len());
+    let baseline = &export.static_baselines[0];
+    assert_eq!(class_id, baseline.class_id);
+    assert!(baseline.props.is_empty());
+    assert!(baseline.error.is_some());
+}
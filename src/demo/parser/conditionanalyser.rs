@@ -0,0 +1,193 @@
+use crate::demo::data::{DemoTick, PlayerCondition, PlayerConditions};
+use crate::demo::message::packetentities::{EntityId, PacketEntity, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::parser::entitylookup::EntityLookup;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// A player entering or leaving a condition
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConditionChange {
+    pub tick: DemoTick,
+    pub entered: bool,
+}
+
+/// The condition changes of all players, indexed by player entity and condition
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ConditionHistory {
+    pub players: BTreeMap<EntityId, BTreeMap<PlayerCondition, Vec<ConditionChange>>>,
+}
+
+impl ConditionHistory {
+    /// All changes of a condition for a player, ordered by tick
+    pub fn changes(&self, entity: EntityId, condition: PlayerCondition) -> &[ConditionChange] {
+        self.players
+            .get(&entity)
+            .and_then(|conditions| conditions.get(&condition))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Whether the player was in the condition at the given tick
+    pub fn had_condition(
+        &self,
+        entity: EntityId,
+        condition: PlayerCondition,
+        tick: DemoTick,
+    ) -> bool {
+        let changes = self.changes(entity, condition);
+        let index = changes.partition_point(|change| change.tick <= tick);
+        index
+            .checked_sub(1)
+            .map(|index| changes[index].entered)
+            .unwrap_or_default()
+    }
+
+    fn record(&mut self, entity: EntityId, condition: PlayerCondition, change: ConditionChange) {
+        self.players
+            .entry(entity)
+            .or_default()
+            .entry(condition)
+            .or_default()
+            .push(change);
+    }
+}
+
+/// Records when players enter and leave conditions
+#[derive(Default, Debug)]
+pub struct ConditionAnalyser {
+    history: ConditionHistory,
+    lookup: EntityLookup,
+    conditions: HashMap<EntityId, PlayerConditions>,
+}
+
+impl MessageHandler for ConditionAnalyser {
+    type Output = ConditionHistory;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        if let Message::PacketEntities(message) = message {
+            for entity in &message.entities {
+                if self.lookup.class_name(entity).map(|class| class.as_str()) == Some("CTFPlayer") {
+                    self.handle_player(entity, tick, parser_state);
+                }
+            }
+        }
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        _parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _parser_state: &ParserState,
+    ) {
+        self.lookup.handle_data_tables(server_classes);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.history
+    }
+}
+
+impl BorrowMessageHandler for ConditionAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.history
+    }
+}
+
+impl ConditionAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn handle_player(&mut self, entity: &PacketEntity, tick: DemoTick, parser_state: &ParserState) {
+        let old = self
+            .conditions
+            .get(&entity.entity_index)
+            .copied()
+            .unwrap_or_default();
+        let new = if entity.update_type == UpdateType::Delete {
+            self.conditions.remove(&entity.entity_index);
+            PlayerConditions::default()
+        } else {
+            let mut conditions = old;
+            for prop in entity.props(parser_state) {
+                conditions.update_from_prop(&prop);
+            }
+            self.conditions.insert(entity.entity_index, conditions);
+            conditions
+        };
+        self.record_changes(entity.entity_index, &old, &new, tick);
+    }
+
+    fn record_changes(
+        &mut self,
+        entity: EntityId,
+        old: &PlayerConditions,
+        new: &PlayerConditions,
+        tick: DemoTick,
+    ) {
+        for condition in new.difference(old) {
+            self.history.record(
+                entity,
+                condition,
+                ConditionChange {
+                    tick,
+                    entered: true,
+                },
+            );
+        }
+        for condition in old.difference(new) {
+            self.history.record(
+                entity,
+                condition,
+                ConditionChange {
+                    tick,
+                    entered: false,
+                },
+            );
+        }
+    }
+}
+
+#[test]
+fn test_condition_history() {
+    let entity = EntityId::from(1u32);
+    let ubered: PlayerConditions = [PlayerCondition::Invulnerable].into_iter().collect();
+    let burning: PlayerConditions = [PlayerCondition::Burning].into_iter().collect();
+
+    let mut analyser = ConditionAnalyser::new();
+    analyser.record_changes(
+        entity,
+        &PlayerConditions::default(),
+        &ubered,
+        DemoTick::from(10),
+    );
+    analyser.record_changes(entity, &ubered, &burning, DemoTick::from(20));
+    analyser.record_changes(
+        entity,
+        &burning,
+        &PlayerConditions::default(),
+        DemoTick::from(30),
+    );
+
+    let history = analyser.history;
+    assert!(!history.had_condition(entity, PlayerCondition::Invulnerable, DemoTick::from(9)));
+    assert!(history.had_condition(entity, PlayerCondition::Invulnerable, DemoTick::from(10)));
+    assert!(history.had_condition(entity, PlayerCondition::Invulnerable, DemoTick::from(19)));
+    assert!(!history.had_condition(entity, PlayerCondition::Invulnerable, DemoTick::from(20)));
+    assert!(history.had_condition(entity, PlayerCondition::Burning, DemoTick::from(25)));
+    assert!(!history.had_condition(entity, PlayerCondition::Burning, DemoTick::from(100)));
+    assert!(!history.had_condition(
+        EntityId::from(2u32),
+        PlayerCondition::Burning,
+        DemoTick::from(25)
+    ));
+    assert_eq!(2, history.changes(entity, PlayerCondition::Burning).len());
+}
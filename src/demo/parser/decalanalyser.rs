@@ -0,0 +1,390 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::bspdecal::BSPDecalMessage;
+use crate::demo::message::packetentities::{EntityId, PacketEntity};
+use crate::demo::message::tempentities::TempEntity;
+use crate::demo::message::{Message, MessageType};
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::parser::lifecycle::EntityInfo;
+use crate::demo::sendprop::SendPropIdentifier;
+use crate::demo::vector::Vector;
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const MODEL_INDEX: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DecalSource {
+    /// Decals placed in the map, sent with the `BspDecal` message
+    Map,
+    /// Decals created by a temp entity, like the blood or scorch marks created by the server
+    TempEntity,
+    /// A spray logo placed by a player
+    Spray { player: EntityId },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecalEvent {
+    pub tick: DemoTick,
+    pub source: DecalSource,
+    pub position: Vector,
+    /// Index into the `decalprecache` table, sprays use the custom logo of the player instead
+    pub texture_index: Option<u16>,
+    pub texture: Option<String>,
+    /// The entity the decal is applied to, the world is entity 0
+    pub entity: EntityId,
+    pub model_index: Option<u16>,
+    pub model: Option<String>,
+    pub low_priority: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DecalState {
+    pub decals: Vec<DecalEvent>,
+}
+
+impl DecalState {
+    /// All sprays placed during the demo
+    pub fn sprays(&self) -> impl Iterator<Item = (EntityId, &DecalEvent)> {
+        self.decals.iter().filter_map(|decal| match decal.source {
+            DecalSource::Spray { player } => Some((player, decal)),
+            _ => None,
+        })
+    }
+
+    /// All decals with a texture name containing the pattern
+    pub fn matching<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = &'a DecalEvent> {
+        self.decals.iter().filter(move |decal| {
+            decal
+                .texture
+                .as_deref()
+                .map(|texture| texture.contains(pattern))
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Collect the decals placed during the demo
+#[derive(Default, Debug)]
+pub struct DecalAnalyser {
+    state: DecalState,
+    /// Model index of every entity, to resolve the model a temp entity decal is placed on
+    models: HashMap<EntityId, u16>,
+}
+
+impl MessageHandler for DecalAnalyser {
+    type Output = DecalState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(
+            message_type,
+            MessageType::BspDecal | MessageType::TempEntities | MessageType::PacketEntities
+        )
+    }
+
+    fn requires_string_tables() -> bool {
+        true
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        match message {
+            Message::BspDecal(message) => self.handle_bsp_decal(message, tick, parser_state),
+            Message::PacketEntities(message) => {
+                for entity in &message.entities {
+                    self.handle_entity(entity, parser_state);
+                }
+            }
+            Message::TempEntities(message) => {
+                for event in &message.events {
+                    match event.typed(parser_state) {
+                        TempEntity::Decal(decal) => self.state.decals.push(DecalEvent {
+                            tick,
+                            source: DecalSource::TempEntity,
+                            position: decal.origin,
+                            texture_index: Some(decal.index),
+                            texture: decal_name(parser_state, decal.index),
+                            entity: decal.entity,
+                            model_index: self.model_index(decal.entity),
+                            model: self.model_name(decal.entity, parser_state),
+                            low_priority: false,
+                        }),
+                        TempEntity::PlayerDecal(decal) => self.state.decals.push(DecalEvent {
+                            tick,
+                            source: DecalSource::Spray {
+                                player: decal.player,
+                            },
+                            position: decal.origin,
+                            texture_index: None,
+                            texture: None,
+                            entity: decal.entity,
+                            model_index: self.model_index(decal.entity),
+                            model: self.model_name(decal.entity, parser_state),
+                            low_priority: false,
+                        }),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_entity_deleted(
+        &mut self,
+        entity: &EntityInfo,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        self.models.remove(&entity.id);
+    }
+
+    fn on_entity_replaced(
+        &mut self,
+        old: &EntityInfo,
+        _new: &EntityInfo,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        self.models.remove(&old.id);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for DecalAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl DecalAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn handle_bsp_decal(
+        &mut self,
+        message: &BSPDecalMessage,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        // decals without an entity are placed on the world
+        let model_index = (message.ent_index != 0).then_some(message.model_index);
+        self.state.decals.push(DecalEvent {
+            tick,
+            source: DecalSource::Map,
+            position: message.position,
+            texture_index: Some(message.texture_index),
+            texture: decal_name(parser_state, message.texture_index),
+            entity: EntityId::from(message.ent_index as u32),
+            model_index,
+            model: model_index.and_then(|index| model_name(parser_state, index)),
+            low_priority: message.low_priority,
+        });
+    }
+
+    fn handle_entity(&mut self, entity: &PacketEntity, parser_state: &ParserState) {
+        if let Some(prop) = entity.get_prop_by_identifier(&MODEL_INDEX, parser_state) {
+            if let Ok(model_index) = i64::try_from(&prop.value) {
+                self.models.insert(entity.entity_index, model_index as u16);
+            }
+        }
+    }
+
+    /// The model of the entity a decal is placed on, decals on the world don't have a model
+    fn model_index(&self, entity: EntityId) -> Option<u16> {
+        if entity == EntityId::from(0u32) {
+            return None;
+        }
+        self.models.get(&entity).copied()
+    }
+
+    fn model_name(&self, entity: EntityId, parser_state: &ParserState) -> Option<String> {
+        self.model_index(entity)
+            .and_then(|index| model_name(parser_state, index))
+    }
+}
+
+fn decal_name(parser_state: &ParserState, index: u16) -> Option<String> {
+    parser_state
+        .string_table_contents()?
+        .decal(index as usize)
+        .map(String::from)
+}
+
+fn model_name(parser_state: &ParserState, index: u16) -> Option<String> {
+    parser_state
+        .string_table_contents()?
+        .model(index as usize)
+        .map(String::from)
+}
+
+#[test]
+fn test_bsp_decal() {
+    use crate::demo::packet::stringtable::StringTableEntry;
+
+    let mut state = ParserState::new(24, |_| true, false);
+    state.enable_string_tables();
+    let decal = |name: &str| StringTableEntry {
+        text: Some(name.to_string().into()),
+        extra_data: None,
+    };
+
+    let mut analyser = DecalAnalyser::new();
+    state.handle_string_entry("decalprecache", 3, &decal("decals/scorch1"), 0.into());
+    analyser.handle_message(
+        &Message::BspDecal(BSPDecalMessage {
+            position: Vector {
+                x: 10.0,
+                y: -20.0,
+                z: 5.0,
+            },
+            texture_index: 3,
+            ent_index: 0,
+            model_index: 0,
+            low_priority: true,
+        }),
+        12.into(),
+        &state,
+    );
+    let output = analyser.into_output(&state);
+    assert_eq!(output.decals.len(), 1);
+    assert_eq!(output.decals[0].texture.as_deref(), Some("decals/scorch1"));
+    assert_eq!(output.decals[0].model_index, None);
+    assert_eq!(output.matching("scorch").count(), 1);
+}
+
+#[test]
+fn test_temp_entity_decals() {
+    use crate::demo::message::packetentities::{PacketEntitiesMessage, UpdateType};
+    use crate::demo::message::tempentities::{EventInfo, TempEntitiesMessage};
+    use crate::demo::packet::datatable::{ClassId, SendTableName, ServerClass, ServerClassName};
+    use crate::demo::packet::stringtable::StringTableEntry;
+    use crate::demo::sendprop::{SendProp, SendPropValue};
+
+    let mut state = ParserState::new(24, |_| true, false);
+    state.enable_string_tables();
+    for (id, (class, table)) in [
+        ("CTEDecal", "DT_TEDecal"),
+        ("CTEBSPDecal", "DT_TEBSPDecal"),
+        ("CTEWorldDecal", "DT_TEWorldDecal"),
+        ("CTEPlayerDecal", "DT_TEPlayerDecal"),
+        ("CBaseDoor", "DT_BaseDoor"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        state.server_classes.push(ServerClass {
+            id: ClassId::from(id as u16),
+            name: ServerClassName::from(class),
+            data_table: SendTableName::from(table),
+        });
+    }
+    let entry = |name: &str| StringTableEntry {
+        text: Some(name.to_string().into()),
+        extra_data: None,
+    };
+    let prop = |table: &str, name: &str, value: SendPropValue| SendProp {
+        index: 0,
+        identifier: SendPropIdentifier::new(table, name),
+        value,
+    };
+    let origin = SendPropValue::Vector(Vector {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    });
+    let event = |class: u16, props: Vec<SendProp>| EventInfo {
+        class_id: ClassId::from(class),
+        fire_delay: 0.0,
+        reliable: false,
+        props,
+    };
+    let decal = |class: u16, table: &str, entity: i64| {
+        event(
+            class,
+            vec![
+                prop(table, "m_nEntity", SendPropValue::Integer(entity)),
+                prop(table, "m_vecOrigin", origin.clone()),
+                prop(table, "m_nIndex", SendPropValue::Integer(3)),
+            ],
+        )
+    };
+
+    let mut analyser = DecalAnalyser::new();
+    state.handle_string_entry("decalprecache", 3, &entry("decals/scorch1"), 0.into());
+    state.handle_string_entry(
+        "modelprecache",
+        7,
+        &entry("models/props/door.mdl"),
+        0.into(),
+    );
+    analyser.handle_message(
+        &Message::PacketEntities(PacketEntitiesMessage {
+            entities: vec![PacketEntity {
+                server_class: ClassId::from(4),
+                entity_index: EntityId::from(50u32),
+                props: vec![prop(
+                    "DT_BaseEntity",
+                    "m_nModelIndex",
+                    SendPropValue::Integer(7),
+                )],
+                in_pvs: true,
+                update_type: UpdateType::Preserve,
+                serial_number: 1,
+                delay: None,
+                delta: None,
+                baseline_index: 0,
+            }],
+            removed_entities: vec![],
+            max_entries: 0,
+            delta: None,
+            base_line: 0,
+            updated_base_line: false,
+        }),
+        10.into(),
+        &state,
+    );
+    analyser.handle_message(
+        &Message::TempEntities(TempEntitiesMessage {
+            events: vec![
+                decal(0, "DT_TEDecal", 50),
+                decal(1, "DT_TEBSPDecal", 0),
+                decal(2, "DT_TEWorldDecal", 0),
+                event(
+                    3,
+                    vec![
+                        prop("DT_TEPlayerDecal", "m_nPlayer", SendPropValue::Integer(4)),
+                        prop("DT_TEPlayerDecal", "m_nEntity", SendPropValue::Integer(50)),
+                        prop("DT_TEPlayerDecal", "m_vecOrigin", origin.clone()),
+                    ],
+                ),
+            ],
+        }),
+        12.into(),
+        &state,
+    );
+
+    let output = analyser.into_output(&state);
+    assert_eq!(output.decals.len(), 4);
+    for decal in &output.decals[0..3] {
+        assert_eq!(decal.source, DecalSource::TempEntity);
+        assert_eq!(decal.texture.as_deref(), Some("decals/scorch1"));
+        assert_eq!(decal.position.z, 3.0);
+    }
+    assert_eq!(output.decals[0].model_index, Some(7));
+    assert_eq!(
+        output.decals[0].model.as_deref(),
+        Some("models/props/door.mdl")
+    );
+    assert_eq!(output.decals[1].model, None);
+
+    let sprays: Vec<_> = output.sprays().collect();
+    assert_eq!(sprays.len(), 1);
+    assert_eq!(sprays[0].0, EntityId::from(4u32));
+    assert_eq!(sprays[0].1.texture, None);
+    assert_eq!(sprays[0].1.model.as_deref(), Some("models/props/door.mdl"));
+}
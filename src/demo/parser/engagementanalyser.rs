@@ -0,0 +1,517 @@
+use crate::demo::data::DemoTick;
+use crate::demo::gameevent_gen::PlayerDeathEvent;
+use crate::demo::gamevent::GameEvent;
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::message::Message;
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::packet::message::MessagePacketMeta;
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::parser::gamestateanalyser::{GameState, GameStateAnalyser, PlayerState};
+pub use crate::demo::parser::gamestateanalyser::{Team, UserId};
+use crate::demo::parser::handler::BorrowMessageHandler;
+use crate::demo::parser::lifecycle::EntityInfo;
+use crate::demo::parser::MessageHandler;
+use crate::demo::vector::Vector;
+use crate::{MessageType, ParserState};
+use serde::{Deserialize, Serialize};
+
+/// Settings used to group kills into fights and detect trades
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct EngagementSettings {
+    /// Maximum time in seconds between two kills of the same fight
+    pub fight_window: f32,
+    /// Maximum distance in hammer units between a kill and the other kills of the fight
+    pub fight_distance: f32,
+    /// Maximum time in seconds for a teammate to avenge a death
+    pub trade_window: f32,
+}
+
+impl Default for EngagementSettings {
+    fn default() -> Self {
+        EngagementSettings {
+            fight_window: 10.0,
+            fight_distance: 1500.0,
+            trade_window: 5.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EngagementKill {
+    pub tick: DemoTick,
+    pub attacker: Option<UserId>,
+    pub assister: Option<UserId>,
+    pub victim: UserId,
+    pub attacker_team: Team,
+    pub victim_team: Team,
+    pub attacker_position: Option<Vector>,
+    pub victim_position: Vector,
+    pub weapon: String,
+}
+
+impl EngagementKill {
+    /// Whether the kill was made by a player on the opposing team
+    pub fn is_enemy_kill(&self) -> bool {
+        self.attacker.is_some()
+            && self.attacker != Some(self.victim)
+            && self.attacker_team.is_player()
+            && self.attacker_team != self.victim_team
+    }
+
+    fn position(&self) -> Vector {
+        self.victim_position
+    }
+}
+
+/// A death that was avenged by a teammate of the victim
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Trade {
+    /// Index of the original kill in the kills of the round
+    pub kill: usize,
+    /// Index of the kill that avenged the original kill
+    pub trade: usize,
+    /// Number of ticks between the two kills
+    pub delay: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ManAdvantageSwing {
+    pub tick: DemoTick,
+    /// The team with more players alive after the kill, `None` if both teams are even
+    pub advantage: Option<Team>,
+    pub red_alive: u8,
+    pub blue_alive: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Fight {
+    pub start_tick: DemoTick,
+    pub end_tick: DemoTick,
+    /// Indexes of the kills in the kills of the round
+    pub kills: Vec<usize>,
+    /// The first kill by an enemy player in this fight
+    pub first_pick: Option<usize>,
+    /// The team with the most kills in the fight, `None` if both teams got an equal number of kills
+    pub winner: Option<Team>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RoundEngagements {
+    pub start_tick: DemoTick,
+    pub end_tick: Option<DemoTick>,
+    pub winner: Option<Team>,
+    pub kills: Vec<EngagementKill>,
+    pub fights: Vec<Fight>,
+    pub trades: Vec<Trade>,
+    /// The first kill by an enemy player in this round
+    pub first_pick: Option<usize>,
+    pub advantage_swings: Vec<ManAdvantageSwing>,
+}
+
+impl RoundEngagements {
+    fn new(start_tick: DemoTick) -> Self {
+        RoundEngagements {
+            start_tick,
+            ..RoundEngagements::default()
+        }
+    }
+
+    /// Add a kill to the round, updating the fights, trades and man-advantage swings
+    ///
+    /// The window settings are in ticks here
+    pub fn push_kill(
+        &mut self,
+        kill: EngagementKill,
+        alive: (u8, u8),
+        fight_window: u32,
+        fight_distance: f32,
+        trade_window: u32,
+    ) {
+        let index = self.kills.len();
+        let tick = kill.tick;
+        let enemy_kill = kill.is_enemy_kill();
+
+        if enemy_kill {
+            if self.first_pick.is_none() {
+                self.first_pick = Some(index);
+            }
+            self.find_trade(&kill, index, trade_window);
+        }
+
+        self.push_fight_kill(&kill, index, fight_window, fight_distance);
+        self.kills.push(kill);
+        self.update_fight_winner();
+
+        let (red_alive, blue_alive) = alive;
+        let advantage = match red_alive.cmp(&blue_alive) {
+            std::cmp::Ordering::Greater => Some(Team::Red),
+            std::cmp::Ordering::Less => Some(Team::Blue),
+            std::cmp::Ordering::Equal => None,
+        };
+        let previous_advantage = self.advantage_swings.last().map(|swing| swing.advantage);
+        if previous_advantage != Some(advantage) {
+            self.advantage_swings.push(ManAdvantageSwing {
+                tick,
+                advantage,
+                red_alive,
+                blue_alive,
+            });
+        }
+    }
+
+    fn find_trade(&mut self, kill: &EngagementKill, index: usize, trade_window: u32) {
+        let traded = self
+            .kills
+            .iter()
+            .enumerate()
+            .rev()
+            .take_while(|(_, original)| kill.tick - original.tick <= trade_window)
+            .find(|(original_index, original)| {
+                original.is_enemy_kill()
+                    && original.attacker == Some(kill.victim)
+                    && original.victim_team == kill.attacker_team
+                    && Some(original.victim) != kill.attacker
+                    && !self
+                        .trades
+                        .iter()
+                        .any(|trade| trade.kill == *original_index)
+            })
+            .map(|(original_index, original)| (original_index, original.tick));
+
+        if let Some((original_index, original_tick)) = traded {
+            self.trades.push(Trade {
+                kill: original_index,
+                trade: index,
+                delay: u32::from(kill.tick - original_tick),
+            });
+        }
+    }
+
+    fn push_fight_kill(
+        &mut self,
+        kill: &EngagementKill,
+        index: usize,
+        fight_window: u32,
+        fight_distance: f32,
+    ) {
+        let kills = &self.kills;
+        let fight = self.fights.iter_mut().rev().find(|fight| {
+            kill.tick - fight.end_tick <= fight_window
+                && fight.kills.iter().any(|fight_kill| {
+                    distance(kills[*fight_kill].position(), kill.position()) <= fight_distance
+                })
+        });
+
+        match fight {
+            Some(fight) => {
+                fight.end_tick = kill.tick;
+                fight.kills.push(index);
+                if fight.first_pick.is_none() && kill.is_enemy_kill() {
+                    fight.first_pick = Some(index);
+                }
+            }
+            None => self.fights.push(Fight {
+                start_tick: kill.tick,
+                end_tick: kill.tick,
+                kills: vec![index],
+                first_pick: kill.is_enemy_kill().then_some(index),
+                winner: None,
+            }),
+        }
+    }
+
+    fn update_fight_winner(&mut self) {
+        let kills = &self.kills;
+        for fight in self.fights.iter_mut() {
+            let (red, blue) = fight
+                .kills
+                .iter()
+                .map(|index| &kills[*index])
+                .filter(|kill| kill.is_enemy_kill())
+                .fold((0, 0), |(red, blue), kill| match kill.attacker_team {
+                    Team::Red => (red + 1, blue),
+                    Team::Blue => (red, blue + 1),
+                    _ => (red, blue),
+                });
+            fight.winner = match red.cmp(&blue) {
+                std::cmp::Ordering::Greater => Some(Team::Red),
+                std::cmp::Ordering::Less => Some(Team::Blue),
+                std::cmp::Ordering::Equal => None,
+            };
+        }
+    }
+}
+
+fn distance(a: Vector, b: Vector) -> f32 {
+    let diff = a - b;
+    (diff.x * diff.x + diff.y * diff.y + diff.z * diff.z).sqrt()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct EngagementState {
+    pub rounds: Vec<RoundEngagements>,
+}
+
+/// Analyser that clusters kills into fights and detects trades, first picks and man-advantage swings
+///
+/// Player positions and teams are taken from the [`GameStateAnalyser`] at the tick of the kill.
+#[derive(Default, Debug)]
+pub struct EngagementAnalyser {
+    game_state: GameStateAnalyser,
+    settings: EngagementSettings,
+    state: EngagementState,
+    tick: DemoTick,
+}
+
+impl MessageHandler for EngagementAnalyser {
+    type Output = EngagementState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        GameStateAnalyser::does_handle(message_type)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        self.game_state.handle_message(message, tick, parser_state);
+
+        if let Message::GameEvent(message) = message {
+            match &message.event {
+                GameEvent::PlayerDeath(death) => self.handle_death(death, parser_state),
+                GameEvent::TeamPlayRoundStart(_) => {
+                    let tick = self.tick;
+                    match self.state.rounds.last_mut() {
+                        // no kills happened yet, restart the pending round
+                        Some(round) if round.end_tick.is_none() && round.kills.is_empty() => {
+                            round.start_tick = tick
+                        }
+                        _ => self.state.rounds.push(RoundEngagements::new(tick)),
+                    }
+                }
+                GameEvent::TeamPlayRoundWin(event) => {
+                    let tick = self.tick;
+                    let round = self.current_round();
+                    round.end_tick = Some(tick);
+                    round.winner = Some(Team::new(event.team)).filter(Team::is_player);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .handle_string_entry(table, index, entry, parser_state);
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .handle_data_tables(parse_tables, server_classes, parser_state);
+    }
+
+    fn handle_packet_meta(
+        &mut self,
+        tick: DemoTick,
+        meta: &MessagePacketMeta,
+        parser_state: &ParserState,
+    ) {
+        self.tick = tick;
+        self.game_state.handle_packet_meta(tick, meta, parser_state);
+    }
+
+    fn on_entity_created(
+        &mut self,
+        entity: &EntityInfo,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .on_entity_created(entity, tick, parser_state);
+    }
+
+    fn on_entity_pvs_change(
+        &mut self,
+        entity: &EntityInfo,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .on_entity_pvs_change(entity, tick, parser_state);
+    }
+
+    fn on_entity_deleted(
+        &mut self,
+        entity: &EntityInfo,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .on_entity_deleted(entity, tick, parser_state);
+    }
+
+    fn on_entity_replaced(
+        &mut self,
+        old: &EntityInfo,
+        new: &EntityInfo,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .on_entity_replaced(old, new, tick, parser_state);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for EngagementAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl EngagementAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_settings(settings: EngagementSettings) -> Self {
+        EngagementAnalyser {
+            settings,
+            ..Self::default()
+        }
+    }
+
+    /// The game state as tracked by the inner [`GameStateAnalyser`]
+    pub fn game_state(&self) -> &GameState {
+        &self.game_state.state
+    }
+
+    fn current_round(&mut self) -> &mut RoundEngagements {
+        if !matches!(self.state.rounds.last(), Some(round) if round.end_tick.is_none()) {
+            self.state.rounds.push(RoundEngagements::new(self.tick));
+        }
+        self.state.rounds.last_mut().unwrap()
+    }
+
+    fn handle_death(&mut self, death: &PlayerDeathEvent, parser_state: &ParserState) {
+        let game_state = &self.game_state.state;
+        let victim = UserId::from(death.user_id);
+        let attacker = Some(UserId::from(death.attacker)).filter(|id| *id != 0u16);
+        let assister = Some(UserId::from(death.assister))
+            .filter(|id| *id != 0u16 && u16::from(*id) < 16 * 1024);
+
+        let victim_player = game_state.get_player_by_user_id(victim);
+        let attacker_player = attacker.and_then(|id| game_state.get_player_by_user_id(id));
+
+        let kill = EngagementKill {
+            tick: self.tick,
+            attacker,
+            assister,
+            victim,
+            attacker_team: attacker_player.map(|p| p.team).unwrap_or_default(),
+            victim_team: victim_player.map(|p| p.team).unwrap_or_default(),
+            attacker_position: attacker_player.map(|p| p.position),
+            victim_position: victim_player.map(|p| p.position).unwrap_or_default(),
+            weapon: death.weapon.to_string(),
+        };
+
+        let victim_entity = victim_player.map(|p| p.entity);
+        let alive = alive_counts(game_state, victim_entity);
+
+        let interval = parser_state.demo_meta.interval_per_tick;
+        let to_ticks = |seconds: f32| {
+            if interval > 0.0 {
+                (seconds / interval) as u32
+            } else {
+                (seconds * 66.0) as u32
+            }
+        };
+        let fight_window = to_ticks(self.settings.fight_window);
+        let trade_window = to_ticks(self.settings.trade_window);
+        let fight_distance = self.settings.fight_distance;
+
+        self.current_round()
+            .push_kill(kill, alive, fight_window, fight_distance, trade_window);
+    }
+}
+
+/// Count the alive players on red and blue, the victim of a kill counts as dead
+fn alive_counts(state: &GameState, victim: Option<EntityId>) -> (u8, u8) {
+    state
+        .players
+        .iter()
+        .filter(|player| player.state == PlayerState::Alive)
+        .filter(|player| Some(player.entity) != victim)
+        .fold((0, 0), |(red, blue), player| match player.team {
+            Team::Red => (red + 1, blue),
+            Team::Blue => (red, blue + 1),
+            _ => (red, blue),
+        })
+}
+
+#[test]
+fn test_engagement_trades_and_fights() {
+    fn kill(tick: u32, attacker: u16, victim: u16, attacker_team: Team, x: f32) -> EngagementKill {
+        EngagementKill {
+            tick: tick.into(),
+            attacker: Some(attacker.into()),
+            assister: None,
+            victim: victim.into(),
+            attacker_team,
+            victim_team: if attacker_team == Team::Red {
+                Team::Blue
+            } else {
+                Team::Red
+            },
+            attacker_position: None,
+            victim_position: Vector { x, y: 0.0, z: 0.0 },
+            weapon: String::new(),
+        }
+    }
+
+    let mut round = RoundEngagements::new(0.into());
+    // red 1 kills blue 2, blue 3 trades by killing red 1
+    round.push_kill(kill(100, 1, 2, Team::Red, 0.0), (6, 5), 660, 1500.0, 330);
+    round.push_kill(kill(200, 3, 1, Team::Blue, 100.0), (5, 5), 660, 1500.0, 330);
+    // red 4 kills blue 5, too late and too far away to be part of the same fight
+    round.push_kill(
+        kill(2000, 4, 5, Team::Red, 5000.0),
+        (5, 4),
+        660,
+        1500.0,
+        330,
+    );
+
+    assert_eq!(Some(0), round.first_pick);
+    assert_eq!(
+        vec![Trade {
+            kill: 0,
+            trade: 1,
+            delay: 100
+        }],
+        round.trades
+    );
+    assert_eq!(2, round.fights.len());
+    assert_eq!(vec![0, 1], round.fights[0].kills);
+    assert_eq!(None, round.fights[0].winner);
+    assert_eq!(Some(Team::Red), round.fights[1].winner);
+    assert_eq!(
+        vec![Some(Team::Red), None, Some(Team::Red)],
+        round
+            .advantage_swings
+            .iter()
+            .map(|swing| swing.advantage)
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,303 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{EntityId, PacketEntity, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::ServerClassName;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::sendprop::{SendProp, SendPropValue};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Compatible serialization with the js parser entity dumps
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum PVSCompat {
+    Preserve = 0,
+    Leave = 2,
+    Enter = 1,
+    Delete = 6,
+}
+
+impl From<UpdateType> for PVSCompat {
+    fn from(pvs: UpdateType) -> Self {
+        match pvs {
+            UpdateType::Preserve => PVSCompat::Preserve,
+            UpdateType::Leave => PVSCompat::Leave,
+            UpdateType::Enter => PVSCompat::Enter,
+            UpdateType::Delete => PVSCompat::Delete,
+        }
+    }
+}
+
+/// An entity update in the format of the js parser entity dumps
+#[derive(PartialEq, Clone, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityDump {
+    pub tick: DemoTick,
+    pub server_class: ServerClassName,
+    pub id: EntityId,
+    /// Props by `"{table}.{prop}"` name
+    pub props: HashMap<String, SendPropValue>,
+    pub pvs: PVSCompat,
+}
+
+impl EntityDump {
+    pub fn from_entity(entity: &PacketEntity, tick: DemoTick, state: &ParserState) -> Self {
+        Self::new(entity, tick, entity.props(state), state)
+    }
+
+    fn new(
+        entity: &PacketEntity,
+        tick: DemoTick,
+        props: impl IntoIterator<Item = SendProp>,
+        state: &ParserState,
+    ) -> Self {
+        EntityDump {
+            tick,
+            server_class: state
+                .server_classes
+                .get(usize::from(entity.server_class))
+                .map(|class| class.name.clone())
+                .unwrap_or_default(),
+            id: entity.entity_index,
+            pvs: entity.update_type.into(),
+            props: props
+                .into_iter()
+                .map(|prop| (prop_name(&prop, state), prop.value))
+                .collect(),
+        }
+    }
+}
+
+fn prop_name(prop: &SendProp, state: &ParserState) -> String {
+    state
+        .prop_full_name(prop.identifier)
+        .unwrap_or_else(|| u64::from(prop.identifier).to_string())
+}
+
+/// Limit the entities included in the dump
+#[derive(Debug, Clone, Default)]
+pub struct EntityDumpFilter {
+    /// Only include entities of these classes, all classes are included when empty
+    pub classes: Vec<ServerClassName>,
+    /// Only include these entities, all entities are included when empty
+    pub entities: Vec<EntityId>,
+    pub start_tick: Option<DemoTick>,
+    pub end_tick: Option<DemoTick>,
+}
+
+impl EntityDumpFilter {
+    pub fn matches(&self, entity: &PacketEntity, tick: DemoTick, state: &ParserState) -> bool {
+        if self
+            .start_tick
+            .map(|start| tick < start)
+            .unwrap_or_default()
+            || self.end_tick.map(|end| tick > end).unwrap_or_default()
+        {
+            return false;
+        }
+        if !self.entities.is_empty() && !self.entities.contains(&entity.entity_index) {
+            return false;
+        }
+        self.classes.is_empty()
+            || state
+                .server_classes
+                .get(usize::from(entity.server_class))
+                .map(|class| self.classes.contains(&class.name))
+                .unwrap_or_default()
+    }
+}
+
+/// Whether to dump the props included in the update or all props of the entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntityDumpMode {
+    #[default]
+    Delta,
+    /// Requires the entity world to be enabled with [`DemoParser::with_entity_world`](crate::DemoParser::with_entity_world)
+    Full,
+}
+
+/// Dump all entity updates in the format of the js parser
+///
+/// By default all dumps are collected into the output, use [`EntityDumper::with_sink`] to
+/// handle the dumps while parsing instead of keeping them in memory
+#[derive(Default)]
+pub struct EntityDumper {
+    filter: EntityDumpFilter,
+    mode: EntityDumpMode,
+    dumps: Vec<EntityDump>,
+    sink: Option<Box<dyn FnMut(EntityDump)>>,
+}
+
+impl fmt::Debug for EntityDumper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EntityDumper")
+            .field("filter", &self.filter)
+            .field("mode", &self.mode)
+            .field("dumps", &self.dumps)
+            .field("sink", &self.sink.is_some())
+            .finish()
+    }
+}
+
+impl MessageHandler for EntityDumper {
+    type Output = Vec<EntityDump>;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn requires_prop_names() -> bool {
+        true
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        if let Message::PacketEntities(message) = message {
+            for entity in &message.entities {
+                if self.filter.matches(entity, tick, parser_state) {
+                    let dump = self.dump(entity, tick, parser_state);
+                    match &mut self.sink {
+                        Some(sink) => sink(dump),
+                        None => self.dumps.push(dump),
+                    }
+                }
+            }
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.dumps
+    }
+}
+
+impl BorrowMessageHandler for EntityDumper {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.dumps
+    }
+}
+
+impl EntityDumper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_filter(self, filter: EntityDumpFilter) -> Self {
+        EntityDumper { filter, ..self }
+    }
+
+    pub fn with_mode(self, mode: EntityDumpMode) -> Self {
+        EntityDumper { mode, ..self }
+    }
+
+    /// Pass every dump to `sink` as soon as it's created instead of collecting them in the output
+    pub fn with_sink(self, sink: impl FnMut(EntityDump) + 'static) -> Self {
+        EntityDumper {
+            sink: Some(Box::new(sink)),
+            ..self
+        }
+    }
+
+    fn dump(&self, entity: &PacketEntity, tick: DemoTick, state: &ParserState) -> EntityDump {
+        let world_entity = match self.mode {
+            EntityDumpMode::Delta => None,
+            EntityDumpMode::Full => state
+                .entity_world()
+                .and_then(|world| world.get(entity.entity_index)),
+        };
+        match world_entity {
+            Some(world_entity) => {
+                EntityDump::new(entity, tick, world_entity.props.iter().cloned(), state)
+            }
+            None => EntityDump::from_entity(entity, tick, state),
+        }
+    }
+}
+
+#[test]
+fn test_entity_dump_filter() {
+    use crate::demo::packet::datatable::{ClassId, SendTableName, ServerClass};
+
+    let mut state = ParserState::new(24, |_| false, false);
+    state.server_classes = vec![
+        ServerClass {
+            id: ClassId::from(0),
+            name: ServerClassName::from("CWorld"),
+            data_table: SendTableName::from("DT_World"),
+        },
+        ServerClass {
+            id: ClassId::from(1),
+            name: ServerClassName::from("CTFPlayer"),
+            data_table: SendTableName::from("DT_TFPlayer"),
+        },
+    ];
+    let entity = |class: u16, index: u32| PacketEntity {
+        server_class: ClassId::from(class),
+        entity_index: EntityId::from(index),
+        props: Vec::new(),
+        in_pvs: true,
+        update_type: UpdateType::Preserve,
+        serial_number: 1,
+        delay: None,
+        delta: None,
+        baseline_index: 0,
+    };
+    let filter = EntityDumpFilter {
+        classes: vec![ServerClassName::from("CTFPlayer")],
+        entities: vec![EntityId::from(2u32)],
+        start_tick: Some(DemoTick::from(10)),
+        end_tick: Some(DemoTick::from(20)),
+    };
+
+    assert!(filter.matches(&entity(1, 2), DemoTick::from(10), &state));
+    assert!(filter.matches(&entity(1, 2), DemoTick::from(20), &state));
+    assert!(!filter.matches(&entity(1, 2), DemoTick::from(21), &state));
+    assert!(!filter.matches(&entity(1, 2), DemoTick::from(9), &state));
+    assert!(!filter.matches(&entity(1, 3), DemoTick::from(15), &state));
+    assert!(!filter.matches(&entity(0, 2), DemoTick::from(15), &state));
+    assert!(EntityDumpFilter::default().matches(&entity(0, 0), DemoTick::from(0), &state));
+}
+
+#[test]
+fn test_entity_dump_sink() {
+    use crate::demo::message::packetentities::PacketEntitiesMessage;
+    use crate::demo::packet::datatable::{ClassId, SendTableName, ServerClass};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let mut state = ParserState::new(24, |_| false, false);
+    state.server_classes = vec![ServerClass {
+        id: ClassId::from(0),
+        name: ServerClassName::from("CTFPlayer"),
+        data_table: SendTableName::from("DT_TFPlayer"),
+    }];
+    let message = Message::PacketEntities(PacketEntitiesMessage {
+        entities: vec![PacketEntity {
+            server_class: ClassId::from(0),
+            entity_index: EntityId::from(1u32),
+            props: Vec::new(),
+            in_pvs: true,
+            update_type: UpdateType::Preserve,
+            serial_number: 1,
+            delay: None,
+            delta: None,
+            baseline_index: 0,
+        }],
+        ..PacketEntitiesMessage::default()
+    });
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let mut dumper = EntityDumper::new().with_sink({
+        let received = received.clone();
+        move |dump| received.borrow_mut().push(dump)
+    });
+    dumper.handle_message(&message, DemoTick::from(5), &state);
+    dumper.handle_message(&message, DemoTick::from(6), &state);
+
+    assert!(dumper.borrow_output(&state).is_empty());
+    let received = received.borrow();
+    assert_eq!(2, received.len());
+    assert_eq!(DemoTick::from(6), received[1].tick);
+    assert_eq!(ServerClassName::from("CTFPlayer"), received[1].server_class);
+}
@@ -0,0 +1,51 @@
+use crate::demo::data::UserInfo;
+use crate::demo::message::packetentities::{EntityId, PacketEntity};
+use crate::demo::packet::datatable::{ServerClass, ServerClassName};
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::parser::analyser::UserId;
+use std::collections::HashMap;
+
+/// The user ids of player entities and the names of the server classes
+///
+/// Shared by the analysers that only look at the entities of some classes and attribute them to players,
+/// feed it from [`MessageHandler::handle_string_entry`](crate::demo::parser::MessageHandler::handle_string_entry)
+/// and [`MessageHandler::handle_data_tables`](crate::demo::parser::MessageHandler::handle_data_tables).
+#[derive(Debug, Clone, Default)]
+pub struct EntityLookup {
+    user_ids: HashMap<EntityId, UserId>,
+    class_names: Vec<ServerClassName>, // indexed by ClassId
+}
+
+impl EntityLookup {
+    /// Update the user ids from a string table entry, returns the user info if the entry is a valid `userinfo` entry
+    pub fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+    ) -> Option<UserInfo> {
+        if table != "userinfo" {
+            return None;
+        }
+        let user_info = UserInfo::from_string_table_entry(index, entry).ok()??;
+        self.user_ids
+            .insert(user_info.entity_id, user_info.player_info.user_id);
+        Some(user_info)
+    }
+
+    pub fn handle_data_tables(&mut self, server_classes: &[ServerClass]) {
+        self.class_names = server_classes
+            .iter()
+            .map(|class| &class.name)
+            .cloned()
+            .collect();
+    }
+
+    pub fn user_id(&self, entity: EntityId) -> Option<UserId> {
+        self.user_ids.get(&entity).copied()
+    }
+
+    pub fn class_name(&self, entity: &PacketEntity) -> Option<&ServerClassName> {
+        self.class_names.get(usize::from(entity.server_class))
+    }
+}
@@ -0,0 +1,227 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{EntityHandle, EntityId, PacketEntity, UpdateType};
+use crate::demo::packet::datatable::{ClassId, ServerClass, ServerClassName};
+use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+
+/// An entity with all its props as of the last update
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldEntity {
+    pub id: EntityId,
+    pub server_class: ClassId,
+    pub serial_number: u32,
+    pub in_pvs: bool,
+    /// The tick the entity was created at
+    pub created: DemoTick,
+    /// The tick the entity last received an update
+    pub updated: DemoTick,
+    pub props: Vec<SendProp>,
+}
+
+impl WorldEntity {
+    pub fn get_prop(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue> {
+        self.props
+            .iter()
+            .find(|prop| prop.identifier == identifier)
+            .map(|prop| &prop.value)
+    }
+
+    pub fn get_prop_by_name(&self, table_name: &str, name: &str) -> Option<&SendPropValue> {
+        self.get_prop(SendPropIdentifier::new(table_name, name))
+    }
+
+    fn apply_update(&mut self, props: Vec<SendProp>) {
+        for prop in props {
+            match self
+                .props
+                .iter_mut()
+                .find(|existing| existing.identifier == prop.identifier)
+            {
+                Some(existing) => existing.value = prop.value,
+                None => self.props.push(prop),
+            }
+        }
+    }
+}
+
+/// The current state of all entities, built by applying the entity updates from the demo
+///
+/// Only maintained when enabled with [`DemoParser::with_entity_world`](crate::DemoParser::with_entity_world).
+#[derive(Debug, Clone, Default)]
+pub struct EntityWorld {
+    entities: Vec<Option<WorldEntity>>,
+    class_names: Vec<ServerClassName>,
+}
+
+impl EntityWorld {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&WorldEntity> {
+        self.entities.get(usize::from(id)).and_then(Option::as_ref)
+    }
+
+    /// Resolve an entity handle, entities that don't match the serial number of the handle are not resolved
+    pub fn resolve(&self, handle: EntityHandle) -> Option<&WorldEntity> {
+        self.get(handle.entity()?)
+            .filter(|entity| handle.matches(entity.id, entity.serial_number))
+    }
+
+    /// All live entities, including the ones outside of the PVS
+    pub fn iter(&self) -> impl Iterator<Item = &WorldEntity> {
+        self.entities.iter().flatten()
+    }
+
+    /// All live entities that are currently inside the PVS
+    pub fn in_pvs(&self) -> impl Iterator<Item = &WorldEntity> {
+        self.iter().filter(|entity| entity.in_pvs)
+    }
+
+    pub fn class_name(&self, class: ClassId) -> Option<&ServerClassName> {
+        self.class_names.get(usize::from(class))
+    }
+
+    /// All live entities of a server class, e.g. `CTFPlayer`
+    pub fn of_class<'a>(&'a self, class_name: &'a str) -> impl Iterator<Item = &'a WorldEntity> {
+        self.iter().filter(move |entity| {
+            self.class_name(entity.server_class)
+                .map(|name| name.as_str() == class_name)
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    pub fn handle_server_classes(&mut self, server_classes: &[ServerClass]) {
+        self.class_names = server_classes
+            .iter()
+            .map(|class| class.name.clone())
+            .collect();
+    }
+
+    /// Remove all entities, as done by the client before a full (non-delta) entity update
+    pub fn clear(&mut self) {
+        self.entities.clear();
+    }
+
+    pub fn remove(&mut self, id: EntityId) -> Option<WorldEntity> {
+        self.entities
+            .get_mut(usize::from(id))
+            .and_then(Option::take)
+    }
+
+    /// Apply an entity update, `props` should contain the baseline props for entities entering the PVS
+    pub fn handle_entity(&mut self, entity: &PacketEntity, props: Vec<SendProp>, tick: DemoTick) {
+        let index = usize::from(entity.entity_index);
+        match entity.update_type {
+            UpdateType::Delete => {
+                self.remove(entity.entity_index);
+            }
+            UpdateType::Enter => {
+                if self.entities.len() <= index {
+                    self.entities.resize(index + 1, None);
+                }
+                match &mut self.entities[index] {
+                    // re-entering the pvs
+                    Some(existing)
+                        if existing.server_class == entity.server_class
+                            && existing.serial_number == entity.serial_number =>
+                    {
+                        existing.in_pvs = true;
+                        existing.updated = tick;
+                        existing.apply_update(props);
+                    }
+                    slot => {
+                        *slot = Some(WorldEntity {
+                            id: entity.entity_index,
+                            server_class: entity.server_class,
+                            serial_number: entity.serial_number,
+                            in_pvs: true,
+                            created: tick,
+                            updated: tick,
+                            props,
+                        })
+                    }
+                }
+            }
+            UpdateType::Leave | UpdateType::Preserve => {
+                if let Some(Some(existing)) = self.entities.get_mut(index) {
+                    existing.in_pvs = entity.update_type == UpdateType::Preserve;
+                    existing.updated = tick;
+                    existing.apply_update(props);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_entity_world() {
+    use crate::demo::sendprop::SendPropValue;
+
+    const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+    const TEAM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+
+    let prop = |identifier: SendPropIdentifier, value: i64| SendProp {
+        index: 0,
+        identifier,
+        value: SendPropValue::Integer(value),
+    };
+    let entity = |update_type: UpdateType, props: Vec<SendProp>| PacketEntity {
+        server_class: ClassId::from(1u16),
+        entity_index: EntityId::from(3u32),
+        props,
+        in_pvs: update_type != UpdateType::Leave,
+        update_type,
+        serial_number: 7,
+        delay: None,
+        delta: None,
+        baseline_index: 0,
+    };
+
+    let mut world = EntityWorld::new();
+    world.handle_server_classes(&[
+        ServerClass {
+            id: ClassId::from(0u16),
+            name: ServerClassName::from("CWorld".to_string()),
+            data_table: "DT_World".to_string().into(),
+        },
+        ServerClass {
+            id: ClassId::from(1u16),
+            name: ServerClassName::from("CTFPlayer".to_string()),
+            data_table: "DT_TFPlayer".to_string().into(),
+        },
+    ]);
+
+    let enter = entity(UpdateType::Enter, vec![]);
+    world.handle_entity(&enter, vec![prop(HEALTH, 125), prop(TEAM, 2)], 1.into());
+    let update = entity(UpdateType::Preserve, vec![]);
+    world.handle_entity(&update, vec![prop(HEALTH, 100)], 2.into());
+
+    let player = world.get(EntityId::from(3u32)).unwrap();
+    assert_eq!(player.get_prop(HEALTH), Some(&SendPropValue::Integer(100)));
+    assert_eq!(player.get_prop(TEAM), Some(&SendPropValue::Integer(2)));
+    assert_eq!(player.created, DemoTick::from(1));
+    assert_eq!(world.of_class("CTFPlayer").count(), 1);
+    assert_eq!(world.of_class("CWorld").count(), 0);
+    assert!(world
+        .resolve(EntityHandle::new(EntityId::from(3u32), 7))
+        .is_some());
+    assert!(world
+        .resolve(EntityHandle::new(EntityId::from(3u32), 8))
+        .is_none());
+
+    world.handle_entity(&entity(UpdateType::Leave, vec![]), vec![], 3.into());
+    assert_eq!(world.in_pvs().count(), 0);
+    assert_eq!(world.len(), 1);
+
+    world.handle_entity(&entity(UpdateType::Delete, vec![]), vec![], 4.into());
+    assert!(world.get(EntityId::from(3u32)).is_none());
+    assert!(world.is_empty());
+}
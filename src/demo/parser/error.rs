@@ -1,7 +1,7 @@
 use crate::demo::gamevent::GameEventValueType;
 use crate::demo::message::gameevent::GameEventTypeId;
 use crate::demo::message::packetentities::EntityId;
-use crate::demo::packet::datatable::{ClassId, SendTableName};
+use crate::demo::packet::datatable::{ClassId, SendTableName, ServerClassName};
 use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
 use bitbuffer::BitError;
 use err_derive::Error;
@@ -81,6 +81,22 @@ pub enum ParseError {
     UnknownEntity(EntityId),
     #[error(display = "No sendprop definition found for property")]
     UnknownDefinition(SendPropIdentifier),
+    #[error(
+        display = "Invalid value decoded for {} of entity {} ({}): {}",
+        prop_name,
+        entity,
+        class,
+        error
+    )]
+    InvalidPropValue {
+        entity: EntityId,
+        class: ServerClassName,
+        prop: SendPropIdentifier,
+        /// `"{table}.{prop}"` name of the prop, or the identifier if the name isn't known
+        prop_name: String,
+        value: SendPropValue,
+        error: InvalidPropValueError,
+    },
 }
 
 #[derive(Debug, Error)]
@@ -104,6 +120,38 @@ pub enum MalformedSendPropDefinitionError {
     },
 }
 
+/// A decoded prop value that doesn't match its definition, only checked in strict mode
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum InvalidPropValueError {
+    #[error(
+        display = "float {} is outside of the range {} to {}",
+        value,
+        low,
+        high
+    )]
+    FloatOutOfRange { value: f32, low: f32, high: f32 },
+    #[error(display = "float {} is not finite", _0)]
+    NonFiniteFloat(f32),
+    #[error(display = "integer {} doesn't fit in {} bits", value, bit_count)]
+    IntOutOfRange { value: i64, bit_count: u8 },
+    #[error(
+        display = "float {} can't be encoded with a resolution of {}",
+        value,
+        resolution
+    )]
+    UnreachableFloat { value: f32, resolution: f32 },
+    #[error(display = "array has {} elements but only {} are allowed", count, max)]
+    TooManyElements { count: usize, max: u16 },
+    #[error(
+        display = "string of {} bytes is longer than the maximum of {}",
+        length,
+        max
+    )]
+    StringTooLong { length: usize, max: usize },
+    #[error(display = "value doesn't match the prop type {}", _0)]
+    WrongType(&'static str),
+}
+
 #[derive(Debug, Error)]
 pub enum GameEventError {
     #[error(display = "Incorrect number of values")]
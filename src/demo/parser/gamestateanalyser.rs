@@ -1,8 +1,8 @@
-use crate::demo::data::DemoTick;
+use crate::demo::data::{DemoTick, PlayerConditions};
 use crate::demo::gameevent_gen::{ObjectDestroyedEvent, PlayerDeathEvent};
 use crate::demo::gamevent::GameEvent;
 use crate::demo::message::gameevent::GameEventMessage;
-use crate::demo::message::packetentities::{EntityId, PacketEntity, UpdateType};
+use crate::demo::message::packetentities::{EntityHandle, EntityId, PacketEntity, UpdateType};
 use crate::demo::message::Message;
 use crate::demo::packet::datatable::{ParseSendTable, ServerClass, ServerClassName};
 use crate::demo::packet::message::MessagePacketMeta;
@@ -10,6 +10,7 @@ use crate::demo::packet::stringtable::StringTableEntry;
 use crate::demo::parser::analyser::UserInfo;
 pub use crate::demo::parser::analyser::{Class, Team, UserId};
 use crate::demo::parser::handler::BorrowMessageHandler;
+use crate::demo::parser::lifecycle::EntityInfo;
 use crate::demo::parser::MessageHandler;
 use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
 use crate::demo::vector::{Vector, VectorXY};
@@ -43,7 +44,7 @@ impl PlayerState {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Player {
-    entity: EntityId,
+    pub entity: EntityId,
     pub position: Vector,
     pub health: u16,
     pub max_health: u16,
@@ -57,6 +58,10 @@ pub struct Player {
     pub simtime: u16,
     pub ping: u16,
     pub in_pvs: bool,
+    /// Not serialized to keep the output of the game state unchanged,
+    /// use the [`ConditionAnalyser`](crate::demo::parser::conditionanalyser::ConditionAnalyser) for the condition history
+    #[serde(skip)]
+    pub conditions: PlayerConditions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -220,12 +225,29 @@ impl Building {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
 pub enum BuildingClass {
     Sentry,
     Dispenser,
     Teleporter,
 }
 
+impl BuildingClass {
+    /// The building class from the `object_type` of object game events, `None` for sappers and unknown objects
+    pub fn from_object_type<U>(object_type: U) -> Option<Self>
+    where
+        u8: TryFrom<U>,
+    {
+        match u8::try_from(object_type).ok()? {
+            0 => Some(BuildingClass::Dispenser),
+            1 => Some(BuildingClass::Teleporter),
+            2 => Some(BuildingClass::Sentry),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct World {
     pub boundary_min: Vector,
@@ -284,6 +306,17 @@ impl GameState {
 
         &mut self.players[index]
     }
+
+    pub fn get_player_by_user_id(&self, user_id: UserId) -> Option<&Player> {
+        self.players.iter().find(|player| {
+            player
+                .info
+                .as_ref()
+                .map(|info| info.user_id == user_id)
+                .unwrap_or_default()
+        })
+    }
+
     pub fn get_or_create_building(
         &mut self,
         entity_id: EntityId,
@@ -381,6 +414,25 @@ impl MessageHandler for GameStateAnalyser {
         self.tick = tick;
     }
 
+    fn on_entity_deleted(
+        &mut self,
+        entity: &EntityInfo,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        self.state.remove_building(entity.id);
+    }
+
+    fn on_entity_replaced(
+        &mut self,
+        old: &EntityInfo,
+        _new: &EntityInfo,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        self.state.remove_building(old.id);
+    }
+
     fn into_output(self, _state: &ParserState) -> Self::Output {
         self.state
     }
@@ -512,7 +564,9 @@ impl GameStateAnalyser {
                 SIMTIME_PROP => {
                     player.simtime = i64::try_from(&prop.value).unwrap_or_default() as u16
                 }
-                _ => {}
+                _ => {
+                    player.conditions.update_from_prop(&prop);
+                }
             }
         }
     }
@@ -552,8 +606,8 @@ impl GameStateAnalyser {
         const ROCKETS: SendPropIdentifier =
             SendPropIdentifier::new("DT_ObjectSentrygun", "m_iAmmoRockets");
 
+        // deleted buildings are removed in `on_entity_deleted`
         if entity.update_type == UpdateType::Delete {
-            self.state.remove_building(entity.entity_index);
             return;
         }
 
@@ -574,7 +628,7 @@ impl GameStateAnalyser {
                     }
                     TARGET => {
                         sentry.auto_aim_target =
-                            UserId::from(i64::try_from(&prop.value).unwrap_or_default() as u16)
+                            handle_user_id(&prop.value, parser_state).unwrap_or_default()
                     }
                     SHELLS => sentry.shells = i64::try_from(&prop.value).unwrap_or_default() as u16,
                     ROCKETS => {
@@ -600,8 +654,8 @@ impl GameStateAnalyser {
         const IS_ENTRANCE: SendPropIdentifier =
             SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode");
 
+        // deleted buildings are removed in `on_entity_deleted`
         if entity.update_type == UpdateType::Delete {
-            self.state.remove_building(entity.entity_index);
             return;
         }
 
@@ -626,8 +680,10 @@ impl GameStateAnalyser {
                             i64::try_from(&prop.value).unwrap_or_default() as u16
                     }
                     OTHER_END => {
-                        teleporter.other_end =
-                            EntityId::from(i64::try_from(&prop.value).unwrap_or_default() as u32)
+                        teleporter.other_end = EntityHandle::try_from(&prop.value)
+                            .ok()
+                            .and_then(|handle| handle.entity())
+                            .unwrap_or_default()
                     }
                     YAW_TO_EXIT => {
                         teleporter.yaw_to_exit = f32::try_from(&prop.value).unwrap_or_default()
@@ -647,8 +703,8 @@ impl GameStateAnalyser {
         const HEALING: SendPropIdentifier =
             SendPropIdentifier::new("DT_ObjectDispenser", "healing_array");
 
+        // deleted buildings are removed in `on_entity_deleted`
         if entity.update_type == UpdateType::Delete {
-            self.state.remove_building(entity.entity_index);
             return;
         }
 
@@ -670,7 +726,7 @@ impl GameStateAnalyser {
 
                         dispenser.healing = values
                             .iter()
-                            .map(|val| UserId::from(i64::try_from(val).unwrap_or_default() as u16))
+                            .filter_map(|val| handle_user_id(val, parser_state))
                             .collect()
                     }
                     _ => {}
@@ -751,8 +807,7 @@ impl GameStateAnalyser {
                         BUILDING => *building = i64::try_from(&prop.value).unwrap_or_default() > 0,
                         LEVEL => *level = i64::try_from(&prop.value).unwrap_or_default() as u8,
                         BUILDER => {
-                            *builder =
-                                UserId::from(i64::try_from(&prop.value).unwrap_or_default() as u16)
+                            *builder = handle_user_id(&prop.value, parser_state).unwrap_or_default()
                         }
                         MAX_HEALTH => {
                             *max_health = i64::try_from(&prop.value).unwrap_or_default() as u16
@@ -781,3 +836,66 @@ impl GameStateAnalyser {
         Ok(())
     }
 }
+
+/// The user id of the player a networked entity handle points to
+fn handle_user_id(value: &SendPropValue, parser_state: &ParserState) -> Option<UserId> {
+    let entity = parser_state.resolve_handle(EntityHandle::try_from(value).ok()?)?;
+    parser_state.user_id(entity)
+}
+
+#[test]
+fn test_buildings_removed_on_delete() {
+    use crate::demo::message::packetentities::PacketEntitiesMessage;
+    use crate::demo::packet::datatable::{ClassId, SendTableName};
+
+    let state = ParserState::new(24, |_| true, false);
+    let entity = |index: u32| PacketEntity {
+        server_class: ClassId::from(0u16),
+        entity_index: EntityId::from(index),
+        props: Vec::new(),
+        in_pvs: true,
+        update_type: UpdateType::Preserve,
+        serial_number: 1,
+        delay: None,
+        delta: None,
+        baseline_index: 0,
+    };
+    let info = |index: u32, serial_number: u32| EntityInfo {
+        id: EntityId::from(index),
+        server_class: ClassId::from(0u16),
+        class_name: "CObjectSentrygun",
+        serial_number,
+        in_pvs: true,
+    };
+
+    let mut analyser = GameStateAnalyser::new();
+    analyser.handle_data_tables(
+        &[],
+        &[ServerClass {
+            id: ClassId::from(0u16),
+            name: ServerClassName::from("CObjectSentrygun"),
+            data_table: SendTableName::from("DT_ObjectSentrygun"),
+        }],
+        &state,
+    );
+    analyser.handle_message(
+        &Message::PacketEntities(PacketEntitiesMessage {
+            entities: vec![entity(10), entity(11)],
+            removed_entities: vec![],
+            max_entries: 0,
+            delta: None,
+            base_line: 0,
+            updated_base_line: false,
+        }),
+        1.into(),
+        &state,
+    );
+    assert_eq!(analyser.state.buildings.len(), 2);
+
+    analyser.on_entity_deleted(&info(10, 1), 2.into(), &state);
+    assert_eq!(analyser.state.buildings.len(), 1);
+
+    // the entity index is reused by a new entity
+    analyser.on_entity_replaced(&info(11, 1), &info(11, 2), 3.into(), &state);
+    assert!(analyser.state.buildings.is_empty());
+}
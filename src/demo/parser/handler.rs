@@ -1,12 +1,14 @@
 use crate::demo::message::{Message, MessageType};
 use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
 use crate::demo::packet::stringtable::{StringTable, StringTableEntry};
+use crate::demo::packet::usercmd::ResolvedUserCmd;
 use crate::demo::packet::Packet;
 use crate::Result;
 
 use crate::demo::data::{DemoTick, ServerTick};
 use crate::demo::header::Header;
 use crate::demo::packet::message::MessagePacketMeta;
+use crate::demo::parser::lifecycle::{EntityInfo, EntityLifecycleEvent};
 use crate::ParserState;
 use std::borrow::Cow;
 
@@ -15,6 +17,16 @@ pub trait MessageHandler {
 
     fn does_handle(message_type: MessageType) -> bool;
 
+    /// Whether the analyser reads names from [`ParserState::string_table_contents`], which is then kept by the parser
+    fn requires_string_tables() -> bool {
+        false
+    }
+
+    /// Whether the analyser reads prop names from [`ParserState::prop_name`], which are then kept by the parser
+    fn requires_prop_names() -> bool {
+        false
+    }
+
     fn handle_header(&mut self, _header: &Header) {}
 
     fn handle_message(&mut self, _message: &Message, _tick: DemoTick, _parser_state: &ParserState) {
@@ -45,6 +57,54 @@ pub trait MessageHandler {
     ) {
     }
 
+    /// Called for every user command recorded in a POV demo
+    fn handle_user_cmd(
+        &mut self,
+        _tick: DemoTick,
+        _cmd: &ResolvedUserCmd,
+        _parser_state: &ParserState,
+    ) {
+    }
+
+    /// Called when a new entity is created
+    ///
+    /// The entity lifecycle callbacks are only called when the packet entities are parsed,
+    /// they are called before the `PacketEntities` message containing the change is handled
+    fn on_entity_created(
+        &mut self,
+        _entity: &EntityInfo,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+    }
+
+    /// Called when an existing entity enters or leaves the PVS
+    fn on_entity_pvs_change(
+        &mut self,
+        _entity: &EntityInfo,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+    }
+
+    fn on_entity_deleted(
+        &mut self,
+        _entity: &EntityInfo,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+    }
+
+    /// Called when the index of an entity is reused by a new entity, instead of `on_entity_deleted` and `on_entity_created`
+    fn on_entity_replaced(
+        &mut self,
+        _old: &EntityInfo,
+        _new: &EntityInfo,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+    }
+
     fn into_output(self, state: &ParserState) -> Self::Output;
 }
 
@@ -87,7 +147,13 @@ impl<'a> Default for DemoHandler<'a, NullHandler> {
 
 impl<'a, T: MessageHandler> DemoHandler<'a, T> {
     pub fn with_analyser(analyser: T) -> Self {
-        let state_handler = ParserState::new(24, T::does_handle, false);
+        let mut state_handler = ParserState::new(24, T::does_handle, false);
+        if T::requires_string_tables() {
+            state_handler.enable_string_tables();
+        }
+        if T::requires_prop_names() {
+            state_handler.enable_prop_names();
+        }
 
         DemoHandler {
             server_tick: ServerTick::default(),
@@ -98,7 +164,13 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
         }
     }
     pub fn parse_all_with_analyser(analyser: T) -> Self {
-        let state_handler = ParserState::new(24, T::does_handle, true);
+        let mut state_handler = ParserState::new(24, T::does_handle, true);
+        if T::requires_string_tables() {
+            state_handler.enable_string_tables();
+        }
+        if T::requires_prop_names() {
+            state_handler.enable_prop_names();
+        }
 
         DemoHandler {
             server_tick: ServerTick::default(),
@@ -109,6 +181,30 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
         }
     }
 
+    /// Maintain the full state of all entities, available through [`ParserState::entity_world`]
+    pub fn with_entity_world(mut self) -> Self {
+        self.state_handler.enable_entity_world();
+        self
+    }
+
+    /// Keep the contents of all string tables, available through [`ParserState::string_table_contents`]
+    pub fn with_string_tables(mut self) -> Self {
+        self.state_handler.enable_string_tables();
+        self
+    }
+
+    /// Keep the names of all props from the data tables, available through [`ParserState::prop_name`]
+    pub fn with_prop_names(mut self) -> Self {
+        self.state_handler.enable_prop_names();
+        self
+    }
+
+    /// Validate all decoded prop values, see [`ParserState::enable_strict_props`]
+    pub fn with_strict_props(mut self) -> Self {
+        self.state_handler.enable_strict_props();
+        self
+    }
+
     pub fn handle_header(&mut self, header: &Header) {
         self.state_handler.protocol_version = header.protocol;
         self.analyser.handle_header(header);
@@ -120,11 +216,13 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
                 self.handle_data_table(packet.tables, packet.server_classes)?;
             }
             Packet::StringTables(packet) => {
+                self.demo_tick = packet.tick;
                 for table in packet.tables.into_iter() {
                     self.handle_string_table(table)
                 }
             }
             Packet::Message(packet) | Packet::Signon(packet) => {
+                self.demo_tick = packet.tick;
                 self.analyser
                     .handle_packet_meta(packet.tick, &packet.meta, &self.state_handler);
                 for message in packet.messages {
@@ -146,6 +244,13 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
                     }
                 }
             }
+            Packet::UserCmd(packet) => {
+                self.demo_tick = packet.tick;
+                // commands are stored in the demo encoded against an empty command, not the previous one
+                let cmd = packet.cmd.resolve(&ResolvedUserCmd::default());
+                self.analyser
+                    .handle_user_cmd(packet.tick, &cmd, &self.state_handler);
+            }
             _ => {}
         };
         Ok(())
@@ -156,8 +261,12 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
             .handle_string_table_meta(table.get_table_meta());
         for (entry_index, entry) in table.entries.into_iter() {
             let entry_index = entry_index as usize;
-            self.state_handler
-                .handle_string_entry(&table.name, entry_index, &entry);
+            self.state_handler.handle_string_entry(
+                &table.name,
+                entry_index,
+                &entry,
+                self.demo_tick,
+            );
             self.analyser.handle_string_entry(
                 &table.name,
                 entry_index,
@@ -174,7 +283,7 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
             for (index, entry) in entries {
                 let index = index as usize;
                 self.state_handler
-                    .handle_string_entry(table_name, index, &entry);
+                    .handle_string_entry(table_name, index, &entry, self.demo_tick);
                 self.analyser
                     .handle_string_entry(table_name, index, &entry, &self.state_handler);
             }
@@ -194,6 +303,12 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
 
     pub fn handle_message(&mut self, message: Message<'a>, tick: DemoTick) {
         let message_type = message.get_message_type();
+        if let Message::PacketEntities(entities) = &message {
+            self.state_handler.update_entity_world(entities, tick);
+            for event in self.state_handler.entity_lifecycle.handle_message(entities) {
+                self.handle_lifecycle_event(event, tick);
+            }
+        }
         if T::does_handle(message_type) {
             self.analyser
                 .handle_message(&message, tick, &self.state_handler);
@@ -201,6 +316,31 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
         self.state_handler.handle_message(message, tick);
     }
 
+    fn handle_lifecycle_event(&mut self, event: EntityLifecycleEvent, tick: DemoTick) {
+        let state = &self.state_handler;
+        let classes = state.server_classes.as_slice();
+        match event {
+            EntityLifecycleEvent::Created(entity) => {
+                self.analyser
+                    .on_entity_created(&entity.info(classes), tick, state)
+            }
+            EntityLifecycleEvent::PvsChange(entity) => {
+                self.analyser
+                    .on_entity_pvs_change(&entity.info(classes), tick, state)
+            }
+            EntityLifecycleEvent::Deleted(entity) => {
+                self.analyser
+                    .on_entity_deleted(&entity.info(classes), tick, state)
+            }
+            EntityLifecycleEvent::Replaced { old, new } => self.analyser.on_entity_replaced(
+                &old.info(classes),
+                &new.info(classes),
+                tick,
+                state,
+            ),
+        }
+    }
+
     pub fn into_output(self) -> T::Output {
         self.analyser.into_output(&self.state_handler)
     }
@@ -0,0 +1,216 @@
+use crate::demo::message::packetentities::{EntityId, PacketEntitiesMessage, UpdateType};
+use crate::demo::packet::datatable::{ClassId, ServerClass};
+
+/// The identity and PVS status of an entity, as passed to the entity lifecycle callbacks of the `MessageHandler`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityInfo<'a> {
+    pub id: EntityId,
+    pub server_class: ClassId,
+    pub class_name: &'a str,
+    pub serial_number: u32,
+    pub in_pvs: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackedEntity {
+    pub id: EntityId,
+    pub server_class: ClassId,
+    pub serial_number: u32,
+    pub in_pvs: bool,
+}
+
+impl TrackedEntity {
+    pub fn info<'a>(&self, server_classes: &'a [ServerClass]) -> EntityInfo<'a> {
+        EntityInfo {
+            id: self.id,
+            server_class: self.server_class,
+            class_name: server_classes
+                .get(usize::from(self.server_class))
+                .map(|class| class.name.as_str())
+                .unwrap_or_default(),
+            serial_number: self.serial_number,
+            in_pvs: self.in_pvs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityLifecycleEvent {
+    Created(TrackedEntity),
+    /// The entity entered or left the PVS, `in_pvs` contains the new status
+    PvsChange(TrackedEntity),
+    Deleted(TrackedEntity),
+    /// The entity index was reused for a new entity
+    Replaced {
+        old: TrackedEntity,
+        new: TrackedEntity,
+    },
+}
+
+/// Turns the entity deltas into higher level lifecycle events
+#[derive(Debug, Clone, Default)]
+pub struct EntityLifecycle {
+    entities: Vec<Option<TrackedEntity>>,
+}
+
+impl EntityLifecycle {
+    pub fn get(&self, id: EntityId) -> Option<&TrackedEntity> {
+        self.entities.get(usize::from(id)).and_then(Option::as_ref)
+    }
+
+    pub fn handle_message(&mut self, message: &PacketEntitiesMessage) -> Vec<EntityLifecycleEvent> {
+        let mut events = Vec::new();
+
+        for removed in message.removed_entities.iter() {
+            if let Some(existing) = self.remove(*removed) {
+                events.push(EntityLifecycleEvent::Deleted(existing));
+            }
+        }
+
+        // the client removes all entities that aren't part of a full update
+        if message.delta.is_none() {
+            for slot in self.entities.iter_mut() {
+                let keep = slot
+                    .map(|existing| {
+                        message
+                            .entities
+                            .iter()
+                            .any(|entity| entity.entity_index == existing.id)
+                    })
+                    .unwrap_or(true);
+                if !keep {
+                    events.extend(slot.take().map(EntityLifecycleEvent::Deleted));
+                }
+            }
+        }
+
+        for entity in message.entities.iter() {
+            let index = usize::from(entity.entity_index);
+            if self.entities.len() <= index {
+                self.entities.resize(index + 1, None);
+            }
+            let slot = &mut self.entities[index];
+            match (entity.update_type, slot.as_mut()) {
+                (UpdateType::Delete, _) => {
+                    events.extend(slot.take().map(EntityLifecycleEvent::Deleted));
+                }
+                (UpdateType::Enter, Some(existing))
+                    if existing.server_class == entity.server_class
+                        && existing.serial_number == entity.serial_number =>
+                {
+                    let entered = !existing.in_pvs;
+                    existing.in_pvs = true;
+                    if entered {
+                        events.push(EntityLifecycleEvent::PvsChange(*existing));
+                    }
+                }
+                (UpdateType::Enter, existing) => {
+                    let new = TrackedEntity {
+                        id: entity.entity_index,
+                        server_class: entity.server_class,
+                        serial_number: entity.serial_number,
+                        in_pvs: true,
+                    };
+                    events.push(match existing {
+                        Some(old) => EntityLifecycleEvent::Replaced { old: *old, new },
+                        None => EntityLifecycleEvent::Created(new),
+                    });
+                    *slot = Some(new);
+                }
+                (UpdateType::Leave, Some(existing)) if existing.in_pvs => {
+                    existing.in_pvs = false;
+                    events.push(EntityLifecycleEvent::PvsChange(*existing));
+                }
+                _ => {}
+            }
+        }
+
+        events
+    }
+
+    fn remove(&mut self, id: EntityId) -> Option<TrackedEntity> {
+        self.entities
+            .get_mut(usize::from(id))
+            .and_then(Option::take)
+    }
+}
+
+#[test]
+fn test_entity_lifecycle() {
+    use crate::demo::message::packetentities::PacketEntity;
+
+    let entity = |index: u32, serial_number: u32, update_type: UpdateType| PacketEntity {
+        server_class: ClassId::from(1u16),
+        entity_index: EntityId::from(index),
+        props: Vec::new(),
+        in_pvs: update_type != UpdateType::Leave,
+        update_type,
+        serial_number,
+        delay: None,
+        delta: None,
+        baseline_index: 0,
+    };
+    let message = |entities: Vec<PacketEntity>, removed_entities: Vec<u32>| PacketEntitiesMessage {
+        entities,
+        removed_entities: removed_entities.into_iter().map(EntityId::from).collect(),
+        max_entries: 0,
+        delta: Some(1u32.into()),
+        base_line: 0,
+        updated_base_line: false,
+    };
+    let tracked = |index: u32, serial_number: u32, in_pvs: bool| TrackedEntity {
+        id: EntityId::from(index),
+        server_class: ClassId::from(1u16),
+        serial_number,
+        in_pvs,
+    };
+
+    let mut lifecycle = EntityLifecycle::default();
+    assert_eq!(
+        lifecycle.handle_message(&message(
+            vec![
+                entity(1, 5, UpdateType::Enter),
+                entity(2, 6, UpdateType::Enter)
+            ],
+            vec![]
+        )),
+        vec![
+            EntityLifecycleEvent::Created(tracked(1, 5, true)),
+            EntityLifecycleEvent::Created(tracked(2, 6, true))
+        ]
+    );
+    assert_eq!(
+        lifecycle.handle_message(&message(
+            vec![
+                entity(1, 5, UpdateType::Leave),
+                entity(2, 6, UpdateType::Preserve)
+            ],
+            vec![]
+        )),
+        vec![EntityLifecycleEvent::PvsChange(tracked(1, 5, false))]
+    );
+    assert_eq!(
+        lifecycle.handle_message(&message(
+            vec![
+                entity(1, 5, UpdateType::Enter),
+                entity(2, 7, UpdateType::Enter)
+            ],
+            vec![]
+        )),
+        vec![
+            EntityLifecycleEvent::PvsChange(tracked(1, 5, true)),
+            EntityLifecycleEvent::Replaced {
+                old: tracked(2, 6, true),
+                new: tracked(2, 7, true)
+            }
+        ]
+    );
+    assert_eq!(
+        lifecycle.handle_message(&message(vec![entity(1, 5, UpdateType::Delete)], vec![2])),
+        vec![
+            EntityLifecycleEvent::Deleted(tracked(2, 7, true)),
+            EntityLifecycleEvent::Deleted(tracked(1, 5, true))
+        ]
+    );
+    assert_eq!(lifecycle.get(EntityId::from(1u32)), None);
+}
@@ -0,0 +1,821 @@
+use crate::demo::data::DemoTick;
+use crate::demo::gameevent_gen::{PlayerDeathEvent, PlayerSpawnEvent};
+use crate::demo::gamevent::GameEvent;
+use crate::demo::message::packetentities::{EntityHandle, EntityId, PacketEntity, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass, ServerClassName};
+use crate::demo::packet::stringtable::StringTableEntry;
+pub use crate::demo::parser::analyser::{Class, UserId};
+use crate::demo::parser::entitylookup::EntityLookup;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::convert::TryFrom;
+
+pub(crate) const ITEM_DEFINITION_INDEX: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iItemDefinitionIndex");
+const ITEM_QUALITY: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iEntityQuality");
+const ITEM_LEVEL: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iEntityLevel");
+const ATTRIBUTE_INDEX: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+const ATTRIBUTE_VALUE: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+const ATTRIBUTE_COUNT: SendPropIdentifier =
+    SendPropIdentifier::new("_LPT_m_Attributes_20", "lengthprop20");
+const WEAPON_OWNER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseCombatWeapon", "m_hOwner");
+const OWNER_ENTITY: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+
+const ACTIVE_WEAPON: SendPropIdentifier =
+    SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+/// Number of entries in `m_hMyWeapons`
+const MAX_WEAPONS: u8 = 48;
+
+const ATTRIBUTE_PAINT_KIT: u16 = 834;
+const ATTRIBUTE_KILLSTREAK_EFFECT: u16 = 2013;
+const ATTRIBUTE_KILLSTREAK_SHEEN: u16 = 2014;
+const ATTRIBUTE_KILLSTREAK_TIER: u16 = 2025;
+const ATTRIBUTE_AUSTRALIUM: u16 = 2027;
+const ATTRIBUTE_FESTIVIZED: u16 = 2053;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Item {
+    pub entity: EntityId,
+    pub class: ServerClassName,
+    pub item_definition_index: u16,
+    pub quality: u8,
+    pub level: u8,
+    pub owner: Option<EntityId>,
+    /// Item attributes by attribute definition index, values are the raw 32 bit attribute value
+    pub attributes: BTreeMap<u16, u32>,
+    /// The raw attribute props by prop index, all attributes share the same prop identifiers
+    #[serde(skip)]
+    attribute_props: BTreeMap<u32, (SendPropIdentifier, i64)>,
+    #[serde(skip)]
+    attribute_count: Option<usize>,
+}
+
+impl Item {
+    pub fn is_wearable(&self) -> bool {
+        self.class.as_str().starts_with("CTFWearable")
+    }
+
+    fn float_attribute(&self, attribute: u16) -> Option<f32> {
+        self.attributes
+            .get(&attribute)
+            .map(|raw| f32::from_bits(*raw))
+    }
+
+    /// The killstreak tier of the weapon: 1 for basic, 2 for specialized and 3 for professional killstreak
+    pub fn killstreak_tier(&self) -> Option<u8> {
+        self.float_attribute(ATTRIBUTE_KILLSTREAK_TIER)
+            .map(|tier| tier as u8)
+    }
+
+    pub fn killstreak_sheen(&self) -> Option<u8> {
+        self.float_attribute(ATTRIBUTE_KILLSTREAK_SHEEN)
+            .map(|sheen| sheen as u8)
+    }
+
+    pub fn killstreak_effect(&self) -> Option<u16> {
+        self.float_attribute(ATTRIBUTE_KILLSTREAK_EFFECT)
+            .map(|effect| effect as u16)
+    }
+
+    /// The war paint applied to the weapon
+    pub fn paint_kit(&self) -> Option<u32> {
+        self.float_attribute(ATTRIBUTE_PAINT_KIT)
+            .map(|paint_kit| paint_kit as u32)
+    }
+
+    pub fn is_australium(&self) -> bool {
+        self.attributes.contains_key(&ATTRIBUTE_AUSTRALIUM)
+    }
+
+    pub fn is_festivized(&self) -> bool {
+        self.attributes.contains_key(&ATTRIBUTE_FESTIVIZED)
+    }
+
+    pub(crate) fn update(&mut self, entity: &PacketEntity, parser_state: &ParserState) {
+        for prop in entity.props(parser_state) {
+            match prop.identifier {
+                ITEM_DEFINITION_INDEX => {
+                    self.item_definition_index =
+                        i64::try_from(&prop.value).unwrap_or_default() as u16
+                }
+                ITEM_QUALITY => self.quality = i64::try_from(&prop.value).unwrap_or_default() as u8,
+                ITEM_LEVEL => self.level = i64::try_from(&prop.value).unwrap_or_default() as u8,
+                WEAPON_OWNER | OWNER_ENTITY => self.owner = handle_entity(&prop.value),
+                _ => {}
+            }
+        }
+
+        // all attributes share the same identifiers, which the deduplicated prop list can't represent,
+        // so the attribute props are tracked by their prop index instead
+        if entity.update_type == UpdateType::Enter {
+            self.attribute_props.clear();
+            for prop in entity.get_baseline_props(parser_state).iter() {
+                self.update_attribute_prop(prop);
+            }
+        }
+        for prop in entity.props.iter() {
+            self.update_attribute_prop(prop);
+        }
+
+        let mut attribute = None;
+        let mut attributes = BTreeMap::new();
+        for (identifier, value) in self.attribute_props.values() {
+            match *identifier {
+                ATTRIBUTE_INDEX => attribute = Some(*value as u16),
+                ATTRIBUTE_VALUE => {
+                    if let Some(attribute) = attribute.take() {
+                        if self
+                            .attribute_count
+                            .map(|count| attributes.len() < count)
+                            .unwrap_or(true)
+                        {
+                            attributes.insert(attribute, *value as u32);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.attributes = attributes;
+    }
+
+    fn update_attribute_prop(&mut self, prop: &SendProp) {
+        match prop.identifier {
+            ATTRIBUTE_INDEX | ATTRIBUTE_VALUE => {
+                self.attribute_props.insert(
+                    prop.index,
+                    (
+                        prop.identifier,
+                        i64::try_from(&prop.value).unwrap_or_default(),
+                    ),
+                );
+            }
+            ATTRIBUTE_COUNT => {
+                self.attribute_count = Some(i64::try_from(&prop.value).unwrap_or_default() as usize)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The loadout slot an item is equipped in
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadoutSlot {
+    Primary,
+    Secondary,
+    Melee,
+    Pda,
+    Pda2,
+    Building,
+}
+
+impl LoadoutSlot {
+    /// The slot of a weapon, from the item definition for the stock weapons and from the weapon class otherwise
+    pub fn from_item(item: &Item) -> Option<Self> {
+        Self::from_item_definition(item.item_definition_index)
+            .or_else(|| Self::from_weapon_class(item.class.as_str()))
+    }
+
+    fn from_item_definition(item_definition_index: u16) -> Option<Self> {
+        Some(match item_definition_index {
+            0..=8 => LoadoutSlot::Melee,
+            9 => LoadoutSlot::Primary, // engineer shotgun
+            10..=12 => LoadoutSlot::Secondary,
+            13..=15 | 17..=19 | 21 => LoadoutSlot::Primary,
+            16 | 20 | 22..=24 | 29 => LoadoutSlot::Secondary,
+            25 | 27 => LoadoutSlot::Pda,
+            26 | 30 => LoadoutSlot::Pda2,
+            28 | 735 | 736 => LoadoutSlot::Building,
+            _ => return None,
+        })
+    }
+
+    fn from_weapon_class(class: &str) -> Option<Self> {
+        Some(match class {
+            "CTFScatterGun"
+            | "CTFSodaPopper"
+            | "CTFPEPBrawlerBlaster"
+            | "CTFPistol_ScoutPrimary"
+            | "CTFRocketLauncher"
+            | "CTFRocketLauncher_DirectHit"
+            | "CTFRocketLauncher_AirStrike"
+            | "CTFParticleCannon"
+            | "CTFFlameThrower"
+            | "CTFWeaponFlameBall"
+            | "CTFGrenadeLauncher"
+            | "CTFCannon"
+            | "CTFMinigun"
+            | "CTFShotgun_Primary"
+            | "CTFShotgun_Revenge"
+            | "CTFShotgunBuildingRescue"
+            | "CTFDRGPomson"
+            | "CTFSyringeGun"
+            | "CTFCrossbow"
+            | "CTFSniperRifle"
+            | "CTFSniperRifleDecap"
+            | "CTFSniperRifleClassic"
+            | "CTFCompoundBow"
+            | "CTFParachute_Primary" => LoadoutSlot::Primary,
+            "CTFPistol"
+            | "CTFPistol_Scout"
+            | "CTFPistol_ScoutSecondary"
+            | "CTFShotgun"
+            | "CTFShotgun_Soldier"
+            | "CTFShotgun_HWG"
+            | "CTFShotgun_Pyro"
+            | "CTFSMG"
+            | "CTFChargedSMG"
+            | "CTFPipebombLauncher"
+            | "CTFMediGun"
+            | "CTFFlareGun"
+            | "CTFFlareGun_Revenge"
+            | "CTFRaygun"
+            | "CTFLaserPointer"
+            | "CTFMechanicalArm"
+            | "CTFJar"
+            | "CTFJarMilk"
+            | "CTFJarGas"
+            | "CTFLunchBox"
+            | "CTFLunchBox_Drink"
+            | "CTFCleaver"
+            | "CTFBuffItem"
+            | "CTFRocketPack"
+            | "CTFRevolver"
+            | "CTFParachute_Secondary" => LoadoutSlot::Secondary,
+            "CTFBat" | "CTFBat_Wood" | "CTFBat_Fish" | "CTFBat_Giftwrap" | "CTFShovel"
+            | "CTFFireAxe" | "CTFBreakableSign" | "CTFSlap" | "CTFBottle" | "CTFStickBomb"
+            | "CTFSword" | "CTFKatana" | "CTFFists" | "CTFWrench" | "CTFRobotArm"
+            | "CTFBonesaw" | "CTFClub" | "CTFKnife" => LoadoutSlot::Melee,
+            "CTFWeaponPDA_Engineer_Build" | "CTFWeaponPDA_Spy" => LoadoutSlot::Pda,
+            "CTFWeaponPDA_Engineer_Destroy" | "CTFWeaponInvis" => LoadoutSlot::Pda2,
+            "CTFWeaponBuilder" | "CTFWeaponSapper" => LoadoutSlot::Building,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct WeaponSwitch {
+    pub tick: DemoTick,
+    pub from: Option<u16>,
+    pub to: u16,
+}
+
+/// The loadout of a player during a single life
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Loadout {
+    pub start: DemoTick,
+    pub end: Option<DemoTick>,
+    pub class: Class,
+    pub weapons: BTreeMap<LoadoutSlot, Item>,
+    /// Weapons for which the slot couldn't be determined
+    pub other_weapons: Vec<Item>,
+    pub wearables: Vec<Item>,
+    pub switches: Vec<WeaponSwitch>,
+}
+
+impl Loadout {
+    /// The item definition index of the weapon that was active at the given tick
+    pub fn active_weapon_at(&self, tick: DemoTick) -> Option<u16> {
+        self.switches
+            .iter()
+            .rev()
+            .find(|switch| switch.tick <= tick)
+            .map(|switch| switch.to)
+    }
+
+    fn active_weapon(&self) -> Option<u16> {
+        self.switches.last().map(|switch| switch.to)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct LoadoutState {
+    pub players: BTreeMap<UserId, Vec<Loadout>>,
+}
+
+impl LoadoutState {
+    /// The loadout the player had at the given tick
+    pub fn loadout_at(&self, user_id: UserId, tick: DemoTick) -> Option<&Loadout> {
+        self.players.get(&user_id)?.iter().rev().find(|loadout| {
+            loadout.start <= tick && loadout.end.map(|end| end >= tick).unwrap_or(true)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct PlayerWeapons {
+    /// Weapons by their index in `m_hMyWeapons`
+    weapons: BTreeMap<u8, EntityId>,
+    active: Option<EntityId>,
+}
+
+/// Tracks the weapons and cosmetics of every player for every life
+#[derive(Default, Debug)]
+pub struct LoadoutAnalyser {
+    state: LoadoutState,
+    lookup: EntityLookup,
+    /// The index in `m_hMyWeapons` for the identifier of each entry
+    my_weapons: HashMap<SendPropIdentifier, u8>,
+    players: HashMap<EntityId, PlayerWeapons>,
+    items: HashMap<EntityId, Item>,
+    /// Players whose weapons or items changed since the loadouts were last updated
+    changed: BTreeSet<EntityId>,
+}
+
+impl MessageHandler for LoadoutAnalyser {
+    type Output = LoadoutState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(
+            message_type,
+            MessageType::GameEvent | MessageType::PacketEntities
+        )
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        match message {
+            Message::GameEvent(message) => match &message.event {
+                GameEvent::PlayerSpawn(event) => self.handle_spawn(event, tick),
+                GameEvent::PlayerDeath(event) => self.handle_death(event, tick),
+                _ => {}
+            },
+            Message::PacketEntities(message) => {
+                for entity in &message.entities {
+                    self.handle_entity(entity, parser_state)
+                }
+                self.update_loadouts(tick);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        _parser_state: &ParserState,
+    ) {
+        self.lookup.handle_string_entry(table, index, entry);
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        _parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _parser_state: &ParserState,
+    ) {
+        self.lookup.handle_data_tables(server_classes);
+        self.my_weapons = (0..MAX_WEAPONS)
+            .map(|index| {
+                (
+                    SendPropIdentifier::new("m_hMyWeapons", &format!("{:03}", index)),
+                    index,
+                )
+            })
+            .collect();
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for LoadoutAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl LoadoutAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn handle_spawn(&mut self, event: &PlayerSpawnEvent, tick: DemoTick) {
+        let loadouts = self
+            .state
+            .players
+            .entry(UserId::from(event.user_id))
+            .or_default();
+        end_loadout(loadouts, tick);
+        loadouts.push(Loadout {
+            start: tick,
+            class: Class::new(event.class),
+            ..Loadout::default()
+        });
+
+        let user_id = UserId::from(event.user_id);
+        let lookup = &self.lookup;
+        self.changed.extend(
+            self.players
+                .keys()
+                .filter(|entity| lookup.user_id(**entity) == Some(user_id)),
+        );
+    }
+
+    fn handle_death(&mut self, event: &PlayerDeathEvent, tick: DemoTick) {
+        if let Some(loadouts) = self.state.players.get_mut(&UserId::from(event.user_id)) {
+            end_loadout(loadouts, tick);
+        }
+    }
+
+    fn handle_entity(&mut self, entity: &PacketEntity, parser_state: &ParserState) {
+        let class_name = match self.lookup.class_name(entity) {
+            Some(class_name) => class_name,
+            None => return,
+        };
+
+        if entity.update_type == UpdateType::Delete {
+            if let Some(item) = self.items.remove(&entity.entity_index) {
+                self.item_changed(item.entity, item.owner);
+            }
+            self.players.remove(&entity.entity_index);
+            self.changed.remove(&entity.entity_index);
+            return;
+        }
+
+        if class_name.as_str() == "CTFPlayer" {
+            let player = self.players.entry(entity.entity_index).or_default();
+            for prop in entity.props(parser_state) {
+                if prop.identifier == ACTIVE_WEAPON {
+                    player.active = handle_entity(&prop.value);
+                    self.changed.insert(entity.entity_index);
+                } else if let Some(index) = self.my_weapons.get(&prop.identifier) {
+                    match handle_entity(&prop.value) {
+                        Some(weapon) => player.weapons.insert(*index, weapon),
+                        None => player.weapons.remove(index),
+                    };
+                    self.changed.insert(entity.entity_index);
+                }
+            }
+        } else if self.items.contains_key(&entity.entity_index)
+            || entity
+                .get_prop_by_identifier(&ITEM_DEFINITION_INDEX, parser_state)
+                .is_some()
+        {
+            let item = self
+                .items
+                .entry(entity.entity_index)
+                .or_insert_with(|| Item {
+                    entity: entity.entity_index,
+                    class: class_name.clone(),
+                    ..Item::default()
+                });
+            if entity.update_type == UpdateType::Enter && item.class != *class_name {
+                // the entity index has been reused for a different item
+                *item = Item {
+                    entity: entity.entity_index,
+                    class: class_name.clone(),
+                    ..Item::default()
+                };
+            }
+            let previous_owner = item.owner;
+            item.update(entity, parser_state);
+            let owner = item.owner;
+
+            if previous_owner != owner {
+                self.item_changed(entity.entity_index, previous_owner);
+            }
+            self.item_changed(entity.entity_index, owner);
+        }
+    }
+
+    /// Mark the owner of the item and any player holding it as weapon for updating
+    fn item_changed(&mut self, item: EntityId, owner: Option<EntityId>) {
+        self.changed.extend(owner);
+        self.changed.extend(
+            self.players
+                .iter()
+                .filter(|(_, player)| player.weapons.values().any(|weapon| *weapon == item))
+                .map(|(entity, _)| *entity),
+        );
+    }
+
+    fn update_loadouts(&mut self, tick: DemoTick) {
+        for entity in std::mem::take(&mut self.changed) {
+            let player = match self.players.get(&entity) {
+                Some(player) => player,
+                None => continue,
+            };
+            let loadout = match self
+                .lookup
+                .user_id(entity)
+                .and_then(|user_id| self.state.players.get_mut(&user_id))
+                .and_then(|loadouts| loadouts.last_mut())
+                .filter(|loadout| loadout.end.is_none())
+            {
+                Some(loadout) => loadout,
+                None => continue,
+            };
+
+            loadout.weapons.clear();
+            loadout.other_weapons.clear();
+            for weapon in player
+                .weapons
+                .values()
+                .filter_map(|weapon| self.items.get(weapon))
+            {
+                match LoadoutSlot::from_item(weapon) {
+                    Some(slot) => {
+                        loadout.weapons.insert(slot, weapon.clone());
+                    }
+                    None => loadout.other_weapons.push(weapon.clone()),
+                }
+            }
+
+            loadout.wearables = self
+                .items
+                .values()
+                .filter(|item| item.is_wearable() && item.owner == Some(entity))
+                .cloned()
+                .collect();
+            loadout.wearables.sort_by_key(|wearable| wearable.entity);
+
+            let active = player
+                .active
+                .and_then(|weapon| self.items.get(&weapon))
+                .map(|weapon| weapon.item_definition_index);
+            if let Some(active) = active {
+                if loadout.active_weapon() != Some(active) {
+                    loadout.switches.push(WeaponSwitch {
+                        tick,
+                        from: loadout.active_weapon(),
+                        to: active,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn end_loadout(loadouts: &mut [Loadout], tick: DemoTick) {
+    if let Some(loadout) = loadouts.last_mut() {
+        if loadout.end.is_none() {
+            loadout.end = Some(tick);
+        }
+    }
+}
+
+/// The entity index a networked entity handle points to
+fn handle_entity(value: &SendPropValue) -> Option<EntityId> {
+    EntityHandle::try_from(value).ok()?.entity()
+}
+
+#[test]
+fn test_item_attributes() {
+    use crate::demo::packet::datatable::ClassId;
+
+    let state = ParserState::new(24, |_| false, false);
+    let prop = |index: u32, identifier: SendPropIdentifier, value: i64| SendProp {
+        index,
+        identifier,
+        value: SendPropValue::Integer(value),
+    };
+    let entity = |props: Vec<SendProp>| PacketEntity {
+        server_class: ClassId::from(0),
+        entity_index: EntityId::from(100u32),
+        props,
+        in_pvs: true,
+        update_type: UpdateType::Preserve,
+        serial_number: 1,
+        delay: None,
+        delta: None,
+        baseline_index: 0,
+    };
+
+    let mut item = Item::default();
+    item.update(
+        &entity(vec![
+            prop(1, ITEM_DEFINITION_INDEX, 18),
+            prop(10, ATTRIBUTE_INDEX, ATTRIBUTE_KILLSTREAK_TIER as i64),
+            prop(11, ATTRIBUTE_VALUE, 1.0f32.to_bits() as i64),
+            prop(12, ATTRIBUTE_INDEX, ATTRIBUTE_PAINT_KIT as i64),
+            prop(13, ATTRIBUTE_VALUE, 200.0f32.to_bits() as i64),
+            prop(20, ATTRIBUTE_COUNT, 2),
+        ]),
+        &state,
+    );
+    assert_eq!(Some(1), item.killstreak_tier());
+    assert_eq!(Some(200), item.paint_kit());
+
+    // a delta that only changes the value of the first attribute
+    item.update(
+        &entity(vec![prop(11, ATTRIBUTE_VALUE, 3.0f32.to_bits() as i64)]),
+        &state,
+    );
+    assert_eq!(Some(3), item.killstreak_tier());
+    assert_eq!(Some(200), item.paint_kit());
+
+    item.update(&entity(vec![prop(20, ATTRIBUTE_COUNT, 1)]), &state);
+    assert_eq!(Some(3), item.killstreak_tier());
+    assert_eq!(None, item.paint_kit());
+}
+
+#[test]
+fn test_loadout_slot() {
+    let item = |item_definition_index: u16, class: &str| Item {
+        item_definition_index,
+        class: ServerClassName::from(class),
+        ..Item::default()
+    };
+    assert_eq!(
+        Some(LoadoutSlot::Primary),
+        LoadoutSlot::from_item(&item(18, "CTFRocketLauncher"))
+    );
+    assert_eq!(
+        Some(LoadoutSlot::Primary),
+        LoadoutSlot::from_item(&item(9, "CTFShotgun"))
+    );
+    assert_eq!(
+        Some(LoadoutSlot::Secondary),
+        LoadoutSlot::from_item(&item(1153, "CTFShotgun_Soldier"))
+    );
+    assert_eq!(
+        Some(LoadoutSlot::Melee),
+        LoadoutSlot::from_item(&item(5000, "CTFKatana"))
+    );
+    assert_eq!(None, LoadoutSlot::from_item(&item(5000, "CTFUnknown")));
+}
+
+#[test]
+fn test_loadout_weapons() {
+    use crate::demo::packet::datatable::{ClassId, SendTableName};
+
+    let state = ParserState::new(24, |_| false, false);
+    let mut analyser = LoadoutAnalyser::new();
+    analyser.handle_data_tables(
+        &[],
+        &[
+            ServerClass {
+                id: ClassId::from(0),
+                name: ServerClassName::from("CTFPlayer"),
+                data_table: SendTableName::from("DT_TFPlayer"),
+            },
+            ServerClass {
+                id: ClassId::from(1),
+                name: ServerClassName::from("CTFRocketLauncher"),
+                data_table: SendTableName::from("DT_TFRocketLauncher"),
+            },
+        ],
+        &state,
+    );
+    let entity = |class: u16, index: u32, props: Vec<SendProp>| PacketEntity {
+        server_class: ClassId::from(class),
+        entity_index: EntityId::from(index),
+        props,
+        in_pvs: true,
+        update_type: UpdateType::Preserve,
+        serial_number: 1,
+        delay: None,
+        delta: None,
+        baseline_index: 0,
+    };
+    let handle = |index: u32| {
+        SendPropValue::Integer(u32::from(EntityHandle::new(EntityId::from(index), 1)) as i64)
+    };
+
+    analyser.handle_entity(
+        &entity(
+            0,
+            1,
+            vec![SendProp {
+                index: 0,
+                identifier: SendPropIdentifier::new("m_hMyWeapons", "040"),
+                value: handle(100),
+            }],
+        ),
+        &state,
+    );
+    analyser.handle_entity(
+        &entity(
+            1,
+            100,
+            vec![SendProp {
+                index: 0,
+                identifier: ITEM_DEFINITION_INDEX,
+                value: SendPropValue::Integer(513),
+            }],
+        ),
+        &state,
+    );
+
+    let player = &analyser.players[&EntityId::from(1u32)];
+    assert_eq!(Some(&EntityId::from(100u32)), player.weapons.get(&40));
+    let weapon = &analyser.items[&EntityId::from(100u32)];
+    assert_eq!(Some(LoadoutSlot::Primary), LoadoutSlot::from_item(weapon));
+}
+
+#[test]
+fn test_loadout_wearables() {
+    use crate::demo::data::userinfo::{PlayerInfo, UserInfo};
+    use crate::demo::packet::datatable::{ClassId, SendTableName};
+
+    let state = ParserState::new(24, |_| false, false);
+    let mut analyser = LoadoutAnalyser::new();
+    analyser.handle_data_tables(
+        &[],
+        &[
+            ServerClass {
+                id: ClassId::from(0),
+                name: ServerClassName::from("CTFPlayer"),
+                data_table: SendTableName::from("DT_TFPlayer"),
+            },
+            ServerClass {
+                id: ClassId::from(1),
+                name: ServerClassName::from("CTFWearable"),
+                data_table: SendTableName::from("DT_TFWearable"),
+            },
+        ],
+        &state,
+    );
+    let info = UserInfo {
+        entity_id: EntityId::from(1u32),
+        player_info: PlayerInfo {
+            user_id: UserId::from(12u16),
+            steam_id: "[U:1:1]".into(),
+            ..PlayerInfo::default()
+        },
+    };
+    let entry = StringTableEntry {
+        text: Some("0".into()),
+        ..info.encode_to_string_table().unwrap()
+    };
+    analyser.handle_string_entry("userinfo", 0, &entry, &state);
+
+    let entity =
+        |class: u16, index: u32, update_type: UpdateType, props: Vec<SendProp>| PacketEntity {
+            server_class: ClassId::from(class),
+            entity_index: EntityId::from(index),
+            props,
+            in_pvs: true,
+            update_type,
+            serial_number: 1,
+            delay: None,
+            delta: None,
+            baseline_index: 0,
+        };
+    let wearable = |index: u32, owner: u32| {
+        entity(
+            1,
+            index,
+            UpdateType::Preserve,
+            vec![
+                SendProp {
+                    index: 0,
+                    identifier: ITEM_DEFINITION_INDEX,
+                    value: SendPropValue::Integer(index as i64),
+                },
+                SendProp {
+                    index: 1,
+                    identifier: OWNER_ENTITY,
+                    value: SendPropValue::Integer(u32::from(EntityHandle::new(
+                        EntityId::from(owner),
+                        1,
+                    )) as i64),
+                },
+            ],
+        )
+    };
+    let wearables = |analyser: &LoadoutAnalyser| -> Vec<u16> {
+        analyser.state.players[&UserId::from(12u16)][0]
+            .wearables
+            .iter()
+            .map(|wearable| wearable.item_definition_index)
+            .collect()
+    };
+
+    analyser.handle_entity(&entity(0, 1, UpdateType::Preserve, vec![]), &state);
+    analyser.handle_spawn(
+        &PlayerSpawnEvent {
+            user_id: 12,
+            team: 2,
+            class: 3,
+        },
+        DemoTick::from(1),
+    );
+    analyser.handle_entity(&wearable(100, 1), &state);
+    analyser.handle_entity(&wearable(101, 1), &state);
+    analyser.update_loadouts(DemoTick::from(1));
+    assert_eq!(vec![100, 101], wearables(&analyser));
+    assert!(analyser.changed.is_empty());
+
+    // the wearable moves to another player
+    analyser.handle_entity(&wearable(100, 2), &state);
+    analyser.update_loadouts(DemoTick::from(2));
+    assert_eq!(vec![101], wearables(&analyser));
+
+    analyser.handle_entity(&entity(1, 101, UpdateType::Delete, vec![]), &state);
+    analyser.update_loadouts(DemoTick::from(3));
+    assert!(wearables(&analyser).is_empty());
+}
@@ -12,13 +12,29 @@ pub use crate::demo::parser::handler::{DemoHandler, MessageHandler, NullHandler}
 pub use crate::demo::parser::state::ParserState;
 use crate::Stream;
 
+pub mod ammoanalyser;
 pub mod analyser;
+pub mod baselines;
+pub mod conditionanalyser;
+pub mod decalanalyser;
+pub mod engagementanalyser;
+pub mod entitydump;
+pub mod entitylookup;
+pub mod entityworld;
 pub mod error;
 pub mod gamestateanalyser;
 pub mod handler;
+pub mod lifecycle;
+pub mod loadoutanalyser;
 pub mod messagetypeanalyser;
 pub mod player_summary_analyzer;
+pub mod povanalyser;
+pub mod prophistory;
+pub mod soundanalyser;
+pub mod spyanalyser;
 pub mod state;
+pub mod stringtables;
+pub mod tempentityanalyser;
 
 pub use self::error::*;
 use crate::demo::parser::handler::BorrowMessageHandler;
@@ -87,6 +103,32 @@ impl<'a, A: MessageHandler> DemoParser<'a, A> {
         }
     }
 
+    /// Maintain the full state of all entities, available through [`ParserState::entity_world`]
+    pub fn with_entity_world(mut self) -> Self {
+        self.handler = self.handler.with_entity_world();
+        self
+    }
+
+    /// Keep the contents of all string tables with their update history, available through [`ParserState::string_table_contents`]
+    pub fn with_string_tables(mut self) -> Self {
+        self.handler = self.handler.with_string_tables();
+        self
+    }
+
+    /// Keep the names of all props from the data tables, available through [`ParserState::prop_name`]
+    pub fn with_prop_names(mut self) -> Self {
+        self.handler = self.handler.with_prop_names();
+        self
+    }
+
+    /// Fail parsing when a decoded prop value doesn't match its definition
+    ///
+    /// Catches corrupt data or the entity stream getting out of sync at the point it happens
+    pub fn with_strict_props(mut self) -> Self {
+        self.handler = self.handler.with_strict_props();
+        self
+    }
+
     pub fn parse(self) -> Result<(Header, A::Output)> {
         let (header, mut ticker) = self.ticker()?;
         while ticker.tick()? {
@@ -0,0 +1,417 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::message::MessagePacketMeta;
+use crate::demo::packet::usercmd::{Button, Buttons, ResolvedUserCmd};
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::sendprop::SendPropIdentifier;
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+const LOCAL_PITCH: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[0]");
+const LOCAL_YAW: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[1]");
+const NON_LOCAL_PITCH: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[0]");
+const NON_LOCAL_YAW: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[1]");
+
+/// Default angle difference in degrees above which two angle sources are considered different
+pub const DEFAULT_ANGLE_THRESHOLD: f32 = 1.0;
+
+/// Degrees of yaw and pitch per unit of mouse movement at a sensitivity of 1 (`m_yaw`/`m_pitch`)
+const MOUSE_DEGREES: f32 = 0.022;
+
+/// Pitch and yaw in degrees
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct Angles {
+    pub pitch: f32,
+    pub yaw: f32,
+}
+
+impl Angles {
+    pub fn new(pitch: f32, yaw: f32) -> Self {
+        Angles { pitch, yaw }
+    }
+
+    /// Difference between two angles, with the yaw difference wrapped to -180..180
+    pub fn diff(&self, other: &Angles) -> Angles {
+        Angles {
+            pitch: self.pitch - other.pitch,
+            yaw: wrap_angle(self.yaw - other.yaw),
+        }
+    }
+
+    /// Largest absolute component of the difference between two angles
+    pub fn deviation(&self, other: &Angles) -> f32 {
+        let diff = self.diff(other);
+        diff.pitch.abs().max(diff.yaw.abs())
+    }
+}
+
+fn wrap_angle(angle: f32) -> f32 {
+    let angle = angle % 360.0;
+    if angle > 180.0 {
+        angle - 360.0
+    } else if angle < -180.0 {
+        angle + 360.0
+    } else {
+        angle
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandSample {
+    pub command_number: u32,
+    pub angles: Angles,
+    pub buttons: Buttons,
+    pub mouse_dx: i16,
+    pub mouse_dy: i16,
+}
+
+/// All angle sources for the pov player at a single tick
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AngleSample {
+    pub tick: DemoTick,
+    /// The view angles of the client as stored in the packet metadata
+    pub view: Option<Angles>,
+    /// The angles from the user commands sent by the client during this tick
+    pub commands: Vec<CommandSample>,
+    /// The eye angles of the player as networked by the server
+    pub server: Option<Angles>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AngleSource {
+    View,
+    UserCmd,
+    Server,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AngleDiscrepancy {
+    pub tick: DemoTick,
+    pub command_number: u32,
+    pub source: AngleSource,
+    pub other: AngleSource,
+    pub diff: Angles,
+}
+
+/// A user command where only the attack tick differs from the view angles of the client
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SilentAim {
+    pub tick: DemoTick,
+    pub command_number: u32,
+    pub view: Angles,
+    pub command: Angles,
+    pub deviation: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MouseInconsistencyKind {
+    /// The angles changed without any mouse movement
+    NoMouseMovement,
+    /// The angles changed in the opposite direction of the mouse movement
+    OppositeDirection,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MouseInconsistency {
+    pub tick: DemoTick,
+    pub command_number: u32,
+    pub kind: MouseInconsistencyKind,
+    pub mouse_dx: i16,
+    pub mouse_dy: i16,
+    pub change: Angles,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct PovState {
+    pub local_entity: Option<EntityId>,
+    pub samples: Vec<AngleSample>,
+}
+
+impl PovState {
+    fn commands(&self) -> impl Iterator<Item = (&AngleSample, &CommandSample)> {
+        self.samples
+            .iter()
+            .flat_map(|sample| sample.commands.iter().map(move |cmd| (sample, cmd)))
+    }
+
+    /// Ticks where the user command angles differ from the view or server angles by more than `threshold` degrees
+    pub fn discrepancies(&self, threshold: f32) -> Vec<AngleDiscrepancy> {
+        let mut result = Vec::new();
+        for (sample, cmd) in self.commands() {
+            let others = [
+                (AngleSource::View, sample.view),
+                (AngleSource::Server, sample.server),
+            ];
+            for (other, angles) in others {
+                if let Some(angles) = angles {
+                    if cmd.angles.deviation(&angles) > threshold {
+                        result.push(AngleDiscrepancy {
+                            tick: sample.tick,
+                            command_number: cmd.command_number,
+                            source: AngleSource::UserCmd,
+                            other,
+                            diff: cmd.angles.diff(&angles),
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// User commands on attack ticks whose angles differ from the view angles of the client,
+    /// while the surrounding commands match the view angles.
+    pub fn silent_aim(&self, threshold: f32) -> Vec<SilentAim> {
+        let mut view = None;
+        let commands: Vec<_> = self
+            .samples
+            .iter()
+            .flat_map(|sample| {
+                view = sample.view.or(view);
+                let view = view;
+                sample
+                    .commands
+                    .iter()
+                    .map(move |cmd| (sample.tick, view, cmd))
+            })
+            .collect();
+        let deviation = |index: usize| -> Option<f32> {
+            let (_, view, cmd) = commands.get(index)?;
+            Some(cmd.angles.deviation(view.as_ref()?))
+        };
+
+        commands
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(index, (tick, view, cmd))| {
+                let view = (*view)?;
+                let deviation_here = deviation(index)?;
+                let matches_before = deviation(index - 1)? <= threshold;
+                let matches_after = deviation(index + 1)? <= threshold;
+                (cmd.buttons.contains(Button::Attack)
+                    && deviation_here > threshold
+                    && matches_before
+                    && matches_after)
+                    .then_some(SilentAim {
+                        tick: *tick,
+                        command_number: cmd.command_number,
+                        view,
+                        command: cmd.angles,
+                        deviation: deviation_here,
+                    })
+            })
+            .collect()
+    }
+
+    /// User commands where the change in angles doesn't match the mouse movement.
+    ///
+    /// Only changes larger than `threshold` degrees are considered.
+    pub fn mouse_inconsistencies(&self, threshold: f32) -> Vec<MouseInconsistency> {
+        let mut result = Vec::new();
+        let mut previous: Option<&CommandSample> = None;
+        for (sample, cmd) in self.commands() {
+            if let Some(previous) = previous {
+                let change = cmd.angles.diff(&previous.angles);
+                let kind = if cmd.mouse_dx == 0 && cmd.mouse_dy == 0 {
+                    (change.yaw.abs().max(change.pitch.abs()) > threshold)
+                        .then_some(MouseInconsistencyKind::NoMouseMovement)
+                } else {
+                    // moving the mouse right lowers the yaw, moving it down raises the pitch
+                    let yaw_opposite = change.yaw.abs() > threshold
+                        && cmd.mouse_dx != 0
+                        && change.yaw.signum() == (cmd.mouse_dx as f32).signum();
+                    let pitch_opposite = change.pitch.abs() > threshold
+                        && cmd.mouse_dy != 0
+                        && change.pitch.signum() != (cmd.mouse_dy as f32).signum();
+                    (yaw_opposite || pitch_opposite)
+                        .then_some(MouseInconsistencyKind::OppositeDirection)
+                };
+                if let Some(kind) = kind {
+                    result.push(MouseInconsistency {
+                        tick: sample.tick,
+                        command_number: cmd.command_number,
+                        kind,
+                        mouse_dx: cmd.mouse_dx,
+                        mouse_dy: cmd.mouse_dy,
+                        change,
+                    });
+                }
+            }
+            previous = Some(cmd);
+        }
+        result
+    }
+
+    /// Estimate the mouse sensitivity of the player from the yaw changes and horizontal mouse movement
+    pub fn estimated_sensitivity(&self) -> Option<f32> {
+        let mut previous: Option<&CommandSample> = None;
+        let mut ratios = Vec::new();
+        for (_, cmd) in self.commands() {
+            if let Some(previous) = previous {
+                let change = cmd.angles.diff(&previous.angles);
+                if cmd.mouse_dx != 0 && change.yaw != 0.0 {
+                    ratios.push(-change.yaw / (cmd.mouse_dx as f32 * MOUSE_DEGREES));
+                }
+            }
+            previous = Some(cmd);
+        }
+        ratios.sort_by(|a, b| a.total_cmp(b));
+        ratios.get(ratios.len() / 2).copied()
+    }
+}
+
+/// Align the client view angles, user command angles and server eye angles of the pov player in a demo
+#[derive(Default, Debug)]
+pub struct PovAnalyser {
+    state: PovState,
+    server: Option<Angles>,
+}
+
+impl PovAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn sample(&mut self, tick: DemoTick) -> &mut AngleSample {
+        let samples = &mut self.state.samples;
+        if samples.last().map(|sample| sample.tick) != Some(tick) {
+            // server angles are only sent when they change
+            samples.push(AngleSample {
+                tick,
+                view: None,
+                commands: Vec::new(),
+                server: self.server,
+            });
+        }
+        samples.last_mut().unwrap()
+    }
+}
+
+impl MessageHandler for PovAnalyser {
+    type Output = PovState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(
+            message_type,
+            MessageType::ServerInfo | MessageType::PacketEntities
+        )
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        match message {
+            Message::ServerInfo(message) => {
+                self.state.local_entity = Some(EntityId::from(message.player_slot as u32 + 1));
+            }
+            Message::PacketEntities(message) => {
+                let local_entity = match self.state.local_entity {
+                    Some(entity) => entity,
+                    None => return,
+                };
+                for entity in &message.entities {
+                    if entity.entity_index != local_entity {
+                        continue;
+                    }
+                    let mut server = self.server.unwrap_or_default();
+                    let mut updated = false;
+                    for prop in entity.props(parser_state) {
+                        let value = f32::try_from(&prop.value).unwrap_or_default();
+                        match prop.identifier {
+                            LOCAL_PITCH | NON_LOCAL_PITCH => server.pitch = value,
+                            LOCAL_YAW | NON_LOCAL_YAW => server.yaw = value,
+                            _ => continue,
+                        }
+                        updated = true;
+                    }
+                    if updated {
+                        self.server = Some(server);
+                        self.sample(tick).server = Some(server);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_packet_meta(
+        &mut self,
+        tick: DemoTick,
+        meta: &MessagePacketMeta,
+        _parser_state: &ParserState,
+    ) {
+        let angles = &meta.view_angles[0].angles;
+        self.sample(tick).view = Some(Angles::new(angles.x, angles.y));
+    }
+
+    fn handle_user_cmd(
+        &mut self,
+        tick: DemoTick,
+        cmd: &ResolvedUserCmd,
+        _parser_state: &ParserState,
+    ) {
+        self.sample(tick).commands.push(CommandSample {
+            command_number: cmd.command_number,
+            angles: Angles::new(cmd.view_angles[0], cmd.view_angles[1]),
+            buttons: cmd.buttons,
+            mouse_dx: cmd.mouse_dx,
+            mouse_dy: cmd.mouse_dy,
+        });
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for PovAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+#[test]
+fn test_silent_aim() {
+    fn sample(tick: u32, view: (f32, f32), cmd: (f32, f32), attack: bool) -> AngleSample {
+        AngleSample {
+            tick: tick.into(),
+            view: Some(Angles::new(view.0, view.1)),
+            commands: vec![CommandSample {
+                command_number: tick,
+                angles: Angles::new(cmd.0, cmd.1),
+                buttons: if attack {
+                    Button::Attack.into()
+                } else {
+                    Buttons::empty()
+                },
+                mouse_dx: 0,
+                mouse_dy: 0,
+            }],
+            server: None,
+        }
+    }
+
+    let state = PovState {
+        local_entity: Some(EntityId::from(1u32)),
+        samples: vec![
+            sample(1, (0.0, 179.0), (0.0, 179.0), false),
+            sample(2, (0.0, 179.0), (5.0, -170.0), true),
+            sample(3, (0.0, 179.0), (0.0, 179.0), false),
+            sample(4, (0.0, 179.0), (0.0, -179.5), true),
+        ],
+    };
+
+    let silent = state.silent_aim(DEFAULT_ANGLE_THRESHOLD);
+    assert_eq!(silent.len(), 1);
+    assert_eq!(silent[0].tick, DemoTick::from(2u32));
+    assert_eq!(silent[0].deviation, 11.0);
+
+    let mouse = state.mouse_inconsistencies(DEFAULT_ANGLE_THRESHOLD);
+    assert_eq!(mouse.len(), 3);
+    assert_eq!(mouse[0].kind, MouseInconsistencyKind::NoMouseMovement);
+}
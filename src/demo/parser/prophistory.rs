@@ -0,0 +1,725 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{
+    EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
+};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ClassId, ParseSendTable, ServerClass, ServerClassName};
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::parser::lifecycle::EntityInfo;
+use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
+use crate::demo::vector::{Vector, VectorXY};
+use crate::ParserState;
+use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Number of changes between two changes that store the full value
+const KEYFRAME_INTERVAL: usize = 64;
+
+/// A recorded change of a prop
+///
+/// Numeric values are stored as the difference to the previous value,
+/// with the full value stored for the first change and every [`KEYFRAME_INTERVAL`] changes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PropDelta {
+    Value(Box<SendPropValue>),
+    Integer(i64),
+    Float(f32),
+    Vector(Vector),
+    VectorXY(VectorXY),
+}
+
+impl PropDelta {
+    fn encode(previous: Option<&SendPropValue>, value: &SendPropValue, index: usize) -> Self {
+        previous
+            .filter(|_| !index.is_multiple_of(KEYFRAME_INTERVAL))
+            .and_then(|previous| Self::numeric_delta(previous, value))
+            .unwrap_or_else(|| PropDelta::Value(Box::new(value.clone())))
+    }
+
+    fn numeric_delta(previous: &SendPropValue, value: &SendPropValue) -> Option<Self> {
+        Some(match (previous, value) {
+            (SendPropValue::Integer(previous), SendPropValue::Integer(value)) => {
+                PropDelta::Integer(value.wrapping_sub(*previous))
+            }
+            (SendPropValue::Float(previous), SendPropValue::Float(value)) => {
+                PropDelta::Float(float_delta(*previous, *value)?)
+            }
+            (SendPropValue::Vector(previous), SendPropValue::Vector(value)) => {
+                PropDelta::Vector(Vector {
+                    x: float_delta(previous.x, value.x)?,
+                    y: float_delta(previous.y, value.y)?,
+                    z: float_delta(previous.z, value.z)?,
+                })
+            }
+            (SendPropValue::VectorXY(previous), SendPropValue::VectorXY(value)) => {
+                PropDelta::VectorXY(VectorXY {
+                    x: float_delta(previous.x, value.x)?,
+                    y: float_delta(previous.y, value.y)?,
+                })
+            }
+            _ => return None,
+        })
+    }
+
+    fn apply(&self, value: &mut Option<SendPropValue>) {
+        match (self, value.as_mut()) {
+            (PropDelta::Integer(delta), Some(SendPropValue::Integer(value))) => {
+                *value = value.wrapping_add(*delta)
+            }
+            (PropDelta::Float(delta), Some(SendPropValue::Float(value))) => *value += delta,
+            (PropDelta::Vector(delta), Some(SendPropValue::Vector(value))) => {
+                value.x += delta.x;
+                value.y += delta.y;
+                value.z += delta.z;
+            }
+            (PropDelta::VectorXY(delta), Some(SendPropValue::VectorXY(value))) => {
+                value.x += delta.x;
+                value.y += delta.y;
+            }
+            (PropDelta::Value(full), _) => *value = Some(full.as_ref().clone()),
+            // deltas are only encoded against a value of the same type
+            _ => {}
+        }
+    }
+}
+
+/// Exact comparison of two values, the `PartialEq` of [`SendPropValue`] allows for a difference in floats
+fn same_value(a: &SendPropValue, b: &SendPropValue) -> bool {
+    let same_float = |a: f32, b: f32| a.to_bits() == b.to_bits();
+    match (a, b) {
+        (SendPropValue::Integer(a), SendPropValue::Integer(b)) => a == b,
+        (SendPropValue::Float(a), SendPropValue::Float(b)) => same_float(*a, *b),
+        (SendPropValue::Vector(a), SendPropValue::Vector(b)) => {
+            same_float(a.x, b.x) && same_float(a.y, b.y) && same_float(a.z, b.z)
+        }
+        (SendPropValue::VectorXY(a), SendPropValue::VectorXY(b)) => {
+            same_float(a.x, b.x) && same_float(a.y, b.y)
+        }
+        (SendPropValue::String(a), SendPropValue::String(b)) => a == b,
+        (SendPropValue::Array(a), SendPropValue::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_value(a, b))
+        }
+        _ => false,
+    }
+}
+
+/// The difference between two floats, if the value can be restored exactly from it
+fn float_delta(previous: f32, value: f32) -> Option<f32> {
+    let delta = value - previous;
+    ((previous + delta).to_bits() == value.to_bits()).then_some(delta)
+}
+
+/// The values of a single prop over time
+///
+/// Only the changes are stored, a value is valid until the next change.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PropHistory {
+    changes: Vec<(DemoTick, PropDelta)>,
+    /// The latest value, to detect unchanged values without decoding the changes
+    #[serde(skip)]
+    last: Option<SendPropValue>,
+}
+
+impl PartialEq for PropHistory {
+    fn eq(&self, other: &Self) -> bool {
+        self.changes == other.changes
+    }
+}
+
+impl PropHistory {
+    /// Record the value of the prop at a tick, values are expected to be recorded in order
+    ///
+    /// Returns `false` if the value is unchanged and was not stored
+    pub fn record(&mut self, tick: DemoTick, value: SendPropValue) -> bool {
+        if self.last.is_none() && !self.changes.is_empty() {
+            self.last = self.value_at_index(self.changes.len() - 1);
+        }
+        if matches!(&self.last, Some(last) if same_value(last, &value)) {
+            return false;
+        }
+        match self.changes.last() {
+            // multiple updates in the same tick, only the last one is relevant
+            Some((last_tick, _)) if *last_tick == tick => {
+                let index = self.changes.len() - 1;
+                let previous = index
+                    .checked_sub(1)
+                    .and_then(|index| self.value_at_index(index));
+                if matches!(&previous, Some(previous) if same_value(previous, &value)) {
+                    // changed back to the value before the tick
+                    self.changes.pop();
+                } else {
+                    self.changes[index].1 = PropDelta::encode(previous.as_ref(), &value, index);
+                }
+            }
+            _ => {
+                let delta = PropDelta::encode(self.last.as_ref(), &value, self.changes.len());
+                self.changes.push((tick, delta));
+            }
+        }
+        self.last = Some(value);
+        true
+    }
+
+    /// The value of the prop at the tick, `None` if the prop wasn't set yet
+    pub fn value_at(&self, tick: DemoTick) -> Option<SendPropValue> {
+        let index = self
+            .changes
+            .partition_point(|(change_tick, _)| *change_tick <= tick);
+        self.value_at_index(index.checked_sub(1)?)
+    }
+
+    /// All changes with `start <= tick <= end`
+    pub fn changes_between(
+        &self,
+        start: DemoTick,
+        end: DemoTick,
+    ) -> impl Iterator<Item = (DemoTick, SendPropValue)> + '_ {
+        let from = self.changes.partition_point(|(tick, _)| *tick < start);
+        let to = self.changes.partition_point(|(tick, _)| *tick <= end);
+        self.values_from(from).take(to.saturating_sub(from))
+    }
+
+    /// All changes with their decoded value
+    pub fn changes(&self) -> impl Iterator<Item = (DemoTick, SendPropValue)> + '_ {
+        self.values_from(0)
+    }
+
+    /// The changes as stored, with numeric values encoded as the difference to the previous value
+    pub fn deltas(&self) -> &[(DemoTick, PropDelta)] {
+        &self.changes
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    fn value_at_index(&self, index: usize) -> Option<SendPropValue> {
+        self.values_from(index).next().map(|(_, value)| value)
+    }
+
+    /// Decode the changes starting at the index, starting from the keyframe before it
+    fn values_from(&self, index: usize) -> impl Iterator<Item = (DemoTick, SendPropValue)> + '_ {
+        let keyframe = index - index % KEYFRAME_INTERVAL;
+        self.changes
+            .get(keyframe..)
+            .unwrap_or_default()
+            .iter()
+            .scan(None, |value, (tick, delta)| {
+                delta.apply(value);
+                Some(value.clone().map(|value| (*tick, value)))
+            })
+            .flatten()
+            .skip(index - keyframe)
+    }
+}
+
+/// The recorded props of a single entity, from the tick it was created until it was deleted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntityLifetime {
+    pub serial_number: u32,
+    pub server_class: ClassId,
+    pub created: DemoTick,
+    /// The tick the entity was deleted or replaced, `None` if it still existed at the end of the recording
+    pub deleted: Option<DemoTick>,
+    pub props: FnvHashMap<SendPropIdentifier, PropHistory>,
+}
+
+impl EntityLifetime {
+    /// Whether the entity existed at the tick
+    pub fn contains(&self, tick: DemoTick) -> bool {
+        self.created <= tick && !matches!(self.deleted, Some(deleted) if deleted <= tick)
+    }
+
+    pub fn history(&self, prop: SendPropIdentifier) -> Option<&PropHistory> {
+        self.props.get(&prop)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PropChange {
+    pub tick: DemoTick,
+    pub entity: EntityId,
+    pub serial_number: u32,
+    pub prop: SendPropIdentifier,
+    pub value: SendPropValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct PropHistoryState {
+    /// Entity indexes are reused, every entity that used the index gets its own lifetime
+    pub entities: BTreeMap<EntityId, Vec<EntityLifetime>>,
+}
+
+impl PropHistoryState {
+    /// All entities that used the entity index, in the order they were created
+    pub fn lifetimes(&self, entity: EntityId) -> &[EntityLifetime] {
+        self.entities
+            .get(&entity)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The entity that used the entity index at the tick
+    pub fn lifetime_at(&self, entity: EntityId, tick: DemoTick) -> Option<&EntityLifetime> {
+        self.lifetimes(entity)
+            .iter()
+            .rev()
+            .find(|lifetime| lifetime.contains(tick))
+    }
+
+    /// The value of a prop for an entity at the tick, `None` if no entity used the index at that tick
+    pub fn value_at(
+        &self,
+        entity: EntityId,
+        prop: SendPropIdentifier,
+        tick: DemoTick,
+    ) -> Option<SendPropValue> {
+        self.lifetime_at(entity, tick)?
+            .history(prop)?
+            .value_at(tick)
+    }
+
+    /// All recorded changes with `start <= tick <= end`, ordered by tick
+    pub fn changes_between(&self, start: DemoTick, end: DemoTick) -> Vec<PropChange> {
+        let mut changes: Vec<PropChange> = self
+            .entities
+            .iter()
+            .flat_map(|(entity, lifetimes)| {
+                lifetimes.iter().flat_map(move |lifetime| {
+                    lifetime.props.iter().flat_map(move |(prop, history)| {
+                        history
+                            .changes_between(start, end)
+                            .map(move |(tick, value)| PropChange {
+                                tick,
+                                entity: *entity,
+                                serial_number: lifetime.serial_number,
+                                prop: *prop,
+                                value,
+                            })
+                    })
+                })
+            })
+            .collect();
+        changes.sort_by_key(|change| (change.tick, change.entity, change.prop));
+        changes
+    }
+
+    /// The lifetime to record the props of an entity update into, starting a new one if the index was reused
+    fn lifetime_for(&mut self, entity: &PacketEntity, tick: DemoTick) -> &mut EntityLifetime {
+        let lifetimes = self.entities.entry(entity.entity_index).or_default();
+        let reused = match lifetimes.last() {
+            Some(last) if last.deleted.is_none() => {
+                // only entering entities contain the serial number
+                entity.update_type == UpdateType::Enter
+                    && (last.serial_number != entity.serial_number
+                        || last.server_class != entity.server_class)
+            }
+            _ => true,
+        };
+        if reused {
+            if let Some(last) = lifetimes.last_mut() {
+                last.deleted.get_or_insert(tick);
+            }
+            lifetimes.push(EntityLifetime {
+                serial_number: entity.serial_number,
+                server_class: entity.server_class,
+                created: tick,
+                deleted: None,
+                props: FnvHashMap::default(),
+            });
+        }
+        lifetimes.last_mut().unwrap()
+    }
+
+    fn end_lifetime(&mut self, entity: EntityId, tick: DemoTick) {
+        if let Some(last) = self
+            .entities
+            .get_mut(&entity)
+            .and_then(|lifetimes| lifetimes.last_mut())
+        {
+            last.deleted.get_or_insert(tick);
+        }
+    }
+}
+
+/// Record the values of a set of props over the course of the demo
+///
+/// The history is kept per entity lifetime, so the values of an entity are never mixed with the
+/// values of an earlier entity that used the same index.
+///
+/// ```no_run
+/// use tf_demo_parser::demo::parser::prophistory::PropHistoryRecorder;
+/// use tf_demo_parser::demo::sendprop::SendPropIdentifier;
+///
+/// let recorder = PropHistoryRecorder::new()
+///     .with_prop("CTFPlayer", SendPropIdentifier::new("DT_BasePlayer", "m_iHealth"));
+/// ```
+#[derive(Default, Debug)]
+pub struct PropHistoryRecorder {
+    props: Vec<(ServerClassName, SendPropIdentifier)>,
+    tracked: FnvHashMap<ClassId, Vec<SendPropIdentifier>>,
+    state: PropHistoryState,
+}
+
+impl MessageHandler for PropHistoryRecorder {
+    type Output = PropHistoryState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        if let Message::PacketEntities(message) = message {
+            self.handle_packet_entities(message, tick, parser_state)
+        }
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        _parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _parser_state: &ParserState,
+    ) {
+        self.tracked = server_classes
+            .iter()
+            .filter_map(|class| {
+                let props: Vec<_> = self
+                    .props
+                    .iter()
+                    .filter(|(class_name, _)| *class_name == class.name)
+                    .map(|(_, prop)| *prop)
+                    .collect();
+                (!props.is_empty()).then_some((class.id, props))
+            })
+            .collect();
+    }
+
+    /// Also catches the entities that are dropped by a full update
+    fn on_entity_deleted(
+        &mut self,
+        entity: &EntityInfo,
+        tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        self.state.end_lifetime(entity.id, tick);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for PropHistoryRecorder {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl PropHistoryRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the prop for all entities of the server class
+    pub fn with_prop<S: Into<ServerClassName>>(
+        mut self,
+        class: S,
+        prop: SendPropIdentifier,
+    ) -> Self {
+        self.props.push((class.into(), prop));
+        self
+    }
+
+    fn handle_packet_entities(
+        &mut self,
+        message: &PacketEntitiesMessage,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        for removed in &message.removed_entities {
+            self.state.end_lifetime(*removed, tick);
+        }
+        for entity in &message.entities {
+            if entity.update_type == UpdateType::Delete {
+                self.state.end_lifetime(entity.entity_index, tick);
+                continue;
+            }
+            let tracked = match self.tracked.get(&entity.server_class) {
+                Some(tracked) => tracked,
+                None => {
+                    // the index of a tracked entity can be reused by an untracked class
+                    if entity.update_type == UpdateType::Enter {
+                        self.state.end_lifetime(entity.entity_index, tick);
+                    }
+                    continue;
+                }
+            };
+            let lifetime = self.state.lifetime_for(entity, tick);
+            for prop in entity.props(parser_state) {
+                if tracked.contains(&prop.identifier) {
+                    lifetime
+                        .props
+                        .entry(prop.identifier)
+                        .or_default()
+                        .record(tick, prop.value);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_prop_history() {
+    let mut history = PropHistory::default();
+    assert!(history.record(DemoTick::from(10), SendPropValue::Integer(1)));
+    assert!(!history.record(DemoTick::from(12), SendPropValue::Integer(1)));
+    assert!(history.record(DemoTick::from(15), SendPropValue::Integer(2)));
+    assert!(history.record(DemoTick::from(15), SendPropValue::Integer(3)));
+    assert!(history.record(DemoTick::from(20), SendPropValue::Integer(4)));
+    assert_eq!(3, history.len());
+    assert_eq!(
+        &[
+            (
+                DemoTick::from(10),
+                PropDelta::Value(Box::new(SendPropValue::Integer(1)))
+            ),
+            (DemoTick::from(15), PropDelta::Integer(2)),
+            (DemoTick::from(20), PropDelta::Integer(1)),
+        ],
+        history.deltas()
+    );
+
+    assert_eq!(None, history.value_at(DemoTick::from(9)));
+    assert_eq!(
+        Some(SendPropValue::Integer(1)),
+        history.value_at(DemoTick::from(10))
+    );
+    assert_eq!(
+        Some(SendPropValue::Integer(1)),
+        history.value_at(DemoTick::from(14))
+    );
+    assert_eq!(
+        Some(SendPropValue::Integer(3)),
+        history.value_at(DemoTick::from(19))
+    );
+    assert_eq!(
+        Some(SendPropValue::Integer(4)),
+        history.value_at(DemoTick::from(100))
+    );
+
+    assert_eq!(
+        vec![
+            (DemoTick::from(15), SendPropValue::Integer(3)),
+            (DemoTick::from(20), SendPropValue::Integer(4))
+        ],
+        history
+            .changes_between(DemoTick::from(11), DemoTick::from(20))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        0,
+        history
+            .changes_between(DemoTick::from(16), DemoTick::from(19))
+            .count()
+    );
+    assert_eq!(
+        0,
+        history
+            .changes_between(DemoTick::from(20), DemoTick::from(10))
+            .count()
+    );
+
+    // a value that changes back within a tick doesn't leave a change
+    let mut history = PropHistory::default();
+    assert!(history.record(DemoTick::from(10), SendPropValue::Integer(1)));
+    assert!(history.record(DemoTick::from(15), SendPropValue::Integer(2)));
+    assert!(history.record(DemoTick::from(15), SendPropValue::Integer(1)));
+    assert_eq!(1, history.len());
+    assert!(!history.record(DemoTick::from(16), SendPropValue::Integer(1)));
+    assert!(history.record(DemoTick::from(16), SendPropValue::Integer(5)));
+    assert_eq!(
+        Some(SendPropValue::Integer(5)),
+        history.value_at(DemoTick::from(16))
+    );
+}
+
+#[test]
+fn test_prop_history_delta_roundtrip() {
+    let roundtrip = |values: Vec<SendPropValue>| {
+        let mut history = PropHistory::default();
+        for (tick, value) in values.iter().enumerate() {
+            assert!(history.record(DemoTick::from(tick as u32), value.clone()));
+        }
+        // more than one keyframe
+        assert!(history.len() > KEYFRAME_INTERVAL * 2);
+        for (tick, value) in values.iter().enumerate() {
+            let restored = history.value_at(DemoTick::from(tick as u32)).unwrap();
+            assert!(same_value(value, &restored), "{} != {}", value, restored);
+        }
+        assert!(history
+            .changes()
+            .zip(&values)
+            .all(|((_, restored), value)| same_value(&restored, value)));
+    };
+
+    // floats of very different magnitudes can't always be restored from their difference
+    roundtrip(
+        (1..200)
+            .map(|i| match i % 3 {
+                0 => SendPropValue::Float(i as f32 * 0.1),
+                1 => SendPropValue::Float(f32::MAX / i as f32),
+                _ => SendPropValue::Float(1e-30 * i as f32),
+            })
+            .collect(),
+    );
+    roundtrip(
+        (1..200)
+            .map(|i| {
+                SendPropValue::Vector(Vector {
+                    x: i as f32 * 1.7,
+                    y: -(i as f32) / 3.0,
+                    z: 1e-20 * i as f32,
+                })
+            })
+            .collect(),
+    );
+}
+
+#[test]
+fn test_prop_history_recorder() {
+    use crate::demo::packet::datatable::{SendTable, SendTableName};
+    use crate::demo::sendprop::SendProp;
+
+    const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+    const ARMOR: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_ArmorValue");
+
+    let server_classes = vec![
+        ServerClass {
+            id: ClassId::from(0),
+            name: ServerClassName::from("CWorld"),
+            data_table: SendTableName::from("DT_World"),
+        },
+        ServerClass {
+            id: ClassId::from(1),
+            name: ServerClassName::from("CTFPlayer"),
+            data_table: SendTableName::from("DT_TFPlayer"),
+        },
+    ];
+    let mut state = ParserState::new(24, |_| false, false);
+    // entering entities are merged with their baseline from the send table
+    for class in &server_classes {
+        state.send_tables.push(SendTable {
+            name: class.data_table.clone(),
+            needs_decoder: false,
+            flattened_props: Vec::new(),
+        });
+    }
+
+    let mut recorder = PropHistoryRecorder::new().with_prop("CTFPlayer", HEALTH);
+    recorder.handle_data_tables(&[], &server_classes, &state);
+
+    let entity =
+        |class: u16, update_type: UpdateType, serial_number: u32, health: i64| PacketEntity {
+            server_class: ClassId::from(class),
+            entity_index: EntityId::from(class as u32),
+            props: vec![
+                SendProp {
+                    index: 0,
+                    identifier: HEALTH,
+                    value: SendPropValue::Integer(health),
+                },
+                SendProp {
+                    index: 1,
+                    identifier: ARMOR,
+                    value: SendPropValue::Integer(0),
+                },
+            ],
+            in_pvs: true,
+            update_type,
+            serial_number,
+            delay: None,
+            delta: None,
+            baseline_index: 0,
+        };
+    let message = |entities: Vec<PacketEntity>, removed_entities: Vec<EntityId>| {
+        Message::PacketEntities(PacketEntitiesMessage {
+            entities,
+            removed_entities,
+            max_entries: 0,
+            delta: None,
+            base_line: 0,
+            updated_base_line: false,
+        })
+    };
+
+    recorder.handle_message(
+        &message(vec![entity(1, UpdateType::Preserve, 1, 125)], vec![]),
+        DemoTick::from(1),
+        &state,
+    );
+    recorder.handle_message(
+        &message(vec![entity(0, UpdateType::Preserve, 1, 125)], vec![]),
+        DemoTick::from(1),
+        &state,
+    );
+    recorder.handle_message(
+        &message(vec![entity(1, UpdateType::Preserve, 1, 100)], vec![]),
+        DemoTick::from(5),
+        &state,
+    );
+    // the index is reused by a new player
+    recorder.handle_message(
+        &message(vec![entity(1, UpdateType::Enter, 2, 150)], vec![]),
+        DemoTick::from(8),
+        &state,
+    );
+    recorder.handle_message(
+        &message(vec![], vec![EntityId::from(1u32)]),
+        DemoTick::from(12),
+        &state,
+    );
+
+    let output = recorder.into_output(&state);
+    assert_eq!(1, output.entities.len());
+    let player = EntityId::from(1u32);
+    assert_eq!(2, output.lifetimes(player).len());
+    assert_eq!(None, output.lifetimes(player)[0].history(ARMOR));
+    assert_eq!(
+        Some(SendPropValue::Integer(125)),
+        output.value_at(player, HEALTH, DemoTick::from(4))
+    );
+    assert_eq!(
+        Some(SendPropValue::Integer(100)),
+        output.value_at(player, HEALTH, DemoTick::from(7))
+    );
+    assert_eq!(
+        Some(SendPropValue::Integer(150)),
+        output.value_at(player, HEALTH, DemoTick::from(8))
+    );
+    assert_eq!(None, output.value_at(player, HEALTH, DemoTick::from(12)));
+    assert_eq!(
+        vec![
+            PropChange {
+                tick: DemoTick::from(5),
+                entity: player,
+                serial_number: 1,
+                prop: HEALTH,
+                value: SendPropValue::Integer(100)
+            },
+            PropChange {
+                tick: DemoTick::from(8),
+                entity: player,
+                serial_number: 2,
+                prop: HEALTH,
+                value: SendPropValue::Integer(150)
+            }
+        ],
+        output.changes_between(DemoTick::from(2), DemoTick::from(10))
+    );
+}
@@ -0,0 +1,131 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::message::voice::{SoundEvent, SoundFlag};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "trace")]
+use tracing::warn;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sound {
+    pub tick: DemoTick,
+    /// Name of the sound from the `soundprecache` table
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub event: SoundEvent,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SoundState {
+    pub sounds: Vec<Sound>,
+    /// Number of sound messages that couldn't be decoded, the sounds from these messages are missing
+    pub decode_errors: usize,
+}
+
+impl SoundState {
+    /// All sounds emitted by an entity
+    pub fn sounds_for(&self, entity: EntityId) -> impl Iterator<Item = &Sound> {
+        self.sounds
+            .iter()
+            .filter(move |sound| sound.event.entity == entity)
+    }
+
+    /// All sounds with a name containing the pattern, e.g. `footstep` or `reload`
+    pub fn matching<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = &'a Sound> {
+        self.sounds.iter().filter(move |sound| {
+            sound
+                .name
+                .as_deref()
+                .map(|name| name.contains(pattern))
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Collect all sounds played during the demo
+#[derive(Default, Debug)]
+pub struct SoundAnalyser {
+    state: SoundState,
+    sound_names: Vec<Option<String>>,
+    include_stop: bool,
+}
+
+impl MessageHandler for SoundAnalyser {
+    type Output = SoundState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::ParseSounds)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        if let Message::ParseSounds(message) = message {
+            // a malformed sound message only affects the sounds from that message
+            let sounds = match message.sounds(parser_state.protocol_version) {
+                Ok(sounds) => sounds,
+                Err(_e) => {
+                    #[cfg(feature = "trace")]
+                    warn!(
+                        tick = display(tick),
+                        error = display(&_e),
+                        "failed to decode sounds"
+                    );
+                    self.state.decode_errors += 1;
+                    return;
+                }
+            };
+            for event in sounds {
+                if !self.include_stop && event.flags.contains(SoundFlag::Stop) {
+                    continue;
+                }
+                let name = self
+                    .sound_names
+                    .get(event.sound_index as usize)
+                    .cloned()
+                    .flatten();
+                self.state.sounds.push(Sound { tick, name, event });
+            }
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        _parser_state: &ParserState,
+    ) {
+        if table == "soundprecache" {
+            if self.sound_names.len() <= index {
+                self.sound_names.resize(index + 1, None);
+            }
+            self.sound_names[index] = entry.text.as_ref().map(|text| text.to_string());
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for SoundAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl SoundAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also collect the events that stop a previously playing sound
+    pub fn with_stop(self) -> Self {
+        SoundAnalyser {
+            include_stop: true,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,523 @@
+use crate::demo::data::{DemoTick, PlayerCondition, PlayerConditions};
+use crate::demo::gameevent_gen::{
+    ObjectDestroyedEvent, ObjectRemovedEvent, PlayerDeathEvent, PlayerSappedObjectEvent,
+    PlayerSpawnEvent,
+};
+use crate::demo::gamevent::GameEvent;
+use crate::demo::message::packetentities::{EntityHandle, EntityId, PacketEntity, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::packet::stringtable::StringTableEntry;
+pub use crate::demo::parser::analyser::{Class, Team, UserId};
+use crate::demo::parser::entitylookup::EntityLookup;
+use crate::demo::parser::gamestateanalyser::BuildingClass;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::sendprop::SendPropIdentifier;
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+
+/// `custom_kill` value of a player death caused by a backstab
+pub const CUSTOM_KILL_BACKSTAB: u16 = 2;
+
+/// `object_type` of a sapper in the object game events
+const OBJECT_SAPPER: u16 = 3;
+
+const DISGUISE_TEAM: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseTeam");
+const DISGUISE_CLASS: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseClass");
+const CLOAK_METER: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFPlayerShared", "m_flCloakMeter");
+const BUILT_ON_ENTITY: SendPropIdentifier =
+    SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity");
+
+/// The current disguise and cloak status of a player
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct SpyStatus {
+    pub disguised: bool,
+    pub cloaked: bool,
+    pub disguise_team: Team,
+    pub disguise_class: Class,
+    pub cloak_meter: f32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Disguise {
+    pub tick: DemoTick,
+    pub team: Team,
+    pub class: Class,
+}
+
+/// A period during which the spy was disguised or cloaked, `end` is `None` if it was still ongoing at the end of the demo
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Period {
+    pub start: DemoTick,
+    pub end: Option<DemoTick>,
+}
+
+impl Period {
+    /// Length of the period in ticks, ongoing periods are measured up to `now`
+    pub fn duration(&self, now: DemoTick) -> u32 {
+        u32::from(self.end.unwrap_or(now)).saturating_sub(u32::from(self.start))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct SpyLife {
+    pub start: DemoTick,
+    pub end: Option<DemoTick>,
+    pub backstabs: u32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Backstab {
+    pub tick: DemoTick,
+    pub victim: UserId,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SapOutcome {
+    /// The building was still sapped at the end of the demo
+    #[default]
+    Pending,
+    /// The building was destroyed while sapped
+    BuildingDestroyed { tick: DemoTick, by: Option<UserId> },
+    /// The sapper was removed before it destroyed the building
+    SapperRemoved { tick: DemoTick, by: Option<UserId> },
+    /// The building was picked up or destroyed by its owner while sapped
+    BuildingRemoved { tick: DemoTick },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Sap {
+    pub tick: DemoTick,
+    pub owner: UserId,
+    pub object: Option<BuildingClass>,
+    pub sapper: EntityId,
+    /// The sapped building, `None` until the sapper entity has been seen
+    pub building: Option<EntityId>,
+    pub outcome: SapOutcome,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SpyStats {
+    pub status: SpyStatus,
+    pub lives: Vec<SpyLife>,
+    pub disguises: Vec<Disguise>,
+    pub disguised: Vec<Period>,
+    pub cloaked: Vec<Period>,
+    pub backstabs: Vec<Backstab>,
+    pub saps: Vec<Sap>,
+}
+
+impl SpyStats {
+    /// Total number of ticks spent disguised
+    pub fn time_disguised(&self, now: DemoTick) -> u32 {
+        self.disguised
+            .iter()
+            .map(|period| period.duration(now))
+            .sum()
+    }
+
+    /// Total number of ticks spent cloaked
+    pub fn time_cloaked(&self, now: DemoTick) -> u32 {
+        self.cloaked.iter().map(|period| period.duration(now)).sum()
+    }
+
+    /// Average number of backstabs per life as spy
+    pub fn stabs_per_life(&self) -> f32 {
+        if self.lives.is_empty() {
+            self.backstabs.len() as f32
+        } else {
+            self.backstabs.len() as f32 / self.lives.len() as f32
+        }
+    }
+
+    fn set_status(&mut self, status: SpyStatus, tick: DemoTick) {
+        let old = self.status;
+        self.status = status;
+
+        if status.disguised && !old.disguised {
+            self.disguised.push(Period {
+                start: tick,
+                end: None,
+            });
+        }
+        if !status.disguised && old.disguised {
+            if let Some(period) = self.disguised.last_mut() {
+                period.end = Some(tick);
+            }
+        }
+        if status.disguised
+            && (!old.disguised
+                || status.disguise_team != old.disguise_team
+                || status.disguise_class != old.disguise_class)
+        {
+            self.disguises.push(Disguise {
+                tick,
+                team: status.disguise_team,
+                class: status.disguise_class,
+            });
+        }
+
+        if status.cloaked && !old.cloaked {
+            self.cloaked.push(Period {
+                start: tick,
+                end: None,
+            });
+        }
+        if !status.cloaked && old.cloaked {
+            if let Some(period) = self.cloaked.last_mut() {
+                period.end = Some(tick);
+            }
+        }
+    }
+
+    fn end_life(&mut self, tick: DemoTick) {
+        if let Some(life) = self.lives.last_mut() {
+            if life.end.is_none() {
+                life.end = Some(tick);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SpyState {
+    pub spies: BTreeMap<UserId, SpyStats>,
+    pub tick: DemoTick,
+}
+
+/// Tracks disguises, cloak and invisibility of every spy and combines them with backstabs and sapper usage
+#[derive(Default, Debug)]
+pub struct SpyAnalyser {
+    state: SpyState,
+    lookup: EntityLookup,
+    players: HashMap<EntityId, (SpyStatus, PlayerConditions)>,
+    /// The building each sapper is placed on
+    sappers: HashMap<EntityId, EntityId>,
+}
+
+impl MessageHandler for SpyAnalyser {
+    type Output = SpyState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(
+            message_type,
+            MessageType::GameEvent | MessageType::PacketEntities
+        )
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        self.state.tick = tick;
+        match message {
+            Message::GameEvent(message) => self.handle_event(&message.event, tick),
+            Message::PacketEntities(message) => {
+                for entity in &message.entities {
+                    self.handle_entity(entity, tick, parser_state)
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        _parser_state: &ParserState,
+    ) {
+        self.lookup.handle_string_entry(table, index, entry);
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        _parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _parser_state: &ParserState,
+    ) {
+        self.lookup.handle_data_tables(server_classes);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for SpyAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl SpyAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn handle_event(&mut self, event: &GameEvent, tick: DemoTick) {
+        match event {
+            GameEvent::PlayerSpawn(event) => self.handle_spawn(event, tick),
+            GameEvent::PlayerDeath(event) => self.handle_death(event, tick),
+            GameEvent::PlayerSappedObject(event) => self.handle_sap(event, tick),
+            GameEvent::ObjectDestroyed(event) => self.handle_object_destroyed(event, tick),
+            GameEvent::ObjectRemoved(event) => self.handle_object_removed(event, tick),
+            _ => {}
+        }
+    }
+
+    fn handle_spawn(&mut self, event: &PlayerSpawnEvent, tick: DemoTick) {
+        let user_id = UserId::from(event.user_id);
+        if Class::new(event.class) == Class::Spy {
+            let spy = self.state.spies.entry(user_id).or_default();
+            spy.end_life(tick);
+            spy.lives.push(SpyLife {
+                start: tick,
+                end: None,
+                backstabs: 0,
+            });
+        } else if let Some(spy) = self.state.spies.get_mut(&user_id) {
+            spy.end_life(tick);
+        }
+    }
+
+    fn handle_death(&mut self, event: &PlayerDeathEvent, tick: DemoTick) {
+        if let Some(spy) = self.state.spies.get_mut(&UserId::from(event.user_id)) {
+            spy.end_life(tick);
+        }
+
+        if event.custom_kill == CUSTOM_KILL_BACKSTAB && event.attacker != 0 {
+            let spy = self
+                .state
+                .spies
+                .entry(UserId::from(event.attacker))
+                .or_default();
+            spy.backstabs.push(Backstab {
+                tick,
+                victim: UserId::from(event.user_id),
+            });
+            if let Some(life) = spy.lives.last_mut().filter(|life| life.end.is_none()) {
+                life.backstabs += 1;
+            }
+        }
+    }
+
+    fn handle_sap(&mut self, event: &PlayerSappedObjectEvent, tick: DemoTick) {
+        let spy = self
+            .state
+            .spies
+            .entry(UserId::from(event.user_id))
+            .or_default();
+        let sapper = EntityId::from(event.sapper_id as u32);
+        spy.saps.push(Sap {
+            tick,
+            owner: UserId::from(event.owner_id),
+            object: BuildingClass::from_object_type(event.object),
+            sapper,
+            building: self.sappers.get(&sapper).copied(),
+            outcome: SapOutcome::Pending,
+        });
+    }
+
+    fn handle_object_destroyed(&mut self, event: &ObjectDestroyedEvent, tick: DemoTick) {
+        let object = EntityId::from(event.index as u32);
+        let by = (event.attacker != 0).then(|| UserId::from(event.attacker));
+        for sap in self.pending_saps() {
+            if event.object_type == OBJECT_SAPPER && sap.sapper == object {
+                sap.outcome = SapOutcome::SapperRemoved { tick, by };
+            } else if sap.building == Some(object) {
+                sap.outcome = SapOutcome::BuildingDestroyed { tick, by };
+            }
+        }
+    }
+
+    fn handle_object_removed(&mut self, event: &ObjectRemovedEvent, tick: DemoTick) {
+        let object = EntityId::from(event.index as u32);
+        for sap in self.pending_saps() {
+            if sap.building == Some(object) {
+                sap.outcome = SapOutcome::BuildingRemoved { tick };
+            }
+        }
+    }
+
+    fn handle_sapper(&mut self, entity: &PacketEntity, parser_state: &ParserState) {
+        if entity.update_type == UpdateType::Delete {
+            self.sappers.remove(&entity.entity_index);
+            return;
+        }
+        let building = entity
+            .get_prop_by_identifier(&BUILT_ON_ENTITY, parser_state)
+            .and_then(|prop| EntityHandle::try_from(&prop.value).ok())
+            .and_then(|handle| handle.entity());
+        if let Some(building) = building {
+            self.sappers.insert(entity.entity_index, building);
+            for sap in self.pending_saps() {
+                if sap.sapper == entity.entity_index && sap.building.is_none() {
+                    sap.building = Some(building);
+                }
+            }
+        }
+    }
+
+    fn pending_saps(&mut self) -> impl Iterator<Item = &mut Sap> {
+        self.state
+            .spies
+            .values_mut()
+            .flat_map(|spy| spy.saps.iter_mut())
+            .filter(|sap| sap.outcome == SapOutcome::Pending)
+    }
+
+    fn handle_entity(&mut self, entity: &PacketEntity, tick: DemoTick, parser_state: &ParserState) {
+        match self.lookup.class_name(entity).map(|class| class.as_str()) {
+            Some("CTFPlayer") => self.handle_player(entity, tick, parser_state),
+            Some("CObjectSapper") => self.handle_sapper(entity, parser_state),
+            _ => {}
+        }
+    }
+
+    fn handle_player(&mut self, entity: &PacketEntity, tick: DemoTick, parser_state: &ParserState) {
+        if entity.update_type == UpdateType::Delete {
+            self.players.remove(&entity.entity_index);
+            return;
+        }
+
+        let (mut status, mut conditions) = self
+            .players
+            .get(&entity.entity_index)
+            .copied()
+            .unwrap_or_default();
+        for prop in entity.props(parser_state) {
+            match prop.identifier {
+                DISGUISE_TEAM => {
+                    status.disguise_team = Team::new(i64::try_from(&prop.value).unwrap_or_default())
+                }
+                DISGUISE_CLASS => {
+                    status.disguise_class =
+                        Class::new(i64::try_from(&prop.value).unwrap_or_default())
+                }
+                CLOAK_METER => status.cloak_meter = f32::try_from(&prop.value).unwrap_or_default(),
+                _ => {
+                    conditions.update_from_prop(&prop);
+                }
+            }
+        }
+        status.disguised = conditions.contains(PlayerCondition::Disguised);
+        status.cloaked = conditions.contains(PlayerCondition::Stealthed);
+        self.players
+            .insert(entity.entity_index, (status, conditions));
+
+        if let Some(user_id) = self.lookup.user_id(entity.entity_index) {
+            if let Some(spy) = self.state.spies.get_mut(&user_id) {
+                spy.set_status(status, tick);
+            } else if status.disguised || status.cloaked {
+                self.state
+                    .spies
+                    .entry(user_id)
+                    .or_default()
+                    .set_status(status, tick);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_spy_disguise_and_cloak_periods() {
+    let mut spy = SpyStats::default();
+    let disguised = SpyStatus {
+        disguised: true,
+        disguise_team: Team::Red,
+        disguise_class: Class::Medic,
+        ..SpyStatus::default()
+    };
+    spy.set_status(disguised, DemoTick::from(10));
+    spy.set_status(
+        SpyStatus {
+            disguise_class: Class::Scout,
+            ..disguised
+        },
+        DemoTick::from(20),
+    );
+    spy.set_status(
+        SpyStatus {
+            cloaked: true,
+            ..SpyStatus::default()
+        },
+        DemoTick::from(30),
+    );
+    spy.set_status(SpyStatus::default(), DemoTick::from(45));
+    spy.set_status(disguised, DemoTick::from(100));
+
+    assert_eq!(3, spy.disguises.len());
+    assert_eq!(Class::Scout, spy.disguises[1].class);
+    assert_eq!(20 + 10, spy.time_disguised(DemoTick::from(110)));
+    assert_eq!(15, spy.time_cloaked(DemoTick::from(110)));
+}
+
+#[test]
+fn test_sap_outcome_by_building() {
+    use crate::demo::packet::datatable::ClassId;
+    use crate::demo::sendprop::{SendProp, SendPropValue};
+
+    let state = ParserState::new(24, |_| false, false);
+    let mut analyser = SpyAnalyser::new();
+    let sapper = |sapper: u32, building: u32| PacketEntity {
+        server_class: ClassId::from(0),
+        entity_index: EntityId::from(sapper),
+        props: vec![SendProp {
+            index: 0,
+            identifier: BUILT_ON_ENTITY,
+            value: SendPropValue::Integer(
+                u32::from(EntityHandle::new(EntityId::from(building), 1)) as i64,
+            ),
+        }],
+        in_pvs: true,
+        update_type: UpdateType::Preserve,
+        serial_number: 1,
+        delay: None,
+        delta: None,
+        baseline_index: 0,
+    };
+    let sap = |sapper_id: u16| PlayerSappedObjectEvent {
+        user_id: 1,
+        owner_id: 2,
+        object: 1,
+        sapper_id,
+    };
+
+    // teleporter entrance (100) and exit (101) of the same engineer both sapped
+    analyser.handle_sap(&sap(200), DemoTick::from(10));
+    analyser.handle_sapper(&sapper(200, 100), &state);
+    analyser.handle_sapper(&sapper(201, 101), &state);
+    analyser.handle_sap(&sap(201), DemoTick::from(12));
+
+    analyser.handle_object_destroyed(
+        &ObjectDestroyedEvent {
+            user_id: 2,
+            attacker: 1,
+            assister: 0,
+            weapon: Default::default(),
+            weapon_id: 0,
+            object_type: 1,
+            index: 101,
+            was_building: false,
+        },
+        DemoTick::from(20),
+    );
+
+    let saps = &analyser.state.spies[&UserId::from(1u32)].saps;
+    assert_eq!(Some(BuildingClass::Teleporter), saps[0].object);
+    assert_eq!(Some(EntityId::from(100u32)), saps[0].building);
+    assert_eq!(SapOutcome::Pending, saps[0].outcome);
+    assert_eq!(Some(EntityId::from(101u32)), saps[1].building);
+    assert_eq!(
+        SapOutcome::BuildingDestroyed {
+            tick: DemoTick::from(20),
+            by: Some(UserId::from(1u32))
+        },
+        saps[1].outcome
+    );
+}
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use crate::demo::gamevent::GameEventDefinition;
 
 use crate::demo::message::packetentities::{
-    EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
+    EntityHandle, EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
 };
 use crate::demo::message::stringtable::StringTableMeta;
 use crate::demo::message::{Message, MessageType};
@@ -14,10 +14,17 @@ use crate::demo::packet::datatable::{
 };
 use crate::demo::packet::stringtable::StringTableEntry;
 
-use crate::demo::data::DemoTick;
-use crate::demo::sendprop::{SendProp, SendPropIdentifier};
+use crate::demo::data::{DemoTick, UserInfo};
+use crate::demo::parser::analyser::UserId;
+use crate::demo::parser::baselines::{
+    BaselineDiff, BaselineExport, ClassBaseline, InstanceBaseline,
+};
+use crate::demo::parser::entityworld::EntityWorld;
+use crate::demo::parser::lifecycle::EntityLifecycle;
+use crate::demo::parser::stringtables::StringTables;
+use crate::demo::sendprop::{RawSendPropDefinition, SendProp, SendPropIdentifier, SendPropName};
 use crate::nullhasher::NullHasherBuilder;
-use crate::{Result, Stream};
+use crate::{ParseError, Result, Stream};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 #[cfg(feature = "trace")]
@@ -36,12 +43,23 @@ pub struct ParserState {
     pub parsed_static_baselines: RefCell<HashMap<ClassId, Vec<SendProp>, NullHasherBuilder>>,
     pub event_definitions: Vec<GameEventDefinition>,
     pub string_tables: Vec<StringTableMeta>,
+    pub string_table_contents: Option<StringTables>,
     pub entity_classes: HashMap<EntityId, ClassId, NullHasherBuilder>,
     // indexed by ClassId
     pub send_tables: Vec<SendTable>,
     pub server_classes: Vec<ServerClass>,
+    /// Names of all props in the data tables of the demo, only kept when enabled with [`ParserState::enable_prop_names`]
+    pub prop_names: Option<FnvHashMap<SendPropIdentifier, (SendTableName, SendPropName)>>,
     pub instance_baselines: [Baseline; 2],
     pub demo_meta: DemoMeta,
+    pub entity_world: Option<EntityWorld>,
+    /// Validate decoded prop values against their definition
+    pub strict_props: bool,
+    /// Definitions of all props from the data tables, only kept in strict mode for validating decoded values
+    pub(crate) prop_definitions: FnvHashMap<SendPropIdentifier, RawSendPropDefinition>,
+    pub(crate) entity_lifecycle: EntityLifecycle,
+    /// User ids of the player entities, kept up to date from the `userinfo` table
+    pub(crate) user_ids: HashMap<EntityId, UserId, NullHasherBuilder>,
     analyser_handles: fn(message_type: MessageType) -> bool,
     handle_entities: bool,
     parse_all: bool,
@@ -82,11 +100,18 @@ impl<'a> ParserState {
             parsed_static_baselines: RefCell::new(HashMap::with_hasher(NullHasherBuilder)),
             event_definitions: Vec::new(),
             string_tables: Vec::new(),
+            string_table_contents: None,
             entity_classes: HashMap::with_hasher(NullHasherBuilder),
             send_tables: Vec::new(),
             server_classes: Vec::new(),
+            prop_names: None,
             instance_baselines: [Baseline::default(), Baseline::default()],
             demo_meta: DemoMeta::default(),
+            entity_world: None,
+            strict_props: false,
+            prop_definitions: FnvHashMap::default(),
+            entity_lifecycle: EntityLifecycle::default(),
+            user_ids: HashMap::with_hasher(NullHasherBuilder),
             analyser_handles,
             handle_entities: analyser_handles(MessageType::PacketEntities) || parse_all,
             parse_all,
@@ -94,6 +119,81 @@ impl<'a> ParserState {
         }
     }
 
+    /// The table and prop name for a prop in the data tables of the demo
+    pub fn prop_name(
+        &self,
+        identifier: SendPropIdentifier,
+    ) -> Option<(&SendTableName, &SendPropName)> {
+        self.prop_names
+            .as_ref()?
+            .get(&identifier)
+            .map(|(table, prop)| (table, prop))
+    }
+
+    /// The `"{table}.{prop}"` name for a prop
+    ///
+    /// Falls back to the names known at compile time when the prop names aren't kept or the prop isn't in the data tables
+    pub fn prop_full_name(&self, identifier: SendPropIdentifier) -> Option<String> {
+        match self.prop_name(identifier) {
+            Some((table, prop)) => Some(format!("{}.{}", table, prop)),
+            None => identifier
+                .names()
+                .map(|(table, prop)| format!("{}.{}", table, prop)),
+        }
+    }
+
+    /// Maintain the full state of all entities in the [`EntityWorld`]
+    pub fn enable_entity_world(&mut self) {
+        self.handle_entities = true;
+        self.entity_world.get_or_insert_with(EntityWorld::default);
+    }
+
+    /// Keep the contents of all string tables together with their update history
+    pub fn enable_string_tables(&mut self) {
+        self.string_table_contents
+            .get_or_insert_with(StringTables::default);
+    }
+
+    /// Keep the names of all props from the data tables, needs to be enabled before the data tables are parsed
+    pub fn enable_prop_names(&mut self) {
+        self.prop_names.get_or_insert_with(FnvHashMap::default);
+    }
+
+    /// Fail parsing when a decoded prop value is outside of the bounds of its definition
+    ///
+    /// Needs to be enabled before the data tables are parsed, also keeps the prop names for reporting the invalid prop
+    pub fn enable_strict_props(&mut self) {
+        self.strict_props = true;
+        self.enable_prop_names();
+    }
+
+    pub fn entity_world(&self) -> Option<&EntityWorld> {
+        self.entity_world.as_ref()
+    }
+
+    pub fn string_table_contents(&self) -> Option<&StringTables> {
+        self.string_table_contents.as_ref()
+    }
+
+    /// Resolve an entity handle to the entity it points to, if that entity still exists
+    ///
+    /// Handles to an entity index that has since been reused by a new entity are not resolved.
+    ///
+    /// The live entities are tracked by the [`DemoHandler`](crate::demo::parser::DemoHandler) while it handles
+    /// the packet entities, so this only resolves handles when the packet entities are parsed and the messages
+    /// are passed through the `DemoHandler`, feeding messages to [`ParserState::handle_message`] directly doesn't track them.
+    pub fn resolve_handle(&self, handle: EntityHandle) -> Option<EntityId> {
+        let entity = self.entity_lifecycle.get(handle.entity()?)?;
+        handle
+            .matches(entity.id, entity.serial_number)
+            .then_some(entity.id)
+    }
+
+    /// The user id of a player entity
+    pub fn user_id(&self, entity: EntityId) -> Option<UserId> {
+        self.user_ids.get(&entity).copied()
+    }
+
     pub fn get_static_baseline(
         &self,
         class_id: ClassId,
@@ -130,6 +230,67 @@ impl<'a> ParserState {
         // })
     }
 
+    /// Decode the static baseline of a class, `None` if the class has no static baseline
+    ///
+    /// Requires the send tables, which are only kept when entities are parsed.
+    /// If the baseline fails to decode the error is stored in the returned baseline
+    pub fn class_baseline(&self, class_id: ClassId) -> Option<ClassBaseline> {
+        let static_baseline = self.static_baselines.get(&class_id)?;
+        let props = self
+            .send_tables
+            .get(usize::from(class_id))
+            .ok_or(ParseError::UnknownServerClass(class_id))
+            .and_then(|send_table| static_baseline.parse(send_table));
+        Some(ClassBaseline::new(class_id, props, self))
+    }
+
+    /// Decode the static baselines of all classes, ordered by class id
+    pub fn class_baselines(&self) -> Vec<ClassBaseline> {
+        let mut class_ids: Vec<ClassId> = self.static_baselines.keys().copied().collect();
+        class_ids.sort();
+        class_ids
+            .into_iter()
+            .filter_map(|class_id| self.class_baseline(class_id))
+            .collect()
+    }
+
+    /// The entities in instance baseline 0 or 1
+    pub fn instance_baseline(&self, index: usize) -> Vec<InstanceBaseline> {
+        self.instance_baselines
+            .get(index)
+            .into_iter()
+            .flat_map(|baseline| baseline.iter())
+            .map(|entity| InstanceBaseline::new(entity, self))
+            .collect()
+    }
+
+    /// All entities that differ between instance baseline 0 and 1
+    pub fn diff_instance_baselines(&self) -> Vec<BaselineDiff> {
+        let [first, second] = &self.instance_baselines;
+        first
+            .instances
+            .iter()
+            .zip(second.instances.iter())
+            .enumerate()
+            .filter_map(|(index, (first, second))| {
+                BaselineDiff::new(
+                    EntityId::from(index as u32),
+                    first.as_ref(),
+                    second.as_ref(),
+                    self,
+                )
+            })
+            .collect()
+    }
+
+    /// All static and instance baselines, for serializing
+    pub fn export_baselines(&self) -> BaselineExport {
+        BaselineExport {
+            static_baselines: self.class_baselines(),
+            instance_baselines: [self.instance_baseline(0), self.instance_baseline(1)],
+        }
+    }
+
     pub fn get_baseline(
         &self,
         baseline_index: usize,
@@ -163,7 +324,27 @@ impl<'a> ParserState {
         parse_tables: &[ParseSendTable],
         server_classes: Vec<ServerClass>,
     ) -> Result<()> {
-        if self.handle_entities {
+        if let Some(prop_names) = self.prop_names.as_mut() {
+            *prop_names = parse_tables
+                .iter()
+                .flat_map(|table| {
+                    table
+                        .props
+                        .iter()
+                        .map(move |prop| (prop.identifier, (table.name.clone(), prop.name.clone())))
+                })
+                .collect();
+        }
+        if self.strict_props {
+            self.prop_definitions = parse_tables
+                .iter()
+                .flat_map(|table| table.props.iter())
+                .map(|prop| (prop.identifier, prop.clone()))
+                .collect();
+        }
+
+        // temp entities are encoded using the send tables
+        if self.handle_entities || self.should_parse_message(MessageType::TempEntities) {
             let mut send_tables: FnvHashMap<SendTableName, SendTable> = parse_tables
                 .iter()
                 .map(|parse_table| {
@@ -181,6 +362,10 @@ impl<'a> ParserState {
 
             self.server_classes = server_classes;
 
+            if let Some(world) = self.entity_world.as_mut() {
+                world.handle_server_classes(&self.server_classes);
+            }
+
             self.send_tables.reserve(self.server_classes.len());
 
             for class in self.server_classes.iter() {
@@ -277,12 +462,46 @@ impl<'a> ParserState {
         }
     }
 
+    /// Apply the entity updates from a message to the entity world, if enabled
+    pub fn update_entity_world(&mut self, message: &PacketEntitiesMessage, tick: DemoTick) {
+        if self.entity_world.is_none() {
+            return;
+        }
+        let updates: Vec<Vec<SendProp>> = message
+            .entities
+            .iter()
+            .map(|entity| entity.props(self).collect())
+            .collect();
+        if let Some(world) = self.entity_world.as_mut() {
+            // the client removes all entities before applying a full update
+            if message.delta.is_none() {
+                world.clear();
+            }
+            for removed in message.removed_entities.iter() {
+                world.remove(*removed);
+            }
+            for (entity, props) in message.entities.iter().zip(updates) {
+                world.handle_entity(entity, props, tick);
+            }
+        }
+    }
+
     pub fn handle_string_entry(
         &mut self,
         table: &str,
-        _index: usize,
+        index: usize,
         entry: &StringTableEntry<'a>,
+        tick: DemoTick,
     ) {
+        if let Some(contents) = self.string_table_contents.as_mut() {
+            contents.handle_string_entry(table, index, entry, tick);
+        }
+        if table == "userinfo" {
+            if let Ok(Some(info)) = UserInfo::from_string_table_entry(index, entry) {
+                self.user_ids
+                    .insert(info.entity_id, info.player_info.user_id);
+            }
+        }
         if table == "instancebaseline" {
             if let (Some(extra), Ok(class_id)) = (&entry.extra_data, entry.text().parse()) {
                 let baseline = StaticBaseline::new(class_id, extra.data.to_owned());
@@ -328,6 +547,10 @@ impl Baseline {
         self.instances[usize::from(index)] = Some(entity);
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &BaselineEntity> {
+        self.instances.iter().flatten()
+    }
+
     pub fn keys(&self) -> impl Iterator<Item = EntityId> + '_ {
         self.instances
             .iter()
@@ -405,3 +628,29 @@ impl From<BaselineEntity> for PacketEntity {
         }
     }
 }
+
+#[test]
+fn test_user_id() {
+    use crate::demo::data::userinfo::PlayerInfo;
+
+    let mut state = ParserState::new(24, |_| true, false);
+    let info = UserInfo {
+        entity_id: EntityId::from(3u32),
+        player_info: PlayerInfo {
+            user_id: UserId::from(12u16),
+            steam_id: "[U:1:1]".into(),
+            ..PlayerInfo::default()
+        },
+    };
+    // the entry text is the player slot, which is one less than the entity index
+    let entry = StringTableEntry {
+        text: Some("2".into()),
+        ..info.encode_to_string_table().unwrap()
+    };
+    state.handle_string_entry("userinfo", 2, &entry, 0.into());
+    assert_eq!(
+        state.user_id(EntityId::from(3u32)),
+        Some(UserId::from(12u16))
+    );
+    assert_eq!(state.user_id(EntityId::from(4u32)), None);
+}
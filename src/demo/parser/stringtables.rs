@@ -0,0 +1,300 @@
+use crate::demo::data::{DemoTick, UserInfo};
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::packet::stringtable::StringTableEntry;
+use bitbuffer::{BitReadBuffer, BitReadStream, LittleEndian};
+use serde::{Deserialize, Serialize};
+
+/// The contents of a single string table entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct StringTableValue {
+    pub text: Option<String>,
+    pub data: Option<Vec<u8>>,
+}
+
+impl StringTableValue {
+    fn new(entry: &StringTableEntry) -> Self {
+        StringTableValue {
+            text: entry.text.as_ref().map(|text| text.to_string()),
+            data: entry.extra_data.as_ref().map(|extra| {
+                // fixed size user data can be smaller than a single byte
+                let mut data = extra.data.clone();
+                let mut bytes = data
+                    .read_bytes(data.bits_left() / 8)
+                    .map(|bytes| bytes.into_owned())
+                    .unwrap_or_default();
+                if data.bits_left() > 0 {
+                    bytes.push(data.read_int(data.bits_left()).unwrap_or_default());
+                }
+                bytes
+            }),
+        }
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// The extra data interpreted as a null terminated string
+    pub fn data_string(&self) -> Option<String> {
+        self.data.as_ref().map(|data| {
+            let end = data
+                .iter()
+                .position(|byte| *byte == 0)
+                .unwrap_or(data.len());
+            String::from_utf8_lossy(&data[0..end]).into_owned()
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StringTableUpdate {
+    pub tick: DemoTick,
+    pub index: usize,
+    pub value: StringTableValue,
+}
+
+/// The current contents of a string table, together with all updates made to it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct StringTableContents {
+    pub name: String,
+    pub entries: Vec<Option<StringTableValue>>,
+    pub updates: Vec<StringTableUpdate>,
+}
+
+impl StringTableContents {
+    pub fn get(&self, index: usize) -> Option<&StringTableValue> {
+        self.entries.get(index).and_then(Option::as_ref)
+    }
+
+    pub fn text(&self, index: usize) -> Option<&str> {
+        self.get(index).and_then(StringTableValue::text)
+    }
+
+    /// Find the entry index for the entry with the given text
+    pub fn find(&self, text: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.as_ref().and_then(StringTableValue::text) == Some(text))
+    }
+
+    /// All non-empty entries with their index
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &StringTableValue)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| Some((index, entry.as_ref()?)))
+    }
+
+    /// The value of an entry as it was at the given tick
+    pub fn get_at(&self, index: usize, tick: DemoTick) -> Option<&StringTableValue> {
+        self.updates
+            .iter()
+            .rev()
+            .find(|update| update.index == index && update.tick <= tick)
+            .map(|update| &update.value)
+    }
+
+    fn set(&mut self, index: usize, mut value: StringTableValue, tick: DemoTick) {
+        if self.entries.len() <= index {
+            self.entries.resize(index + 1, None);
+        }
+        // updates only contain the parts of the entry that changed
+        if let Some(existing) = &self.entries[index] {
+            if value.text.is_none() {
+                value.text = existing.text.clone();
+            }
+            if value.data.is_none() {
+                value.data = existing.data.clone();
+            }
+            // the full tables are sent again at the end of the signon
+            if *existing == value {
+                return;
+            }
+        }
+        self.entries[index] = Some(value.clone());
+        self.updates.push(StringTableUpdate { tick, index, value });
+    }
+}
+
+/// Contents of all string tables in the demo, with typed accessors for the commonly used tables
+///
+/// Only maintained by the [`ParserState`](crate::ParserState) when enabled with [`DemoParser::with_string_tables`](crate::DemoParser::with_string_tables)
+/// or by an analyser through [`MessageHandler::requires_string_tables`](crate::demo::parser::MessageHandler::requires_string_tables).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct StringTables {
+    tables: Vec<StringTableContents>,
+}
+
+impl StringTables {
+    pub fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        tick: DemoTick,
+    ) {
+        let value = StringTableValue::new(entry);
+        match self
+            .tables
+            .iter_mut()
+            .find(|contents| contents.name == table)
+        {
+            Some(contents) => contents.set(index, value, tick),
+            None => {
+                let mut contents = StringTableContents {
+                    name: table.into(),
+                    ..StringTableContents::default()
+                };
+                contents.set(index, value, tick);
+                self.tables.push(contents);
+            }
+        }
+    }
+
+    pub fn table(&self, name: &str) -> Option<&StringTableContents> {
+        self.tables.iter().find(|contents| contents.name == name)
+    }
+
+    pub fn tables(&self) -> impl Iterator<Item = &StringTableContents> {
+        self.tables.iter()
+    }
+
+    fn text(&self, table: &str, index: usize) -> Option<&str> {
+        self.table(table)?.text(index)
+    }
+
+    fn texts<'a>(&'a self, table: &str) -> impl Iterator<Item = &'a str> + 'a {
+        self.table(table)
+            .into_iter()
+            .flat_map(|contents| contents.iter())
+            .filter_map(|(_, value)| value.text())
+    }
+
+    /// Path of a model by its model index, as used in `m_nModelIndex`
+    pub fn model(&self, index: usize) -> Option<&str> {
+        self.text("modelprecache", index)
+    }
+
+    /// Path of a sound by its sound index
+    pub fn sound(&self, index: usize) -> Option<&str> {
+        self.text("soundprecache", index)
+    }
+
+    /// Name of a decal by its decal index
+    pub fn decal(&self, index: usize) -> Option<&str> {
+        self.text("decalprecache", index)
+    }
+
+    /// Name of an effect by its index, as used in `m_iEffectName` of effect dispatch temp entities
+    pub fn effect(&self, index: usize) -> Option<&str> {
+        self.text("EffectDispatch", index)
+    }
+
+    /// Name of a particle system by its index
+    pub fn particle(&self, index: usize) -> Option<&str> {
+        self.text("ParticleEffectNames", index)
+    }
+
+    /// All files the client is asked to download
+    pub fn downloadables(&self) -> impl Iterator<Item = &str> {
+        self.texts("downloadables")
+    }
+
+    /// The brightness pattern of a light style
+    pub fn light_style(&self, index: usize) -> Option<String> {
+        self.table("lightstyles")?.get(index)?.data_string()
+    }
+
+    /// Class name of the game rules entity
+    pub fn game_rules_class(&self) -> Option<String> {
+        let table = self.table("GameRulesCreation")?;
+        table.get(table.find("classname")?)?.data_string()
+    }
+
+    /// Contents of an info panel entry, like `motd` or `hostfile`
+    pub fn info_panel(&self, name: &str) -> Option<String> {
+        let table = self.table("InfoPanel")?;
+        table.get(table.find(name)?)?.data_string()
+    }
+
+    /// Models that are loaded during the game instead of being precached
+    pub fn dynamic_models(&self) -> impl Iterator<Item = &str> {
+        self.texts("DynamicModels")
+    }
+
+    /// The player info for a player entity
+    pub fn user_info(&self, entity: EntityId) -> Option<UserInfo> {
+        self.table("userinfo")?.iter().find_map(|(index, value)| {
+            let data = value.data.as_deref()?;
+            let stream = BitReadStream::new(BitReadBuffer::new(data, LittleEndian));
+            UserInfo::parse_from_string_table(index as u16, value.text(), Some(stream))
+                .ok()
+                .flatten()
+                .filter(|info| info.entity_id == entity)
+        })
+    }
+
+    /// The maps in the map cycle of the server
+    pub fn map_cycle(&self) -> Vec<String> {
+        self.table("ServerMapCycle")
+            .and_then(|table| table.get(table.find("ServerMapCycle")?)?.data_string())
+            .map(|cycle| {
+                cycle
+                    .lines()
+                    .map(str::trim)
+                    .filter(|map| !map.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[test]
+fn test_string_tables() {
+    use crate::demo::packet::stringtable::ExtraData;
+
+    fn entry(text: &str, data: Option<&'static [u8]>) -> StringTableEntry<'static> {
+        StringTableEntry {
+            text: Some(text.to_string().into()),
+            extra_data: data.map(|data| {
+                ExtraData::new(BitReadStream::new(BitReadBuffer::new(data, LittleEndian)))
+            }),
+        }
+    }
+
+    let mut tables = StringTables::default();
+    tables.handle_string_entry(
+        "modelprecache",
+        1,
+        &entry("maps/cp_foo.bsp", None),
+        0.into(),
+    );
+    tables.handle_string_entry(
+        "modelprecache",
+        2,
+        &entry("models/player/scout.mdl", None),
+        0.into(),
+    );
+    tables.handle_string_entry(
+        "ServerMapCycle",
+        0,
+        &entry("ServerMapCycle", Some(b"cp_foo\ncp_bar\n\0")),
+        0.into(),
+    );
+    tables.handle_string_entry("lightstyles", 0, &entry("0", Some(b"m\0")), 0.into());
+    tables.handle_string_entry("lightstyles", 0, &entry("0", Some(b"a\0")), 10.into());
+
+    assert_eq!(tables.model(2), Some("models/player/scout.mdl"));
+    assert_eq!(tables.model(3), None);
+    assert_eq!(tables.sound(1), None);
+    assert_eq!(tables.map_cycle(), vec!["cp_foo", "cp_bar"]);
+    assert_eq!(tables.light_style(0), Some("a".into()));
+
+    let light_styles = tables.table("lightstyles").unwrap();
+    assert_eq!(light_styles.updates.len(), 2);
+    assert_eq!(
+        light_styles.get_at(0, 5.into()).unwrap().data_string(),
+        Some("m".into())
+    );
+}
@@ -0,0 +1,86 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::tempentities::{FireBullets, TempEntity};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TempEntityEvent {
+    pub tick: DemoTick,
+    pub fire_delay: f32,
+    pub entity: TempEntity,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct TempEntityState {
+    pub events: Vec<TempEntityEvent>,
+}
+
+impl TempEntityState {
+    /// All bullets fired during the demo, used for reconstructing hitscan shots
+    pub fn bullets(&self) -> impl Iterator<Item = (DemoTick, &FireBullets)> {
+        self.events.iter().filter_map(|event| match &event.entity {
+            TempEntity::FireBullets(bullets) => Some((event.tick, bullets)),
+            _ => None,
+        })
+    }
+}
+
+/// Collect all temp entities in the demo, decoded into their typed form
+#[derive(Default, Debug)]
+pub struct TempEntityAnalyser {
+    state: TempEntityState,
+    include_other: bool,
+}
+
+impl MessageHandler for TempEntityAnalyser {
+    type Output = TempEntityState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::TempEntities)
+    }
+
+    fn requires_string_tables() -> bool {
+        true
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        if let Message::TempEntities(message) = message {
+            for event in &message.events {
+                let entity = event.typed(parser_state);
+                if self.include_other || !matches!(entity, TempEntity::Other(_)) {
+                    self.state.events.push(TempEntityEvent {
+                        tick,
+                        fire_delay: event.fire_delay,
+                        entity,
+                    });
+                }
+            }
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for TempEntityAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl TempEntityAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also collect temp entities that don't have a typed representation
+    pub fn with_other(self) -> Self {
+        TempEntityAnalyser {
+            include_other: true,
+            ..self
+        }
+    }
+}
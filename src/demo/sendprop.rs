@@ -3,9 +3,9 @@ use super::vector::{Vector, VectorXY};
 use crate::consthash::ConstFnvHash;
 use crate::demo::message::stringtable::log_base2;
 use crate::demo::packet::datatable::SendTableName;
-use crate::demo::parser::MalformedSendPropDefinitionError;
+use crate::demo::parser::{InvalidPropValueError, MalformedSendPropDefinitionError};
 use crate::demo::sendprop_gen::get_prop_names;
-use crate::{ParseError, ReadResult, Result, Stream};
+use crate::{ParseError, ParserState, ReadResult, Result, Stream};
 use bitbuffer::{
     BitRead, BitReadStream, BitWrite, BitWriteSized, BitWriteStream, Endianness, LittleEndian,
 };
@@ -13,6 +13,7 @@ use enumflags2::{bitflags, BitFlags};
 use num_traits::Signed;
 use parse_display::Display;
 use serde::de::Error;
+use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Cow;
 use std::cmp::min;
@@ -715,6 +716,73 @@ impl SendPropValue {
             }
         }
     }
+
+    /// Check that a decoded value is within the bounds of its definition from the data tables
+    ///
+    /// Values that are out of bounds are a sign of corrupt data or the stream being out of sync.
+    pub fn validate(
+        &self,
+        definition: &RawSendPropDefinition,
+    ) -> std::result::Result<(), InvalidPropValueError> {
+        match (definition.prop_type, self) {
+            // var ints have no fixed width, so every decoded value is valid
+            (SendPropType::Int, SendPropValue::Integer(_))
+                if definition.flags.contains(SendPropFlag::NormalVarInt) =>
+            {
+                Ok(())
+            }
+            // a decoded integer always fits in the bits it was read from, this only fails
+            // for values that were changed after decoding
+            (SendPropType::Int, SendPropValue::Integer(value)) => validate_int(*value, definition),
+            (SendPropType::Float, SendPropValue::Float(value)) => {
+                validate_float(*value, definition)
+            }
+            (SendPropType::Vector, SendPropValue::Vector(value)) => {
+                validate_float(value.x, definition)?;
+                validate_float(value.y, definition)?;
+                validate_float(value.z, definition)
+            }
+            (SendPropType::VectorXY, SendPropValue::VectorXY(value)) => {
+                validate_float(value.x, definition)?;
+                validate_float(value.y, definition)
+            }
+            // the buffer on the receiving end includes the null terminator
+            (SendPropType::String, SendPropValue::String(value))
+                if value.len() >= DT_MAX_STRING_BUFFERSIZE =>
+            {
+                Err(InvalidPropValueError::StringTooLong {
+                    length: value.len(),
+                    max: DT_MAX_STRING_BUFFERSIZE - 1,
+                })
+            }
+            (SendPropType::String, SendPropValue::String(_)) => Ok(()),
+            (SendPropType::Array, SendPropValue::Array(values)) => {
+                let max = definition.element_count.unwrap_or_default();
+                if values.len() > max as usize {
+                    return Err(InvalidPropValueError::TooManyElements {
+                        count: values.len(),
+                        max,
+                    });
+                }
+                match definition.array_property.as_deref() {
+                    Some(inner_definition) => values
+                        .iter()
+                        .try_for_each(|value| value.validate(inner_definition)),
+                    None => Ok(()),
+                }
+            }
+            (prop_type, _) => Err(InvalidPropValueError::WrongType(match prop_type {
+                SendPropType::Int => "integer",
+                SendPropType::Float => "float",
+                SendPropType::String => "string",
+                SendPropType::Vector => "vector",
+                SendPropType::VectorXY => "vectorxy",
+                SendPropType::Array => "array",
+                SendPropType::DataTable | SendPropType::NumSendPropTypes => "datatable",
+            })),
+        }
+    }
+
     pub fn encode(
         &self,
         stream: &mut BitWriteStream<LittleEndian>,
@@ -1125,21 +1193,21 @@ impl SendPropIdentifier {
 
     /// This returns an option because only props known at compile time will return a name here
     ///
-    /// If you need to know the name of every property you need to keep a map yourself
+    /// If you need to know the name of every property use [`ParserState::prop_name`](crate::ParserState::prop_name)
     pub fn table_name(&self) -> Option<SendTableName> {
         get_prop_names(*self).map(|(table, _)| table.into())
     }
 
     /// This returns an option because only props known at compile time will return a name here
     ///
-    /// If you need to know the name of every property you need to keep a map yourself
+    /// If you need to know the name of every property use [`ParserState::prop_name`](crate::ParserState::prop_name)
     pub fn prop_name(&self) -> Option<SendPropName> {
         get_prop_names(*self).map(|(_, prop)| prop.into())
     }
 
     /// This returns an option because only props known at compile time will return a name here
     ///
-    /// If you need to know the name of every property you need to keep a map yourself
+    /// If you need to know the name of every property use [`ParserState::prop_name`](crate::ParserState::prop_name)
     pub fn names(&self) -> Option<(SendTableName, SendPropName)> {
         get_prop_names(*self).map(|(table, prop)| (table.into(), prop.into()))
     }
@@ -1218,6 +1286,51 @@ pub struct SendProp {
     pub value: SendPropValue,
 }
 
+impl SendProp {
+    /// Display or serialize the prop together with its name from the data tables of the demo
+    pub fn named<'a>(&'a self, state: &'a ParserState) -> NamedSendProp<'a> {
+        NamedSendProp { prop: self, state }
+    }
+}
+
+/// A prop with its name from the data tables, created with [`SendProp::named`]
+///
+/// Displays as `DT_Table.m_prop = value` and serializes with an extra `name` field
+#[derive(Clone, Copy)]
+pub struct NamedSendProp<'a> {
+    prop: &'a SendProp,
+    state: &'a ParserState,
+}
+
+impl NamedSendProp<'_> {
+    pub fn name(&self) -> Option<String> {
+        self.state.prop_full_name(self.prop.identifier)
+    }
+}
+
+impl Display for NamedSendProp<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{} = {}", name, self.prop.value),
+            None => write!(f, "{} = {}", self.prop.identifier, self.prop.value),
+        }
+    }
+}
+
+impl Serialize for NamedSendProp<'_> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("SendProp", 4)?;
+        state.serialize_field("index", &self.prop.index)?;
+        state.serialize_field("identifier", &self.prop.identifier)?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("value", &self.prop.value)?;
+        state.end()
+    }
+}
+
 impl Debug for SendProp {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{} = {}", self.identifier, self.value)
@@ -1288,6 +1401,109 @@ fn test_var_int_roundtrip() {
     var_int_roundtrip(-123125412, true);
 }
 
+/// Size of the buffer strings are decoded into by the game, including the null terminator
+const DT_MAX_STRING_BUFFERSIZE: usize = 512;
+
+/// Coordinates are limited to the world bounds, even though the encoding has room for slightly larger values
+const MAX_COORD_FLOAT: f32 = 16384.0;
+
+/// The largest magnitude and resolution of the fixed point float encodings
+///
+/// Coordinates have a 5 bit (or 3 bit for low precision) fraction, normals are an 11 bit fraction
+fn fixed_point_layout(definition: &FloatDefinition) -> Option<(f32, f32)> {
+    match definition {
+        FloatDefinition::Coord | FloatDefinition::CoordMP => {
+            Some((MAX_COORD_FLOAT, get_frac_factor(5)))
+        }
+        FloatDefinition::CoordMPLowPrecision => Some((MAX_COORD_FLOAT, get_frac_factor(3))),
+        FloatDefinition::CoordMPIntegral => Some((MAX_COORD_FLOAT, 1.0)),
+        FloatDefinition::NormalVarFloat => Some((1.0 - get_frac_factor(11), get_frac_factor(11))),
+        FloatDefinition::FloatNoScale | FloatDefinition::Scaled { .. } => None,
+    }
+}
+
+fn validate_int(
+    value: i64,
+    definition: &RawSendPropDefinition,
+) -> std::result::Result<(), InvalidPropValueError> {
+    let bit_count = definition.bit_count.unwrap_or(32) as u8;
+    let in_range = if definition.flags.contains(SendPropFlag::Unsigned) {
+        match 1i64.checked_shl(bit_count as u32) {
+            Some(max) => value >= 0 && value < max,
+            None => true,
+        }
+    } else {
+        match 1i64.checked_shl(bit_count.saturating_sub(1) as u32) {
+            Some(max) => value >= -max && value < max,
+            None => true,
+        }
+    };
+    if in_range {
+        Ok(())
+    } else {
+        Err(InvalidPropValueError::IntOutOfRange { value, bit_count })
+    }
+}
+
+fn validate_float(
+    value: f32,
+    definition: &RawSendPropDefinition,
+) -> std::result::Result<(), InvalidPropValueError> {
+    if !value.is_finite() {
+        return Err(InvalidPropValueError::NonFiniteFloat(value));
+    }
+    let float_definition = match FloatDefinition::new(
+        definition.flags,
+        definition.bit_count,
+        definition.high_value,
+        definition.low_value,
+    ) {
+        Ok(float_definition) => float_definition,
+        // props without a float encoding can't be decoded in the first place
+        Err(_) => return Ok(()),
+    };
+    match (&float_definition, fixed_point_layout(&float_definition)) {
+        (_, Some((max, _))) if value.abs() > max => Err(InvalidPropValueError::FloatOutOfRange {
+            value,
+            low: -max,
+            high: max,
+        }),
+        // the fixed point values are exact in a float, anything between the steps can't be decoded
+        (_, Some((_, resolution))) if (value / resolution).fract() != 0.0 => {
+            Err(InvalidPropValueError::UnreachableFloat { value, resolution })
+        }
+        (FloatDefinition::Scaled { low, high, .. }, None) => {
+            // the highest raw value decodes to `low + (high - low)`, which can be off from `high` by rounding
+            let (first, last) = (*low, *low + (*high - *low));
+            let (min, max) = (first.min(last), first.max(last));
+            // the server rounds towards the inside of the range for these flags, so the decoded value can't
+            // end up past that end of the range; otherwise allow for the rounding in the scaling
+            let margin = (max - min) * f32::EPSILON * 4.0;
+            let lower_bound = if definition.flags.contains(SendPropFlag::RoundUp) {
+                min
+            } else {
+                min - margin
+            };
+            let upper_bound = if definition.flags.contains(SendPropFlag::RoundDown) {
+                max
+            } else {
+                max + margin
+            };
+            if value < lower_bound || value > upper_bound {
+                Err(InvalidPropValueError::FloatOutOfRange {
+                    value,
+                    low: min,
+                    high: max,
+                })
+            } else {
+                Ok(())
+            }
+        }
+        // every finite float can be decoded without scaling
+        _ => Ok(()),
+    }
+}
+
 pub fn read_bit_coord(stream: &mut Stream) -> ReadResult<f32> {
     let has_int = stream.read()?;
     let has_frac = stream.read()?;
@@ -1328,6 +1544,37 @@ pub fn write_bit_coord(val: f32, stream: &mut BitWriteStream<LittleEndian>) -> R
     Ok(())
 }
 
+pub fn read_bit_vec3_coord(stream: &mut Stream) -> ReadResult<Vector> {
+    let (has_x, has_y, has_z) = stream.read()?;
+
+    Ok(Vector {
+        x: if has_x { read_bit_coord(stream)? } else { 0f32 },
+        y: if has_y { read_bit_coord(stream)? } else { 0f32 },
+        z: if has_z { read_bit_coord(stream)? } else { 0f32 },
+    })
+}
+
+pub fn write_bit_vec3_coord(
+    val: Vector,
+    stream: &mut BitWriteStream<LittleEndian>,
+) -> ReadResult<()> {
+    let has_x = val.x != 0.0;
+    let has_y = val.y != 0.0;
+    let has_z = val.z != 0.0;
+    (has_x, has_y, has_z).write(stream)?;
+
+    if has_x {
+        write_bit_coord(val.x, stream)?;
+    }
+    if has_y {
+        write_bit_coord(val.y, stream)?;
+    }
+    if has_z {
+        write_bit_coord(val.z, stream)?;
+    }
+    Ok(())
+}
+
 #[test]
 fn bit_coord_roundtrip() {
     use bitbuffer::BitReadBuffer;
@@ -1505,3 +1752,293 @@ fn test_bit_normal_roundtrip() {
     roundtrip_normal(0.5);
     roundtrip_normal(-0.5);
 }
+
+#[test]
+fn test_named_prop() {
+    let identifier = SendPropIdentifier::new("DT_FutureTable", "m_iFutureProp");
+    assert_eq!(None, identifier.names());
+
+    let table = ParseSendTable {
+        name: SendTableName::from("DT_FutureTable"),
+        props: vec![RawSendPropDefinition {
+            prop_type: SendPropType::Int,
+            name: SendPropName::from("m_iFutureProp"),
+            identifier,
+            flags: SendPropFlags::default(),
+            table_name: None,
+            low_value: None,
+            high_value: None,
+            bit_count: Some(8),
+            element_count: None,
+            array_property: None,
+            original_bit_count: None,
+        }],
+        needs_decoder: false,
+    };
+    let mut state = ParserState::new(24, |_| false, false);
+    state
+        .handle_data_table(std::slice::from_ref(&table), Vec::new())
+        .unwrap();
+    // prop names are only kept when asked for
+    assert_eq!(None, state.prop_name(identifier));
+
+    state.enable_prop_names();
+    state.handle_data_table(&[table], Vec::new()).unwrap();
+
+    assert_eq!(
+        Some((
+            &SendTableName::from("DT_FutureTable"),
+            &SendPropName::from("m_iFutureProp")
+        )),
+        state.prop_name(identifier)
+    );
+    // the names from the data tables are only available through the parser state
+    assert_eq!(None, identifier.prop_name());
+
+    let prop = SendProp {
+        index: 0,
+        identifier,
+        value: SendPropValue::Integer(5),
+    };
+    assert_eq!("0 = 5", prop.to_string());
+    assert_eq!(
+        "DT_FutureTable.m_iFutureProp = 5",
+        prop.named(&state).to_string()
+    );
+    assert_eq!(
+        format!(
+            r#"{{"index":0,"identifier":"{}","value":5}}"#,
+            u64::from(identifier)
+        ),
+        serde_json::to_string(&prop).unwrap()
+    );
+    assert_eq!(
+        format!(
+            r#"{{"index":0,"identifier":"{}","name":"DT_FutureTable.m_iFutureProp","value":5}}"#,
+            u64::from(identifier)
+        ),
+        serde_json::to_string(&prop.named(&state)).unwrap()
+    );
+}
+
+#[test]
+fn test_validate_prop_value() {
+    let definition = |prop_type, flags, bit_count, low_value, high_value| RawSendPropDefinition {
+        prop_type,
+        name: SendPropName::from("m_prop"),
+        identifier: SendPropIdentifier::new("DT_Test", "m_prop"),
+        flags,
+        table_name: None,
+        low_value,
+        high_value,
+        bit_count,
+        element_count: None,
+        array_property: None,
+        original_bit_count: None,
+    };
+    let no_flags = SendPropFlags::default();
+
+    let scaled = definition(
+        SendPropType::Float,
+        no_flags,
+        Some(8),
+        Some(0.0),
+        Some(10.0),
+    );
+    assert_eq!(Ok(()), SendPropValue::Float(10.0).validate(&scaled));
+    assert_eq!(
+        Err(InvalidPropValueError::FloatOutOfRange {
+            value: 12.0,
+            low: 0.0,
+            high: 10.0
+        }),
+        SendPropValue::Float(12.0).validate(&scaled)
+    );
+
+    // the rounding flags leave no room past the end of the range the server rounds towards
+    let round_down = definition(
+        SendPropType::Float,
+        no_flags | SendPropFlag::RoundDown,
+        Some(8),
+        Some(-0.1),
+        Some(0.3),
+    );
+    let mut data = Vec::new();
+    255u8
+        .write_sized(&mut BitWriteStream::new(&mut data, LittleEndian), 8)
+        .unwrap();
+    let highest = SendPropValue::parse(
+        &mut Stream::from(bitbuffer::BitReadBuffer::new(&data, LittleEndian)),
+        &SendPropParseDefinition::try_from(&round_down).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(Ok(()), highest.validate(&round_down));
+    let past_highest = match highest {
+        SendPropValue::Float(value) => SendPropValue::Float(f32::from_bits(value.to_bits() + 1)),
+        _ => unreachable!(),
+    };
+    assert!(matches!(
+        past_highest.validate(&round_down),
+        Err(InvalidPropValueError::FloatOutOfRange { .. })
+    ));
+    let unrounded = definition(
+        SendPropType::Float,
+        no_flags,
+        Some(8),
+        Some(-0.1),
+        Some(0.3),
+    );
+    assert_eq!(Ok(()), past_highest.validate(&unrounded));
+
+    let round_up = definition(
+        SendPropType::Float,
+        no_flags | SendPropFlag::RoundUp,
+        Some(8),
+        Some(-0.1),
+        Some(0.3),
+    );
+    assert_eq!(Ok(()), SendPropValue::Float(-0.1).validate(&round_up));
+    assert!(
+        SendPropValue::Float(f32::from_bits((-0.1f32).to_bits() + 1))
+            .validate(&round_up)
+            .is_err()
+    );
+
+    let no_scale = definition(
+        SendPropType::Float,
+        no_flags | SendPropFlag::NoScale,
+        Some(32),
+        None,
+        None,
+    );
+    assert_eq!(Ok(()), SendPropValue::Float(1e30).validate(&no_scale));
+    assert!(matches!(
+        SendPropValue::Float(f32::NAN).validate(&no_scale),
+        Err(InvalidPropValueError::NonFiniteFloat(_))
+    ));
+
+    let unsigned = definition(
+        SendPropType::Int,
+        no_flags | SendPropFlag::Unsigned,
+        Some(4),
+        None,
+        None,
+    );
+    assert_eq!(Ok(()), SendPropValue::Integer(15).validate(&unsigned));
+    assert_eq!(
+        Err(InvalidPropValueError::IntOutOfRange {
+            value: 16,
+            bit_count: 4
+        }),
+        SendPropValue::Integer(16).validate(&unsigned)
+    );
+
+    let signed = definition(SendPropType::Int, no_flags, Some(4), None, None);
+    assert_eq!(Ok(()), SendPropValue::Integer(-8).validate(&signed));
+    assert!(SendPropValue::Integer(8).validate(&signed).is_err());
+
+    let var_int = definition(
+        SendPropType::Int,
+        no_flags | SendPropFlag::NormalVarInt,
+        Some(4),
+        None,
+        None,
+    );
+    assert_eq!(Ok(()), SendPropValue::Integer(1 << 40).validate(&var_int));
+
+    let string = definition(SendPropType::String, no_flags, None, None, None);
+    assert_eq!(
+        Ok(()),
+        SendPropValue::String("a".repeat(511)).validate(&string)
+    );
+    assert_eq!(
+        Err(InvalidPropValueError::StringTooLong {
+            length: 512,
+            max: 511
+        }),
+        SendPropValue::String("a".repeat(512)).validate(&string)
+    );
+
+    let array = RawSendPropDefinition {
+        element_count: Some(2),
+        array_property: Some(Box::new(unsigned.clone())),
+        ..definition(SendPropType::Array, no_flags, None, None, None)
+    };
+    assert_eq!(
+        Ok(()),
+        SendPropValue::Array(vec![SendPropValue::Integer(1), SendPropValue::Integer(2)])
+            .validate(&array)
+    );
+    assert!(SendPropValue::Array(vec![SendPropValue::Integer(20)])
+        .validate(&array)
+        .is_err());
+
+    // arrays longer than the definition allows still decode, but don't validate
+    let mut data = Vec::new();
+    {
+        let mut write = BitWriteStream::new(&mut data, LittleEndian);
+        3u8.write_sized(&mut write, 2).unwrap();
+        for value in 1u8..=3 {
+            value.write_sized(&mut write, 4).unwrap();
+        }
+    }
+    let long_array = SendPropValue::parse(
+        &mut Stream::from(bitbuffer::BitReadBuffer::new(&data, LittleEndian)),
+        &SendPropParseDefinition::try_from(&array).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        Err(InvalidPropValueError::TooManyElements { count: 3, max: 2 }),
+        long_array.validate(&array)
+    );
+
+    let coord = definition(
+        SendPropType::Float,
+        no_flags | SendPropFlag::Coord,
+        None,
+        None,
+        None,
+    );
+    assert_eq!(Ok(()), SendPropValue::Float(-123.4375).validate(&coord));
+    assert_eq!(Ok(()), SendPropValue::Float(16384.0).validate(&coord));
+    // the largest coord the encoding can hold is outside of the world bounds
+    let mut data = Vec::new();
+    {
+        let mut write = BitWriteStream::new(&mut data, LittleEndian);
+        (true, true, false).write(&mut write).unwrap();
+        16383u16.write_sized(&mut write, 14).unwrap();
+        31u8.write_sized(&mut write, 5).unwrap();
+    }
+    let outside_world = SendPropValue::parse(
+        &mut Stream::from(bitbuffer::BitReadBuffer::new(&data, LittleEndian)),
+        &SendPropParseDefinition::try_from(&coord).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(SendPropValue::Float(16384.0 + 31.0 / 32.0), outside_world);
+    assert!(matches!(
+        outside_world.validate(&coord),
+        Err(InvalidPropValueError::FloatOutOfRange { .. })
+    ));
+    assert_eq!(
+        Err(InvalidPropValueError::UnreachableFloat {
+            value: 0.1,
+            resolution: 1.0 / 32.0
+        }),
+        SendPropValue::Float(0.1).validate(&coord)
+    );
+
+    let normal = definition(
+        SendPropType::Float,
+        no_flags | SendPropFlag::NormalVarInt,
+        None,
+        None,
+        None,
+    );
+    assert_eq!(Ok(()), SendPropValue::Float(-0.5).validate(&normal));
+    assert!(SendPropValue::Float(1.0).validate(&normal).is_err());
+
+    assert_eq!(
+        Err(InvalidPropValueError::WrongType("integer")),
+        SendPropValue::Float(1.0).validate(&unsigned)
+    );
+}
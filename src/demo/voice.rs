@@ -0,0 +1,608 @@
+//! Extraction of in-game voice communication.
+//!
+//! The [`VoiceAnalyser`] collects all voice packets from a demo, grouped per speaking client.
+//! Afterwards the packets for a speaker can be decoded and rendered into a single 16-bit PCM
+//! track with [`VoiceState::render`], with silence inserted so that every packet is placed at the
+//! time it was recorded. The resulting [`SpeakerAudio`] can be written as a WAV file.
+//!
+//! Modern TF2 demos use the `steam` codec, where each packet contains the Steam voice framing around
+//! a series of Opus frames. The framing (sample rate, silence and checksum) is handled here, while the
+//! actual Opus (or legacy speex/celt) frames are handed to a [`VoiceDecoder`].
+//!
+//! With the `voice` feature enabled an [`OpusDecoder`] is included (linking to libopus) and
+//! [`VoiceState::render_speaker`] decodes the audio of a speaker without a custom decoder.
+
+use crate::demo::data::{DemoTick, UserInfo};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::parser::analyser::UserId;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::ParserState;
+use err_derive::Error;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+/// Errors that can occur while decoding voice data
+#[derive(Debug, Error)]
+pub enum VoiceError {
+    #[error(display = "Voice packet ended unexpectedly")]
+    UnexpectedEnd,
+    #[error(display = "Checksum mismatch in steam voice packet")]
+    InvalidChecksum,
+    #[error(display = "Unknown steam voice payload type {}", _0)]
+    UnknownPayloadType(u8),
+    #[error(display = "Unsupported voice codec {}", _0)]
+    UnsupportedCodec(String),
+    #[error(display = "Error while decoding voice frame: {}", _0)]
+    Decoder(String),
+}
+
+/// The voice codec used in a demo, as announced by the `VoiceInit` message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoiceCodec {
+    /// Steam voice framing around opus frames
+    Steam,
+    Celt,
+    Speex,
+    Other(String),
+}
+
+impl VoiceCodec {
+    pub fn new(name: &str) -> Self {
+        match name {
+            "steam" => VoiceCodec::Steam,
+            "vaudio_celt" => VoiceCodec::Celt,
+            "vaudio_speex" => VoiceCodec::Speex,
+            _ => VoiceCodec::Other(name.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoiceCodecInfo {
+    pub codec: VoiceCodec,
+    pub quality: u8,
+    pub sampling_rate: u16,
+}
+
+/// Decoder for the compressed frames of a voice codec
+pub trait VoiceDecoder {
+    /// Decode a single frame, appending the decoded mono samples to `output`
+    fn decode(&mut self, frame: &[u8], output: &mut Vec<i16>) -> Result<(), VoiceError>;
+
+    /// Reset the decoder state, called when the stream for a speaker is restarted
+    fn reset(&mut self) {}
+}
+
+/// Decoder for the opus frames of the steam voice codec
+#[cfg(feature = "voice")]
+pub struct OpusDecoder {
+    decoder: audiopus::coder::Decoder,
+    sample_rate: audiopus::SampleRate,
+}
+
+#[cfg(feature = "voice")]
+impl OpusDecoder {
+    /// Create a mono decoder, the sample rate has to be one supported by opus
+    pub fn new(sample_rate: u32) -> Result<Self, VoiceError> {
+        let sample_rate = audiopus::SampleRate::try_from(sample_rate as i32)
+            .map_err(|e| VoiceError::Decoder(e.to_string()))?;
+        let decoder = audiopus::coder::Decoder::new(sample_rate, audiopus::Channels::Mono)
+            .map_err(|e| VoiceError::Decoder(e.to_string()))?;
+        Ok(OpusDecoder {
+            decoder,
+            sample_rate,
+        })
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate as u32
+    }
+}
+
+#[cfg(feature = "voice")]
+impl VoiceDecoder for OpusDecoder {
+    fn decode(&mut self, frame: &[u8], output: &mut Vec<i16>) -> Result<(), VoiceError> {
+        // opus frames are at most 120ms
+        let mut buffer = vec![0; self.sample_rate() as usize * 120 / 1000];
+        // an empty frame marks a lost packet, let the decoder conceal it
+        let packet = match frame {
+            [] => None,
+            frame => Some(
+                audiopus::packet::Packet::try_from(frame)
+                    .map_err(|e| VoiceError::Decoder(e.to_string()))?,
+            ),
+        };
+        let signals = audiopus::MutSignals::try_from(&mut buffer[..])
+            .map_err(|e| VoiceError::Decoder(e.to_string()))?;
+        let count = self
+            .decoder
+            .decode(packet, signals, false)
+            .map_err(|e| VoiceError::Decoder(e.to_string()))?;
+        output.extend_from_slice(&buffer[0..count]);
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        if let Ok(decoder) =
+            audiopus::coder::Decoder::new(self.sample_rate, audiopus::Channels::Mono)
+        {
+            self.decoder = decoder;
+        }
+    }
+}
+
+/// A single voice packet as sent by the server
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoicePacket {
+    pub tick: DemoTick,
+    pub proximity: bool,
+    pub data: Vec<u8>,
+}
+
+/// A section of a steam voice packet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SteamVoiceSegment<'a> {
+    SampleRate(u16),
+    /// A number of silent samples
+    Silence(u16),
+    /// Opus frames with packet loss concealment, `sequence` is `None` for a decoder reset
+    Opus {
+        sequence: Option<u16>,
+        frame: &'a [u8],
+    },
+    /// Any other codec payload
+    Other {
+        kind: u8,
+        data: &'a [u8],
+    },
+}
+
+/// A decoded steam voice packet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteamVoicePacket<'a> {
+    pub steam_id: u64,
+    pub segments: Vec<SteamVoiceSegment<'a>>,
+}
+
+const PAYLOAD_SILENCE: u8 = 0;
+const PAYLOAD_OPUS_PLC: u8 = 6;
+const PAYLOAD_SAMPLE_RATE: u8 = 11;
+
+impl<'a> SteamVoicePacket<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, VoiceError> {
+        if data.len() < 12 {
+            return Err(VoiceError::UnexpectedEnd);
+        }
+        let (body, checksum) = data.split_at(data.len() - 4);
+        if crc32(body).to_le_bytes() != checksum {
+            return Err(VoiceError::InvalidChecksum);
+        }
+
+        let mut reader = ByteReader { data: body };
+        let steam_id = u64::from_le_bytes(reader.take_array()?);
+        let mut segments = Vec::new();
+
+        while !reader.data.is_empty() {
+            match reader.u8()? {
+                PAYLOAD_SAMPLE_RATE => segments.push(SteamVoiceSegment::SampleRate(reader.u16()?)),
+                PAYLOAD_SILENCE => segments.push(SteamVoiceSegment::Silence(reader.u16()?)),
+                PAYLOAD_OPUS_PLC => {
+                    let length = reader.u16()? as usize;
+                    let mut frames = ByteReader {
+                        data: reader.take(length)?,
+                    };
+                    while !frames.data.is_empty() {
+                        let frame_length = frames.u16()?;
+                        if frame_length == u16::MAX {
+                            segments.push(SteamVoiceSegment::Opus {
+                                sequence: None,
+                                frame: &[],
+                            });
+                            break;
+                        }
+                        let sequence = frames.u16()?;
+                        segments.push(SteamVoiceSegment::Opus {
+                            sequence: Some(sequence),
+                            frame: frames.take(frame_length as usize)?,
+                        });
+                    }
+                }
+                kind @ 1..=5 => {
+                    let length = reader.u16()? as usize;
+                    segments.push(SteamVoiceSegment::Other {
+                        kind,
+                        data: reader.take(length)?,
+                    });
+                }
+                kind => return Err(VoiceError::UnknownPayloadType(kind)),
+            }
+        }
+
+        Ok(SteamVoicePacket { steam_id, segments })
+    }
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], VoiceError> {
+        if self.data.len() < length {
+            return Err(VoiceError::UnexpectedEnd);
+        }
+        let (head, tail) = self.data.split_at(length);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], VoiceError> {
+        let mut result = [0; N];
+        result.copy_from_slice(self.take(N)?);
+        Ok(result)
+    }
+
+    fn u8(&mut self) -> Result<u8, VoiceError> {
+        Ok(self.take_array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, VoiceError> {
+        Ok(u16::from_le_bytes(self.take_array()?))
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Decoded audio for a single speaker
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SpeakerAudio {
+    pub client: u8,
+    pub sample_rate: u32,
+    pub samples: Vec<i16>,
+    /// Number of voice packets that couldn't be decoded, these are missing from the audio
+    pub decode_errors: usize,
+}
+
+impl SpeakerAudio {
+    /// Write the audio as a mono 16-bit PCM WAV file
+    pub fn write_wav<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let data_length = (self.samples.len() * 2) as u32;
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&(36 + data_length).to_le_bytes())?;
+        writer.write_all(b"WAVEfmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        writer.write_all(&1u16.to_le_bytes())?; // PCM
+        writer.write_all(&1u16.to_le_bytes())?; // mono
+        writer.write_all(&self.sample_rate.to_le_bytes())?;
+        writer.write_all(&(self.sample_rate * 2).to_le_bytes())?;
+        writer.write_all(&2u16.to_le_bytes())?; // block align
+        writer.write_all(&16u16.to_le_bytes())?; // bits per sample
+        writer.write_all(b"data")?;
+        writer.write_all(&data_length.to_le_bytes())?;
+        for sample in &self.samples {
+            writer.write_all(&sample.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Duration of the audio in seconds
+    pub fn duration(&self) -> f32 {
+        self.samples.len() as f32 / self.sample_rate.max(1) as f32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct VoiceState {
+    pub codec: Option<VoiceCodecInfo>,
+    pub interval_per_tick: f32,
+    /// All voice packets, grouped by the speaking client
+    pub packets: BTreeMap<u8, Vec<VoicePacket>>,
+    pub user_ids: BTreeMap<u8, UserId>,
+}
+
+impl VoiceState {
+    /// All clients that have spoken during the demo
+    pub fn speakers(&self) -> impl Iterator<Item = u8> + '_ {
+        self.packets.keys().copied()
+    }
+
+    pub fn user_id(&self, client: u8) -> Option<UserId> {
+        self.user_ids.get(&client).copied()
+    }
+
+    /// Decode all voice packets from a client into a single track starting at the start of the demo.
+    ///
+    /// Silence is inserted between packets so that every packet starts at the time it was received,
+    /// packets that arrive while the previous audio is still playing are appended directly.
+    /// Packets that fail to decode are skipped and counted in [`SpeakerAudio::decode_errors`].
+    pub fn render<D: VoiceDecoder>(
+        &self,
+        client: u8,
+        decoder: &mut D,
+    ) -> Result<SpeakerAudio, VoiceError> {
+        let codec = self.codec();
+        if let VoiceCodec::Other(name) = &codec.codec {
+            return Err(VoiceError::UnsupportedCodec(name.clone()));
+        }
+        let mut audio = SpeakerAudio {
+            client,
+            sample_rate: codec.sampling_rate as u32,
+            ..SpeakerAudio::default()
+        };
+        let packets = match self.packets.get(&client) {
+            Some(packets) => packets,
+            None => return Ok(audio),
+        };
+
+        let mut decoded = Vec::with_capacity(packets.len());
+        for packet in packets {
+            let mut samples = Vec::new();
+            match decode_packet(&codec.codec, packet, decoder, &mut samples, &mut audio) {
+                Ok(()) => decoded.push((packet.tick, samples)),
+                Err(_e) => {
+                    #[cfg(feature = "trace")]
+                    tracing::warn!(
+                        tick = display(packet.tick),
+                        client,
+                        error = display(&_e),
+                        "failed to decode voice packet"
+                    );
+                    audio.decode_errors += 1;
+                }
+            }
+        }
+
+        for (tick, samples) in decoded {
+            let time = u32::from(tick) as f64 * self.interval_per_tick as f64;
+            let start = (time * audio.sample_rate as f64) as usize;
+            if audio.samples.len() < start {
+                audio.samples.resize(start, 0);
+            }
+            audio.samples.extend_from_slice(&samples);
+        }
+
+        Ok(audio)
+    }
+
+    /// Decode all voice packets from a client with the built-in opus decoder, see [`render`](Self::render)
+    #[cfg(feature = "voice")]
+    pub fn render_speaker(&self, client: u8) -> Result<SpeakerAudio, VoiceError> {
+        let codec = self.codec();
+        if codec.codec != VoiceCodec::Steam {
+            return Err(VoiceError::UnsupportedCodec(format!("{:?}", codec.codec)));
+        }
+        // the sample rate is announced inside the steam voice packets
+        let sample_rate = self
+            .packets
+            .get(&client)
+            .into_iter()
+            .flatten()
+            .filter_map(|packet| SteamVoicePacket::parse(&packet.data).ok())
+            .flat_map(|packet| packet.segments)
+            .find_map(|segment| match segment {
+                SteamVoiceSegment::SampleRate(rate) => Some(rate as u32),
+                _ => None,
+            })
+            .unwrap_or(24000);
+        self.render(client, &mut OpusDecoder::new(sample_rate)?)
+    }
+
+    fn codec(&self) -> VoiceCodecInfo {
+        self.codec.clone().unwrap_or(VoiceCodecInfo {
+            codec: VoiceCodec::Steam,
+            quality: 0,
+            sampling_rate: 0,
+        })
+    }
+}
+
+fn decode_packet<D: VoiceDecoder>(
+    codec: &VoiceCodec,
+    packet: &VoicePacket,
+    decoder: &mut D,
+    samples: &mut Vec<i16>,
+    audio: &mut SpeakerAudio,
+) -> Result<(), VoiceError> {
+    match codec {
+        VoiceCodec::Steam => {
+            let steam_packet = SteamVoicePacket::parse(&packet.data)?;
+            for segment in steam_packet.segments {
+                match segment {
+                    SteamVoiceSegment::SampleRate(rate) => audio.sample_rate = rate as u32,
+                    SteamVoiceSegment::Silence(count) => {
+                        samples.resize(samples.len() + count as usize, 0)
+                    }
+                    SteamVoiceSegment::Opus { sequence: None, .. } => decoder.reset(),
+                    SteamVoiceSegment::Opus { frame, .. } => decoder.decode(frame, samples)?,
+                    SteamVoiceSegment::Other { kind, .. } => {
+                        return Err(VoiceError::UnsupportedCodec(format!(
+                            "steam payload type {}",
+                            kind
+                        )))
+                    }
+                }
+            }
+            Ok(())
+        }
+        VoiceCodec::Celt | VoiceCodec::Speex => decoder.decode(&packet.data, samples),
+        VoiceCodec::Other(name) => Err(VoiceError::UnsupportedCodec(name.clone())),
+    }
+}
+
+/// Collect all voice data from a demo, grouped per speaker
+#[derive(Default, Debug)]
+pub struct VoiceAnalyser {
+    state: VoiceState,
+}
+
+impl MessageHandler for VoiceAnalyser {
+    type Output = VoiceState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(
+            message_type,
+            MessageType::VoiceInit | MessageType::VoiceData
+        )
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        self.state.interval_per_tick = parser_state.demo_meta.interval_per_tick;
+        match message {
+            Message::VoiceInit(init) => {
+                self.state.codec = Some(VoiceCodecInfo {
+                    codec: VoiceCodec::new(init.codec()),
+                    quality: init.quality(),
+                    sampling_rate: init.sampling_rate(),
+                })
+            }
+            Message::VoiceData(data) => {
+                self.state
+                    .packets
+                    .entry(data.client())
+                    .or_default()
+                    .push(VoicePacket {
+                        tick,
+                        proximity: data.proximity(),
+                        data: data.bytes(),
+                    })
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        _parser_state: &ParserState,
+    ) {
+        if table == "userinfo" {
+            if let Ok(Some(user_info)) = UserInfo::from_string_table_entry(index, entry) {
+                if let Some(client) = u32::from(user_info.entity_id).checked_sub(1) {
+                    self.state
+                        .user_ids
+                        .insert(client as u8, user_info.player_info.user_id);
+                }
+            }
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for VoiceAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl VoiceAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+fn steam_packet(segments: &[(u8, &[u8])]) -> Vec<u8> {
+    let mut data = 76561198000000000u64.to_le_bytes().to_vec();
+    for (kind, payload) in segments {
+        data.push(*kind);
+        data.extend_from_slice(payload);
+    }
+    let checksum = crc32(&data);
+    data.extend_from_slice(&checksum.to_le_bytes());
+    data
+}
+
+#[test]
+fn test_render_steam_voice() {
+    struct ByteDecoder;
+
+    impl VoiceDecoder for ByteDecoder {
+        fn decode(&mut self, frame: &[u8], output: &mut Vec<i16>) -> Result<(), VoiceError> {
+            output.extend(frame.iter().map(|byte| *byte as i16));
+            Ok(())
+        }
+    }
+
+    let opus = [9, 0, 0, 0, 1, 0, 1, 0, 1, 0, 9];
+    let first = steam_packet(&[(11, &[100, 0]), (6, &opus[..]), (0, &[2, 0])]);
+    let second = steam_packet(&[(11, &[100, 0]), (6, &opus[..])]);
+
+    assert_eq!(
+        SteamVoicePacket::parse(&first).unwrap().segments,
+        vec![
+            SteamVoiceSegment::SampleRate(100),
+            SteamVoiceSegment::Opus {
+                sequence: Some(1),
+                frame: &[],
+            },
+            SteamVoiceSegment::Opus {
+                sequence: Some(1),
+                frame: &[9],
+            },
+            SteamVoiceSegment::Silence(2),
+        ]
+    );
+
+    let mut corrupt = second.clone();
+    corrupt[9] = 0;
+    assert!(matches!(
+        SteamVoicePacket::parse(&corrupt),
+        Err(VoiceError::InvalidChecksum)
+    ));
+
+    let mut state = VoiceState {
+        interval_per_tick: 0.1,
+        ..VoiceState::default()
+    };
+    state.packets.insert(
+        3,
+        vec![
+            VoicePacket {
+                tick: 1.into(),
+                proximity: false,
+                data: first,
+            },
+            VoicePacket {
+                tick: 2.into(),
+                proximity: false,
+                data: second,
+            },
+            VoicePacket {
+                tick: 3.into(),
+                proximity: false,
+                data: corrupt,
+            },
+        ],
+    );
+
+    let audio = state.render(3, &mut ByteDecoder).unwrap();
+    assert_eq!(audio.sample_rate, 100);
+    assert_eq!(audio.decode_errors, 1);
+    // the first packet starts at tick 1 (sample 10) and ends with 2 samples of silence,
+    // the second packet is padded to start at tick 2 (sample 20)
+    assert_eq!(
+        audio.samples,
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9]
+    );
+
+    let mut wav = Vec::new();
+    audio.write_wav(&mut wav).unwrap();
+    assert_eq!(&wav[0..4], b"RIFF");
+    assert_eq!(wav.len(), 44 + audio.samples.len() * 2);
+}
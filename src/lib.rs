@@ -1,3 +1,6 @@
+// allow the derive macros to refer to this crate by name
+extern crate self as tf_demo_parser;
+
 pub use bitbuffer::Result as ReadResult;
 
 pub use crate::demo::{
@@ -0,0 +1,32 @@
+use std::fs;
+
+use tf_demo_parser::demo::entityview_gen::CTFPlayerView;
+use tf_demo_parser::demo::parser::entityworld::WorldEntity;
+use tf_demo_parser::{Demo, DemoParser};
+
+#[test]
+fn player_view_test() {
+    let file = fs::read("test_data/short-2024.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (_, mut ticker) = DemoParser::new(demo.get_stream())
+        .with_entity_world()
+        .ticker()
+        .unwrap();
+    while ticker.tick().unwrap() {}
+
+    let world = ticker.parser_state().entity_world().unwrap();
+    let players: Vec<_> = world
+        .of_class(CTFPlayerView::<WorldEntity>::CLASS)
+        .map(CTFPlayerView::new)
+        .collect();
+    assert!(!players.is_empty());
+    for player in players {
+        // every player has its origin in either the local or non-local table
+        let origin = player.origin().expect("player without origin");
+        let local = player.tf_local_player_exclusive_origin();
+        let non_local = player.tf_non_local_player_exclusive_origin();
+        let xy = local.or(non_local).unwrap();
+        assert_eq!((xy.x, xy.y), (origin.x, origin.y));
+        assert!(player.health().is_some());
+    }
+}